@@ -0,0 +1,45 @@
+//! Parsing helpers for `#[opensearch(...)]` attributes
+
+use syn::meta::ParseNestedMeta;
+use syn::{Attribute, GenericArgument, LitStr, PathArguments, Type};
+
+/// Name of the helper attribute shared by all derives in this crate
+pub(crate) const ATTRIBUTE: &str = "opensearch";
+
+/// Visit every nested item of each `#[opensearch(...)]` attribute
+pub(crate) fn for_each_nested(
+    attrs: &[Attribute],
+    mut visit: impl FnMut(ParseNestedMeta) -> syn::Result<()>,
+) -> syn::Result<()> {
+    for attr in attrs.iter().filter(|a| a.path().is_ident(ATTRIBUTE)) {
+        attr.parse_nested_meta(&mut visit)?;
+    }
+    Ok(())
+}
+
+/// Parse the string value of a `key = "value"` item
+pub(crate) fn string_value(meta: &ParseNestedMeta) -> syn::Result<String> {
+    let lit: LitStr = meta.value()?.parse()?;
+    Ok(lit.value())
+}
+
+/// Return the inner type if `ty` is written as `Option<T>`
+pub(crate) fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
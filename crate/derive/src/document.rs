@@ -0,0 +1,148 @@
+//! Implementation of `#[derive(Document)]`
+
+use crate::attr::{for_each_nested, option_inner, string_value};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Field, Fields, Ident, Result};
+
+/// Fields marked with one of the per-field `#[opensearch(...)]` flags
+#[derive(Default)]
+struct MarkedFields<'a> {
+    id: Option<&'a Field>,
+    routing: Option<&'a Field>,
+    version: Option<&'a Field>,
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "Document can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Document can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut index = None;
+    for_each_nested(&input.attrs, |meta| {
+        if meta.path.is_ident("index") {
+            index = Some(string_value(&meta)?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported opensearch attribute, expected `index = \"...\"`"))
+        }
+    })?;
+    let index = index.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "missing `#[opensearch(index = \"...\")]` attribute",
+        )
+    })?;
+
+    let mut marked = MarkedFields::default();
+    for field in fields {
+        for_each_nested(&field.attrs, |meta| {
+            let slot = if meta.path.is_ident("id") {
+                &mut marked.id
+            } else if meta.path.is_ident("routing") {
+                &mut marked.routing
+            } else if meta.path.is_ident("version") {
+                &mut marked.version
+            } else {
+                return Err(meta.error(
+                    "unsupported opensearch attribute, expected `id`, `routing` or `version`",
+                ));
+            };
+            if slot.is_some() {
+                return Err(meta.error("attribute is set on more than one field"));
+            }
+            *slot = Some(field);
+            Ok(())
+        })?;
+    }
+
+    let id = marked
+        .id
+        .map_or_else(|| quote!(::std::option::Option::None), string_accessor);
+    let routing = marked.routing.map(|field| {
+        let body = string_accessor(field);
+        quote! {
+            fn routing(&self) -> ::std::option::Option<::std::string::String> {
+                #body
+            }
+        }
+    });
+    let version = marked.version.map(|field| {
+        let body = version_accessor(field);
+        quote! {
+            fn version(&self) -> ::std::option::Option<i64> {
+                #body
+            }
+        }
+    });
+
+    let name = &input.ident;
+    // Generic documents are only documents when their parameters serialize
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(Self: ::opensearch_api::__private::serde::Serialize));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::opensearch_api::Document for #name #ty_generics #where_clause {
+            fn index_name() -> &'static str {
+                #index
+            }
+
+            fn id(&self) -> ::std::option::Option<::std::string::String> {
+                #id
+            }
+
+            #routing
+
+            #version
+        }
+    })
+}
+
+fn field_ident(field: &Field) -> &Ident {
+    field
+        .ident
+        .as_ref()
+        .expect("named struct fields always have an identifier")
+}
+
+/// Read a field as an optional string, unwrapping `Option` fields
+fn string_accessor(field: &Field) -> TokenStream {
+    let ident = field_ident(field);
+    if option_inner(&field.ty).is_some() {
+        quote!(self.#ident.as_ref().map(::std::string::ToString::to_string))
+    } else {
+        quote!(::std::option::Option::Some(::std::string::ToString::to_string(&self.#ident)))
+    }
+}
+
+/// Read an integer field as an optional `i64` version, unwrapping `Option` fields
+///
+/// The conversion goes through `TryFrom`, so non-integer fields fail to compile and
+/// values outside the `i64` range are reported as no version instead of wrapping.
+fn version_accessor(field: &Field) -> TokenStream {
+    let ident = field_ident(field);
+    let convert = quote!(<i64 as ::std::convert::TryFrom<_>>::try_from);
+    if option_inner(&field.ty).is_some() {
+        quote!(self.#ident.and_then(|version| #convert(version).ok()))
+    } else {
+        quote!(#convert(self.#ident).ok())
+    }
+}
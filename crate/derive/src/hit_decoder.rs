@@ -0,0 +1,192 @@
+//! Implementation of `#[derive(SearchHitDecoder)]`
+
+use crate::attr::{for_each_nested, string_value};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, Ident, Result, Type, Variant};
+
+/// How a hit is matched to a variant
+enum Matcher {
+    /// Comma-separated index patterns
+    Index(String),
+    /// Index name of the variant's `Document` type
+    Document,
+    /// Value of the discriminator field
+    Value(String),
+}
+
+/// Variant holding the decoded source of matching hits
+struct Arm<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    matcher: Option<Matcher>,
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "SearchHitDecoder can only be derived for enums",
+        ));
+    };
+
+    let mut discriminator = None;
+    for_each_nested(&input.attrs, |meta| {
+        if meta.path.is_ident("discriminator") {
+            discriminator = Some(string_value(&meta)?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported opensearch attribute, expected `discriminator = \"...\"`"))
+        }
+    })?;
+
+    let mut arms = Vec::new();
+    let mut other = None;
+    for variant in &data.variants {
+        let arm = parse_variant(variant, discriminator.is_some())?;
+        if arm.matcher.is_some() {
+            arms.push(arm);
+        } else if other.replace(arm).is_some() {
+            return Err(Error::new_spanned(
+                variant,
+                "`other` is set on more than one variant",
+            ));
+        }
+    }
+
+    // Reached through the crate so that users need not depend on serde_json
+    let serde_json = quote!(::opensearch_api::__private::serde_json);
+    let checks = arms.iter().map(|arm| {
+        let ident = arm.ident;
+        let condition = match arm.matcher.as_ref().expect("matched arms have a matcher") {
+            Matcher::Index(patterns) => {
+                quote!(::opensearch_api::matches_index_pattern(#patterns, index))
+            }
+            Matcher::Document => {
+                let ty = arm.ty;
+                quote! {
+                    ::opensearch_api::matches_index_pattern(
+                        <#ty as ::opensearch_api::Document>::index_name(),
+                        index,
+                    )
+                }
+            }
+            Matcher::Value(value) => quote!(discriminator == ::std::option::Option::Some(#value)),
+        };
+        quote! {
+            if #condition {
+                return ::std::result::Result::Ok(Self::#ident(#serde_json::from_value(source)?));
+            }
+        }
+    });
+
+    let name = &input.ident;
+    let read_discriminator = discriminator.map(|field| {
+        quote! {
+            let value = source.get(#field).cloned();
+            let discriminator = value.as_ref().and_then(#serde_json::Value::as_str);
+        }
+    });
+    let fallback = match other {
+        Some(arm) => {
+            let ident = arm.ident;
+            quote!(::std::result::Result::Ok(Self::#ident(#serde_json::from_value(source)?)))
+        }
+        None => {
+            let message = format!("no variant of {} matches the hit from index '{{}}'", name);
+            quote! {
+                ::std::result::Result::Err(::opensearch_api::Error::Search(
+                    ::std::format!(#message, index),
+                ))
+            }
+        }
+    };
+
+    // Every variant type must be deserializable, including generic ones
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for variant in &data.variants {
+        let ty = newtype(variant)?;
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::opensearch_api::__private::serde::de::DeserializeOwned));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::opensearch_api::SearchHitDecoder for #name #ty_generics #where_clause {
+            fn decode_hit(
+                index: &str,
+                source: #serde_json::Value,
+            ) -> ::opensearch_api::Result<Self> {
+                #read_discriminator
+                #(#checks)*
+                #fallback
+            }
+        }
+    })
+}
+
+/// Type held by a single-field tuple variant
+fn newtype(variant: &Variant) -> Result<&Type> {
+    match &variant.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(&fields.unnamed[0].ty),
+        _ => Err(Error::new_spanned(
+            variant,
+            "SearchHitDecoder variants must hold exactly one unnamed field",
+        )),
+    }
+}
+
+/// Read the matcher of a variant, `None` for the `other` variant
+fn parse_variant(variant: &Variant, by_discriminator: bool) -> Result<Arm<'_>> {
+    let ty = newtype(variant)?;
+
+    let mut patterns = Vec::new();
+    let mut value = None;
+    let mut other = false;
+    for_each_nested(&variant.attrs, |meta| {
+        if meta.path.is_ident("index") && !by_discriminator {
+            patterns.push(string_value(&meta)?);
+        } else if meta.path.is_ident("value") && by_discriminator {
+            value = Some(string_value(&meta)?);
+        } else if meta.path.is_ident("other") {
+            other = true;
+        } else if by_discriminator {
+            return Err(meta
+                .error("unsupported opensearch attribute, expected `value = \"...\"` or `other`"));
+        } else {
+            return Err(meta
+                .error("unsupported opensearch attribute, expected `index = \"...\"` or `other`"));
+        }
+        Ok(())
+    })?;
+
+    let matcher = if other {
+        if !patterns.is_empty() || value.is_some() {
+            return Err(Error::new_spanned(
+                variant,
+                "`other` cannot be combined with another matcher",
+            ));
+        }
+        None
+    } else if by_discriminator {
+        let value = value.ok_or_else(|| {
+            Error::new_spanned(
+                variant,
+                "missing `#[opensearch(value = \"...\")]` or `#[opensearch(other)]` attribute",
+            )
+        })?;
+        Some(Matcher::Value(value))
+    } else if patterns.is_empty() {
+        Some(Matcher::Document)
+    } else {
+        Some(Matcher::Index(patterns.join(",")))
+    };
+
+    Ok(Arm {
+        ident: &variant.ident,
+        ty,
+        matcher,
+    })
+}
@@ -0,0 +1,79 @@
+//! # Derive macros for OpenSearch API for Rust
+//!
+//! This crate provides the procedural macros re-exported by `opensearch-api`
+//! when its `derive` feature is enabled. It is not meant to be used directly.
+
+mod attr;
+mod document;
+mod hit_decoder;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive the `opensearch_api::Document` trait for a struct
+///
+/// The target index is configured on the struct, and the fields holding the
+/// document ID, routing value and version are marked individually:
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize, Document)]
+/// #[opensearch(index = "products")]
+/// struct Product {
+///     #[opensearch(id)]
+///     sku: String,
+///     #[opensearch(routing)]
+///     tenant: String,
+///     #[opensearch(version)]
+///     revision: Option<u64>,
+///     name: String,
+/// }
+/// ```
+///
+/// The version field must be an integer, optionally wrapped in `Option`. A
+/// version that does not fit in an `i64` is reported as no version.
+#[proc_macro_derive(Document, attributes(opensearch))]
+pub fn derive_document(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    document::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive the `opensearch_api::SearchHitDecoder` trait for an enum
+///
+/// Each variant holds the document type of the hits it matches. Variants are
+/// matched on the `_index` of the hit with comma-separated index patterns, or
+/// by default with the index name of their `Document` type. An `other` variant
+/// receives the remaining hits:
+///
+/// ```ignore
+/// #[derive(SearchHitDecoder)]
+/// enum Federated {
+///     #[opensearch(index = "logs-*")]
+///     Log(LogLine),
+///     #[opensearch(index = "audit-*")]
+///     Audit(AuditEvent),
+///     #[opensearch(other)]
+///     Unknown(serde_json::Value),
+/// }
+/// ```
+///
+/// With a `discriminator` field, variants are matched on its value instead:
+///
+/// ```ignore
+/// #[derive(SearchHitDecoder)]
+/// #[opensearch(discriminator = "kind")]
+/// enum Event {
+///     #[opensearch(value = "login")]
+///     Login(LoginEvent),
+///     #[opensearch(value = "logout")]
+///     Logout(LogoutEvent),
+/// }
+/// ```
+#[proc_macro_derive(SearchHitDecoder, attributes(opensearch))]
+pub fn derive_search_hit_decoder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    hit_decoder::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
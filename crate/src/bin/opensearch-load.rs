@@ -0,0 +1,448 @@
+//! Load NDJSON, JSON-array or CSV files into an OpenSearch index
+//!
+//! Documents are sent with [`BulkNamespace::from_stream`], so rejected
+//! operations are retried. NDJSON and CSV files are read lazily, while a JSON
+//! array is read whole before loading. Build with the `loader` feature:
+//!
+//! ```text
+//! cargo run --features loader --bin opensearch-load -- products.csv \
+//!     --index products --id-field sku --batch-size 2000 --concurrency 4
+//! ```
+//!
+//! [`BulkNamespace::from_stream`]: opensearch_api::bulk::BulkNamespace::from_stream
+
+use clap::{Parser, ValueEnum};
+use futures::stream::{self, StreamExt};
+use indicatif::{ProgressBar, ProgressStyle};
+use opensearch_api::bulk::{BulkOperation, BulkRetryPolicy, BulkStreamConfig, IdStrategy};
+use opensearch_api::{Client, ClientConfig, Error};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Load NDJSON, JSON-array or CSV files into an OpenSearch index
+#[derive(Debug, Parser)]
+#[command(name = "opensearch-load", version)]
+struct Args {
+    /// File to load
+    file: PathBuf,
+
+    /// Index to load the documents into
+    #[arg(short, long)]
+    index: String,
+
+    /// Format of the file, guessed from its extension by default
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    /// Number of documents per bulk request
+    #[arg(long, default_value_t = 1000)]
+    batch_size: usize,
+
+    /// Number of bulk requests in flight
+    #[arg(long, default_value_t = 4)]
+    concurrency: usize,
+
+    /// Maximum size in bytes of a bulk request, splitting larger batches
+    #[arg(long)]
+    max_request_bytes: Option<usize>,
+
+    /// Document field holding the document ID
+    #[arg(long)]
+    id_field: Option<String>,
+
+    /// Turn CSV fields holding numbers or booleans into JSON numbers and booleans
+    ///
+    /// Fields are kept as strings unless their value is written exactly as
+    /// JSON would, so `00123` or `1e5` stay strings. The ID field is never converted.
+    #[arg(long)]
+    infer_types: bool,
+
+    /// Generate the IDs of documents without one on the client
+    #[arg(long, value_enum)]
+    generate_ids: Option<GeneratedIds>,
+
+    /// Ingest pipeline to run the documents through
+    #[arg(long)]
+    pipeline: Option<String>,
+
+    /// Routing value of every document
+    #[arg(long)]
+    routing: Option<String>,
+
+    /// Create the index when it does not exist
+    #[arg(long)]
+    create_index: bool,
+
+    /// Maximum number of retries of operations rejected by the cluster
+    #[arg(long, default_value_t = 3)]
+    max_retries: u32,
+
+    /// Client configuration file (YAML), as read by `ClientConfig::from_file`
+    #[arg(long, env = "OPENSEARCH_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// URL of the cluster, overriding the configuration file
+    #[arg(long, env = "OPENSEARCH_URL")]
+    url: Option<String>,
+
+    /// Username for basic authentication, overriding the configuration file
+    #[arg(long, env = "OPENSEARCH_USERNAME")]
+    username: Option<String>,
+
+    /// Password for basic authentication, overriding the configuration file
+    #[arg(long, env = "OPENSEARCH_PASSWORD", hide_env_values = true)]
+    password: Option<String>,
+
+    /// Do not verify TLS certificates
+    #[arg(long)]
+    insecure: bool,
+
+    /// Hide the progress display
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+/// Format of the loaded file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One JSON document per line
+    Ndjson,
+    /// A JSON array of documents
+    Json,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+impl Format {
+    /// Guess the format from the file extension
+    fn from_path(path: &Path) -> Result<Self, Error> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("ndjson") | Some("jsonl") => Ok(Format::Ndjson),
+            Some("json") => Ok(Format::Json),
+            Some("csv") => Ok(Format::Csv),
+            _ => Err(Error::InvalidArgument(format!(
+                "cannot guess the format of {}, pass --format",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Client-side ID generation strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum GeneratedIds {
+    /// Random UUID
+    Uuid4,
+    /// Time-ordered UUID
+    Uuid7,
+    /// SHA-256 digest of the document
+    Hash,
+}
+
+impl From<GeneratedIds> for IdStrategy<Value> {
+    fn from(ids: GeneratedIds) -> Self {
+        match ids {
+            GeneratedIds::Uuid4 => IdStrategy::UuidV4,
+            GeneratedIds::Uuid7 => IdStrategy::UuidV7,
+            GeneratedIds::Hash => IdStrategy::ContentHash,
+        }
+    }
+}
+
+/// Outcome of a load
+#[derive(Debug, Default)]
+struct Report {
+    documents: u64,
+    indexed: u64,
+    failed: u64,
+    retried: u64,
+    errors: BTreeMap<String, u64>,
+}
+
+type Documents = Box<dyn Iterator<Item = Result<Value, Error>>>;
+
+/// Read the documents of a file, lazily except for JSON arrays
+///
+/// `typed_fields` decides which CSV fields are converted to numbers and booleans.
+fn read_documents(
+    path: &Path,
+    format: Format,
+    typed_fields: impl Fn(&str) -> bool + 'static,
+) -> Result<Documents, Error> {
+    match format {
+        Format::Ndjson => {
+            let lines = BufReader::new(File::open(path)?).lines().enumerate();
+            Ok(Box::new(lines.filter_map(|(number, line)| match line {
+                Ok(line) if line.trim().is_empty() => None,
+                Ok(line) => Some(serde_json::from_str(&line).map_err(|err| {
+                    Error::validation(format!("invalid JSON on line {}: {}", number + 1, err))
+                })),
+                Err(err) => Some(Err(err.into())),
+            })))
+        }
+        Format::Json => {
+            let documents: Vec<Value> = serde_json::from_reader(BufReader::new(File::open(path)?))
+                .map_err(|err| Error::validation(format!("invalid JSON array: {}", err)))?;
+            Ok(Box::new(documents.into_iter().map(Ok)))
+        }
+        Format::Csv => {
+            let mut reader = csv::Reader::from_path(path)
+                .map_err(|err| Error::validation(format!("invalid CSV file: {}", err)))?;
+            let headers = reader
+                .headers()
+                .map_err(|err| Error::validation(format!("invalid CSV header: {}", err)))?
+                .clone();
+            Ok(Box::new(reader.into_records().map(move |record| {
+                let record = record
+                    .map_err(|err| Error::validation(format!("invalid CSV record: {}", err)))?;
+                let document: Map<String, Value> = headers
+                    .iter()
+                    .zip(record.iter())
+                    .filter_map(|(header, field)| {
+                        let value = csv_value(field, typed_fields(header))?;
+                        Some((header.to_string(), value))
+                    })
+                    .collect();
+                Ok(Value::Object(document))
+            })))
+        }
+    }
+}
+
+/// Convert a CSV field to a JSON value, skipping empty fields
+///
+/// When `typed`, numbers and booleans are converted if their JSON form is the
+/// field itself, so that identifiers such as `00123` are never altered.
+fn csv_value(field: &str, typed: bool) -> Option<Value> {
+    if field.is_empty() {
+        return None;
+    }
+    if typed {
+        if let Ok(value @ (Value::Number(_) | Value::Bool(_))) = serde_json::from_str(field) {
+            if writes_as(&value, field) {
+                return Some(value);
+            }
+        }
+    }
+    Some(Value::String(field.to_string()))
+}
+
+/// Whether the JSON form of a value is exactly the given text, without allocating it
+fn writes_as(value: &Value, text: &str) -> bool {
+    struct Remaining<'a>(&'a str);
+
+    impl Write for Remaining<'_> {
+        fn write_str(&mut self, part: &str) -> fmt::Result {
+            self.0 = self.0.strip_prefix(part).ok_or(fmt::Error)?;
+            Ok(())
+        }
+    }
+
+    let mut remaining = Remaining(text);
+    write!(remaining, "{}", value).is_ok() && remaining.0.is_empty()
+}
+
+/// ID of a document taken from one of its fields
+fn document_id(document: &Value, field: &str) -> Option<String> {
+    match document.get(field)? {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+/// Build the client from the configuration file and command-line overrides
+fn client(args: &Args) -> Result<Client, Error> {
+    let mut config = match &args.config {
+        Some(path) => ClientConfig::from_file(path)?,
+        None => ClientConfig::builder()
+            .base_url("http://localhost:9200")
+            .build()?,
+    };
+    if let Some(url) = &args.url {
+        config.base_url = url.clone();
+    }
+    if let Some(username) = &args.username {
+        config.username = Some(username.clone());
+    }
+    if let Some(password) = &args.password {
+        config.password = Some(password.clone());
+    }
+    if args.insecure {
+        config.verify_ssl = false;
+    }
+    Client::new(config)
+}
+
+async fn load(args: &Args, progress: &ProgressBar) -> Result<Report, Error> {
+    let format = match args.format {
+        Some(format) => format,
+        None => Format::from_path(&args.file)?,
+    };
+    let infer_types = args.infer_types;
+    let id_header = args.id_field.clone();
+    let documents = read_documents(&args.file, format, move |header| {
+        infer_types && id_header.as_deref() != Some(header)
+    })?;
+    let client = client(args)?;
+
+    if args.create_index {
+        let exists = client
+            .indices()
+            .exists(&args.index)
+            .build()
+            .map_err(|err| Error::BuilderError(err.to_string()))?
+            .send()
+            .await?;
+        if !exists {
+            client
+                .indices()
+                .create(&args.index)
+                .build()
+                .map_err(|err| Error::BuilderError(err.to_string()))?
+                .send()
+                .await?;
+        }
+    }
+
+    let mut bulk = client.bulk::<Value>();
+    if let Some(pipeline) = &args.pipeline {
+        bulk = bulk.pipeline(pipeline);
+    }
+    if let Some(routing) = &args.routing {
+        bulk = bulk.routing(routing);
+    }
+    if let Some(ids) = args.generate_ids {
+        bulk = bulk.id_strategy(ids.into());
+    }
+    if let Some(max_request_bytes) = args.max_request_bytes {
+        bulk = bulk.max_request_bytes(max_request_bytes);
+    }
+
+    // Reading stops at the first invalid document, which fails the load
+    let read_error = Arc::new(Mutex::new(None));
+    let index = args.index.clone();
+    let id_field = args.id_field.clone();
+    let operations = {
+        let read_error = read_error.clone();
+        stream::iter(documents.map_while(move |document| match document {
+            Ok(document) => Some(document),
+            Err(err) => {
+                *read_error.lock().unwrap() = Some(err);
+                None
+            }
+        }))
+        .map(move |document| {
+            let id = id_field
+                .as_deref()
+                .and_then(|field| document_id(&document, field));
+            BulkOperation::index(Some(index.clone()), id, document)
+        })
+    };
+
+    let config = BulkStreamConfig::builder()
+        .chunk_size(args.batch_size)
+        .concurrency(args.concurrency)
+        .retry(
+            BulkRetryPolicy::builder()
+                .max_retries(args.max_retries)
+                .build()?,
+        )
+        .build()?;
+
+    let mut report = Report::default();
+    let mut results = Box::pin(bulk.from_stream(operations, config));
+    while let Some(result) = results.next().await {
+        let response = result?;
+        report.documents += response.items.len() as u64;
+        report.failed += response.failures.len() as u64;
+        report.indexed += (response.items.len() - response.failures.len()) as u64;
+        report.retried += response.retried;
+        for failure in &response.failures {
+            let error_type = failure.error.as_ref().map_or_else(
+                || format!("status {}", failure.status),
+                |error| error.error_type.clone(),
+            );
+            *report.errors.entry(error_type).or_default() += 1;
+        }
+        progress.inc(response.items.len() as u64);
+        if report.failed > 0 {
+            progress.set_message(format!("{} failed", report.failed));
+        }
+    }
+
+    if let Some(err) = read_error.lock().unwrap().take() {
+        return Err(err);
+    }
+    Ok(report)
+}
+
+fn print_report(args: &Args, report: &Report, elapsed: Duration) {
+    let rate = report.documents as f64 / elapsed.as_secs_f64().max(0.001);
+    println!(
+        "Loaded {} into {} in {:.1}s",
+        args.file.display(),
+        args.index,
+        elapsed.as_secs_f64()
+    );
+    println!("  documents: {}", report.documents);
+    println!("  indexed:   {}", report.indexed);
+    println!("  failed:    {}", report.failed);
+    println!("  retried:   {}", report.retried);
+    println!("  rate:      {:.0} documents/s", rate);
+    if !report.errors.is_empty() {
+        println!("Failures by error type:");
+        for (error_type, count) in &report.errors {
+            println!("  {}: {}", error_type, count);
+        }
+    }
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let progress = if args.quiet {
+        ProgressBar::hidden()
+    } else {
+        let progress = ProgressBar::new_spinner();
+        progress.set_style(
+            ProgressStyle::with_template(
+                "{spinner} {pos} documents ({per_sec}) in {elapsed} {msg}",
+            )
+            .expect("the progress template is valid"),
+        );
+        progress.enable_steady_tick(Duration::from_millis(100));
+        progress
+    };
+
+    let started = Instant::now();
+    let result = load(&args, &progress).await;
+    progress.finish_and_clear();
+
+    match result {
+        Ok(report) => {
+            print_report(&args, &report, started.elapsed());
+            if report.failed > 0 {
+                ExitCode::from(2)
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Err(err) => {
+            eprintln!("opensearch-load: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
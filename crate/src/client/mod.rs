@@ -5,6 +5,8 @@ pub mod namespaces;
 
 use base64::Engine;
 use derive_builder::Builder;
+use serde::Deserialize;
+use std::path::Path;
 use std::time::Duration;
 
 use reqwest::header::{HeaderMap, HeaderValue};
@@ -14,7 +16,7 @@ use url::Url;
 use crate::Error;
 
 /// Configuration for the OpenSearch client
-#[derive(Debug, Clone, Default, Builder)]
+#[derive(Debug, Clone, Default, Builder, Deserialize)]
 #[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
 pub struct ClientConfig {
     /// Base URL for the OpenSearch cluster (e.g., "https://localhost:9200")
@@ -31,17 +33,41 @@ pub struct ClientConfig {
 
     /// Request timeout in seconds
     #[builder(default = "30")]
+    #[serde(default = "default_timeout_secs")]
     pub timeout_secs: u64,
 
     /// Whether to verify SSL certificates
     #[builder(default = "true")]
+    #[serde(default = "default_verify_ssl")]
     pub verify_ssl: bool,
 }
 
+fn default_timeout_secs() -> u64 {
+    30
+}
+
+fn default_verify_ssl() -> bool {
+    true
+}
+
 impl ClientConfig {
     pub fn builder() -> ClientConfigBuilder {
         ClientConfigBuilder::default()
     }
+
+    /// Load the configuration from a YAML (or JSON) file
+    ///
+    /// ```yaml
+    /// base_url: https://localhost:9200
+    /// username: admin
+    /// password: admin
+    /// timeout_secs: 60
+    /// verify_ssl: false
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_yaml::from_str(&content)?)
+    }
 }
 
 /// Client for the OpenSearch API
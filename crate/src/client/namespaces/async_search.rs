@@ -0,0 +1,442 @@
+//! Asynchronous search namespace for OpenSearch
+//!
+//! Operations of the asynchronous search plugin, which runs searches in the
+//! background and keeps their results for later retrieval.
+
+use crate::client::namespaces::search::SearchQuery;
+use crate::error::Error;
+use crate::types::search::SearchResponse;
+use derive_builder::Builder;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Client namespace for asynchronous search operations
+#[derive(Debug, Clone)]
+pub struct AsyncSearchNamespace {
+    client: crate::client::Client,
+}
+
+/// State of an asynchronous search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AsyncSearchState {
+    /// Submitted but not started yet
+    Init,
+    /// Running, possibly with partial results
+    Running,
+    /// Completed and not stored
+    Succeeded,
+    /// Failed
+    Failed,
+    /// Completed and being stored
+    Persisting,
+    /// Completed and stored
+    PersistSucceeded,
+    /// Completed but could not be stored
+    PersistFailed,
+    /// Deleted or expired
+    Closed,
+    /// Completed and retrieved from the store
+    StoreResident,
+}
+
+impl AsyncSearchState {
+    /// Whether the search is still running
+    pub fn is_running(&self) -> bool {
+        matches!(self, AsyncSearchState::Init | AsyncSearchState::Running)
+    }
+}
+
+/// Response from submitting or getting an asynchronous search
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsyncSearchResponse<T: Default = serde_json::Value> {
+    /// ID of the asynchronous search
+    pub id: String,
+
+    /// State of the search
+    pub state: AsyncSearchState,
+
+    /// Start time of the search in milliseconds since the epoch
+    #[serde(default)]
+    pub start_time_in_millis: Option<i64>,
+
+    /// Time when the search and its results expire, in milliseconds since the epoch
+    #[serde(default)]
+    pub expiration_time_in_millis: Option<i64>,
+
+    /// Search results, partial while the search is running
+    #[serde(default)]
+    pub response: Option<SearchResponse<T>>,
+
+    /// Error of a failed search
+    #[serde(default)]
+    pub error: Option<serde_json::Value>,
+}
+
+/// Response from deleting an asynchronous search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteAsyncSearchResponse {
+    /// Whether the operation was acknowledged
+    pub acknowledged: bool,
+}
+
+/// Response from the asynchronous search stats API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsyncSearchStatsResponse {
+    /// Cluster name
+    pub cluster_name: String,
+
+    /// Statistics of each node by node ID
+    pub nodes: HashMap<String, AsyncSearchNodeStats>,
+}
+
+impl AsyncSearchStatsResponse {
+    /// Statistics summed over all nodes
+    pub fn total(&self) -> AsyncSearchStats {
+        self.nodes
+            .values()
+            .fold(AsyncSearchStats::default(), |total, node| {
+                let stats = &node.asynchronous_search_stats;
+                AsyncSearchStats {
+                    submitted: total.submitted + stats.submitted,
+                    initialized: total.initialized + stats.initialized,
+                    search_failed: total.search_failed + stats.search_failed,
+                    search_completed: total.search_completed + stats.search_completed,
+                    rejected: total.rejected + stats.rejected,
+                    persist_failed: total.persist_failed + stats.persist_failed,
+                    cancelled: total.cancelled + stats.cancelled,
+                    running_current: total.running_current + stats.running_current,
+                    persisted: total.persisted + stats.persisted,
+                }
+            })
+    }
+}
+
+/// Asynchronous search statistics of a node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsyncSearchNodeStats {
+    /// Counters of the asynchronous searches on the node
+    pub asynchronous_search_stats: AsyncSearchStats,
+}
+
+/// Counters of asynchronous searches
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AsyncSearchStats {
+    /// Number of submitted searches
+    pub submitted: u64,
+    /// Number of searches that started
+    pub initialized: u64,
+    /// Number of searches that failed
+    pub search_failed: u64,
+    /// Number of searches that completed
+    pub search_completed: u64,
+    /// Number of searches rejected because too many were running
+    pub rejected: u64,
+    /// Number of results that could not be stored
+    pub persist_failed: u64,
+    /// Number of searches cancelled before completion
+    pub cancelled: u64,
+    /// Number of searches running now
+    pub running_current: u64,
+    /// Number of stored results
+    pub persisted: u64,
+}
+
+impl AsyncSearchNamespace {
+    /// Create a new asynchronous search namespace with the given client
+    pub(crate) fn new(client: crate::client::Client) -> Self {
+        Self { client }
+    }
+
+    /// Submit a search to run in the background
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use opensearch_api::types::query::MatchAllQuery;
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    /// use std::time::Duration;
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let search = client
+    ///         .search::<serde_json::Value>("logs-*")
+    ///         .query(MatchAllQuery::builder().build()?)
+    ///         .build()?;
+    ///
+    ///     let response = client
+    ///         .async_search()
+    ///         .submit(search)
+    ///         .wait_for_completion_timeout("1s")
+    ///         .keep_on_completion(true)
+    ///         .build()?
+    ///         .send_and_wait(Duration::from_millis(500), Duration::from_secs(60))
+    ///         .await?;
+    ///     println!("{:?}: {:?}", response.state, response.response.map(|r| r.hits.total));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn submit<T>(&self, search: SearchQuery<T>) -> SubmitAsyncSearchRequestBuilder<T>
+    where
+        T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        let mut builder = SubmitAsyncSearchRequestBuilder::default();
+        builder.client(self.client.clone());
+        builder.search(search);
+        builder
+    }
+
+    /// Get the state and results of an asynchronous search
+    pub fn get<T>(&self, id: impl Into<String>) -> GetAsyncSearchRequestBuilder<T>
+    where
+        T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        let mut builder = GetAsyncSearchRequestBuilder::default();
+        builder.client(self.client.clone());
+        builder.id(id);
+        builder
+    }
+
+    /// Delete an asynchronous search, cancelling it if it is still running
+    pub fn delete(&self, id: impl Into<String>) -> DeleteAsyncSearchRequestBuilder {
+        let mut builder = DeleteAsyncSearchRequestBuilder::default();
+        builder.client(self.client.clone());
+        builder.id(id);
+        builder
+    }
+
+    /// Get asynchronous search statistics of all nodes
+    pub async fn stats(&self) -> Result<AsyncSearchStatsResponse, Error> {
+        self.client
+            .request::<(), _>(Method::GET, "/_plugins/_asynchronous_search/stats", None)
+            .await
+    }
+
+    /// Get an asynchronous search every `interval` until it is no longer running
+    ///
+    /// A failed search is returned with its `error` rather than as an `Err`.
+    /// Fails when the search is still running after `timeout`, leaving it on
+    /// the cluster to be fetched again or deleted.
+    pub async fn wait_for_completion<T>(
+        &self,
+        id: impl Into<String>,
+        interval: Duration,
+        timeout: Duration,
+    ) -> Result<AsyncSearchResponse<T>, Error>
+    where
+        T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        let id = id.into();
+        match self.poll(&id, interval, Instant::now() + timeout).await? {
+            Some(response) => Ok(response),
+            None => Err(still_running(&id, timeout)),
+        }
+    }
+
+    /// Get an asynchronous search every `interval` until it completes, or `None` past the deadline
+    async fn poll<T>(
+        &self,
+        id: &str,
+        interval: Duration,
+        deadline: Instant,
+    ) -> Result<Option<AsyncSearchResponse<T>>, Error>
+    where
+        T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        loop {
+            let response = self.get::<T>(id).build()?.send().await?;
+            if !response.state.is_running() {
+                return Ok(Some(response));
+            }
+            if Instant::now() + interval > deadline {
+                return Ok(None);
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+/// Error returned when an asynchronous search outlives the time given to wait for it
+fn still_running(id: &str, timeout: Duration) -> Error {
+    Error::search(format!(
+        "asynchronous search {} still running after {:?}",
+        id, timeout
+    ))
+}
+
+/// Submit asynchronous search request
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
+pub struct SubmitAsyncSearchRequest<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync,
+{
+    /// Client reference
+    #[builder(private)]
+    client: crate::client::Client,
+
+    /// Search to run
+    #[builder(private)]
+    search: SearchQuery<T>,
+
+    /// Time to wait for the search to complete before returning, such as `1s`
+    #[builder(setter(into, strip_option), default)]
+    wait_for_completion_timeout: Option<String>,
+
+    /// Whether to store the results once the search completes
+    #[builder(setter(strip_option), default)]
+    keep_on_completion: Option<bool>,
+
+    /// How long to keep the search and its results, such as `1d`
+    #[builder(setter(into, strip_option), default)]
+    keep_alive: Option<String>,
+}
+
+impl<T> SubmitAsyncSearchRequest<T>
+where
+    T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    /// Send the request to the server
+    pub async fn send(self) -> Result<AsyncSearchResponse<T>, Error> {
+        let (index, mut query_params, body) = self.search.into_parts();
+        query_params.insert(0, ("index", index));
+
+        if let Some(timeout) = self.wait_for_completion_timeout {
+            query_params.push(("wait_for_completion_timeout", timeout));
+        }
+
+        if let Some(keep_on_completion) = self.keep_on_completion {
+            query_params.push(("keep_on_completion", keep_on_completion.to_string()));
+        }
+
+        if let Some(keep_alive) = self.keep_alive {
+            query_params.push(("keep_alive", keep_alive));
+        }
+
+        let query_string: Vec<String> = query_params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect();
+        let path = format!("/_plugins/_asynchronous_search?{}", query_string.join("&"));
+
+        self.client
+            .request::<_, AsyncSearchResponse<T>>(Method::POST, &path, Some(&body))
+            .await
+    }
+
+    /// Submit the search, then get it every `interval` until it is no longer running
+    ///
+    /// Fails when the search is still running after `timeout`, counted from
+    /// the submission. The search is then deleted, since its ID is not returned.
+    pub async fn send_and_wait(
+        self,
+        interval: Duration,
+        timeout: Duration,
+    ) -> Result<AsyncSearchResponse<T>, Error> {
+        let deadline = Instant::now() + timeout;
+        let client = self.client.clone();
+        let response = self.send().await?;
+        if !response.state.is_running() {
+            return Ok(response);
+        }
+        if Instant::now() + interval > deadline {
+            return Err(cancel(&client, &response.id, timeout).await);
+        }
+        tokio::time::sleep(interval).await;
+        let namespace = client.async_search();
+        match namespace.poll(&response.id, interval, deadline).await? {
+            Some(response) => Ok(response),
+            None => Err(cancel(&client, &response.id, timeout).await),
+        }
+    }
+}
+
+/// Delete an asynchronous search that outlived its timeout, returning the timeout error
+async fn cancel(client: &crate::client::Client, id: &str, timeout: Duration) -> Error {
+    // The timeout is reported even when the search could not be deleted
+    if let Ok(request) = client.async_search().delete(id).build() {
+        if let Err(err) = request.send().await {
+            log::debug!("Failed to delete asynchronous search {}: {}", id, err);
+        }
+    }
+    still_running(id, timeout)
+}
+
+/// Get asynchronous search request
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
+pub struct GetAsyncSearchRequest<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync,
+{
+    /// Client reference
+    #[builder(private)]
+    client: crate::client::Client,
+
+    /// ID of the asynchronous search
+    #[builder(setter(into))]
+    id: String,
+
+    /// New time to keep the search and its results, such as `1d`
+    #[builder(setter(into, strip_option), default)]
+    keep_alive: Option<String>,
+
+    /// Type marker for the document type
+    #[builder(setter(skip), default = "std::marker::PhantomData")]
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> GetAsyncSearchRequest<T>
+where
+    T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    /// Send the request to the server
+    pub async fn send(self) -> Result<AsyncSearchResponse<T>, Error> {
+        let mut path = format!("/_plugins/_asynchronous_search/{}", self.id);
+
+        if let Some(keep_alive) = &self.keep_alive {
+            path = format!("{}?keep_alive={}", path, keep_alive);
+        }
+
+        self.client
+            .request::<(), AsyncSearchResponse<T>>(Method::GET, &path, None)
+            .await
+    }
+}
+
+/// Delete asynchronous search request
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
+pub struct DeleteAsyncSearchRequest {
+    /// Client reference
+    #[builder(private)]
+    client: crate::client::Client,
+
+    /// ID of the asynchronous search
+    #[builder(setter(into))]
+    id: String,
+}
+
+impl DeleteAsyncSearchRequest {
+    /// Send the request to the server
+    pub async fn send(self) -> Result<DeleteAsyncSearchResponse, Error> {
+        let path = format!("/_plugins/_asynchronous_search/{}", self.id);
+
+        self.client
+            .request::<(), DeleteAsyncSearchResponse>(Method::DELETE, &path, None)
+            .await
+    }
+}
+
+impl crate::client::Client {
+    /// Access the asynchronous search namespace
+    pub fn async_search(&self) -> AsyncSearchNamespace {
+        AsyncSearchNamespace::new(self.clone())
+    }
+}
@@ -61,214 +61,245 @@
 
 use crate::client::Client;
 use crate::error::Error;
-use crate::types::bulk::BulkResponse;
-use crate::types::common::RefreshPolicy;
+pub use crate::types::bulk::{
+    BulkAction, BulkDeleteMetadata, BulkDeleteMetadataBuilder, BulkIndexMetadata,
+    BulkIndexMetadataBuilder, BulkOperation, BulkParams, BulkParamsBuilder, BulkResponse,
+    BulkUpdateDocument, BulkUpdateDocumentBuilder, BulkUpdateMetadata, BulkUpdateMetadataBuilder,
+    IdStrategy,
+};
+use crate::types::bulk::{BulkResponseError, BulkResponseItem};
+use crate::types::common::{RefreshPolicy, VersionType};
+use crate::Document;
 use derive_builder::Builder;
+use futures::stream::{self, Stream, StreamExt};
 use reqwest::Method;
 use serde::Serialize;
-use serde_json::json;
+use std::fmt;
+use std::io::Write;
+use std::ops::Range;
+use std::time::Duration;
+use tokio::time::Instant;
 
 /// Bulk operations namespace
+///
+/// `T` is the type of indexed documents and partial updates, and `U` the type
+/// of upsert documents, the same as `T` unless the namespace is created with
+/// [`BulkNamespace::new`].
 #[derive(Debug, Clone)]
-pub struct BulkNamespace<T = serde_json::Value>
+pub struct BulkNamespace<T = serde_json::Value, U = T>
 where
     T: Serialize + Clone,
+    U: Serialize + Clone,
 {
     /// Client instance
     client: Client,
     /// Operations to be executed
-    operations: Vec<BulkOperation<T>>,
+    operations: Vec<BulkOperation<T, U>>,
     /// Optional parameters for the request
     params: Option<BulkParams>,
+    /// Maximum size in bytes of the body of each `_bulk` request
+    max_request_bytes: Option<usize>,
+    /// Strategy generating the IDs of index and create operations without one
+    id_strategy: Option<IdStrategy<T>>,
+    /// Error of an operation that could not be added, returned when sending
+    invalid_operation: Option<String>,
 }
 
-/// Bulk operation parameters
-#[derive(Debug, Clone, Default, Serialize, Builder)]
+/// Retry policy for bulk operations rejected by an overloaded cluster
+///
+/// Only operations that failed with HTTP 429 or `es_rejected_execution_exception`
+/// are sent again; any other failure is permanent.
+#[derive(Debug, Clone, Builder)]
 #[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
-pub struct BulkParams {
-    /// Refresh policy after the operation
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub refresh: Option<RefreshPolicy>,
+pub struct BulkRetryPolicy {
+    /// Maximum number of times a rejected operation is sent again
+    #[builder(default = "3")]
+    pub max_retries: u32,
+
+    /// Backoff before the first retry, doubled for every following one
+    #[builder(default = "Duration::from_millis(100)")]
+    pub initial_backoff: Duration,
+
+    /// Upper bound of the backoff between two retries
+    #[builder(default = "Duration::from_secs(10)")]
+    pub max_backoff: Duration,
+}
 
-    /// Index to use for items that don't provide one
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub index: Option<String>,
+impl BulkRetryPolicy {
+    /// Create a new builder for BulkRetryPolicy
+    pub fn builder() -> BulkRetryPolicyBuilder {
+        BulkRetryPolicyBuilder::default()
+    }
 
-    /// Routing value
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub routing: Option<String>,
+    /// Backoff before the given retry, starting at zero
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff)
+    }
+}
 
-    /// Timeout for the operation
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub timeout: Option<String>,
+impl Default for BulkRetryPolicy {
+    fn default() -> Self {
+        BulkRetryPolicy::builder()
+            .build()
+            .expect("all fields have defaults")
+    }
+}
 
-    /// Wait for active shards before proceeding
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub wait_for_active_shards: Option<String>,
+/// Configuration of [`BulkNamespace::from_stream`]
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct BulkStreamConfig {
+    /// Number of operations sent in each `_bulk` request, at least one
+    #[builder(default = "1000")]
+    pub chunk_size: usize,
 
-    /// Pipeline to use for processing the documents
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub pipeline: Option<String>,
+    /// Maximum number of chunks in flight at the same time, at least one
+    #[builder(default = "1")]
+    pub concurrency: usize,
+
+    /// Interval after which a partial chunk is sent, `None` to wait until it is full
+    #[builder(default = "Some(Duration::from_secs(5))")]
+    pub flush_interval: Option<Duration>,
 
-    /// Whether operations must target an alias
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Retry policy for operations rejected by the cluster
     #[builder(default)]
-    pub require_alias: Option<bool>,
+    pub retry: BulkRetryPolicy,
+}
+
+impl BulkStreamConfig {
+    /// Create a new builder for BulkStreamConfig
+    pub fn builder() -> BulkStreamConfigBuilder {
+        BulkStreamConfigBuilder::default()
+    }
 }
 
-impl BulkParams {
-    /// Create a new builder for BulkParams
-    pub fn builder() -> BulkParamsBuilder {
-        BulkParamsBuilder::default()
+impl Default for BulkStreamConfig {
+    fn default() -> Self {
+        BulkStreamConfig::builder()
+            .build()
+            .expect("all fields have defaults")
     }
 }
 
-/// Represents a bulk operation
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
-pub enum BulkOperation<T>
+/// A bulk operation that failed permanently, with the operation that caused it
+#[derive(Debug, Clone)]
+pub struct BulkFailure<T, U = T>
 where
     T: Serialize,
+    U: Serialize,
 {
-    /// Index a document (create if not exists, update if exists)
-    Index {
-        #[serde(rename = "index")]
-        metadata: BulkIndexMetadata,
-        #[serde(skip)]
-        document: T,
-    },
-
-    /// Create a document (fails if already exists)
-    Create {
-        #[serde(rename = "create")]
-        metadata: BulkIndexMetadata,
-        #[serde(skip)]
-        document: T,
-    },
-
-    /// Update an existing document
-    Update {
-        #[serde(rename = "update")]
-        metadata: BulkUpdateMetadata,
-        #[serde(skip)]
-        document: BulkUpdateDocument<T>,
-    },
-
-    /// Delete a document
-    Delete {
-        #[serde(rename = "delete")]
-        metadata: BulkDeleteMetadata,
-    },
-}
+    /// The operation as it was added to the request
+    pub operation: BulkOperation<T, U>,
 
-/// Metadata for bulk index and create operations
-#[derive(Debug, Clone, Serialize, Builder)]
-#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
-pub struct BulkIndexMetadata {
-    /// Target index
-    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub index: Option<String>,
+    /// HTTP status of the last attempt
+    pub status: u16,
 
-    /// Document ID
-    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub id: Option<String>,
+    /// Error reported for the last attempt
+    pub error: Option<BulkResponseError>,
+
+    /// Number of times the operation was sent
+    pub attempts: u32,
 }
 
-impl BulkIndexMetadata {
-    /// Create a new builder for BulkIndexMetadata
-    pub fn builder() -> BulkIndexMetadataBuilder {
-        BulkIndexMetadataBuilder::default()
+impl<T: Serialize, U: Serialize> BulkFailure<T, U> {
+    /// The document of the failed operation, if it carries one
+    pub fn document(&self) -> Option<&T> {
+        match &self.operation {
+            BulkOperation::Index { document, .. } | BulkOperation::Create { document, .. } => {
+                Some(document)
+            }
+            BulkOperation::Update { document, .. } => document.doc.as_ref(),
+            BulkOperation::Delete { .. } => None,
+        }
     }
 }
 
-/// Metadata for bulk update operations
-#[derive(Debug, Clone, Serialize, Builder)]
-#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
-pub struct BulkUpdateMetadata {
-    /// Target index
-    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub index: Option<String>,
-
-    /// Document ID
-    #[serde(rename = "_id")]
-    pub id: String,
-}
+/// Outcome of a bulk request sent with [`BulkNamespace::send_with_retry`]
+#[derive(Debug, Clone)]
+pub struct BulkRetryResponse<T, U = T>
+where
+    T: Serialize,
+    U: Serialize,
+{
+    /// Total time in milliseconds spent by all attempts
+    pub took: u64,
 
-impl BulkUpdateMetadata {
-    /// Create a new builder for BulkUpdateMetadata
-    pub fn builder() -> BulkUpdateMetadataBuilder {
-        BulkUpdateMetadataBuilder::default()
-    }
-}
+    /// Final response item of every operation, in the order they were added
+    pub items: Vec<BulkResponseItem>,
 
-/// Metadata for bulk delete operations
-#[derive(Debug, Clone, Serialize, Builder)]
-#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
-pub struct BulkDeleteMetadata {
-    /// Target index
-    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub index: Option<String>,
+    /// Operations that failed permanently or ran out of retries
+    pub failures: Vec<BulkFailure<T, U>>,
 
-    /// Document ID
-    #[serde(rename = "_id")]
-    pub id: String,
+    /// Number of operations that were sent again
+    pub retried: u64,
 }
 
-impl BulkDeleteMetadata {
-    /// Create a new builder for BulkDeleteMetadata
-    pub fn builder() -> BulkDeleteMetadataBuilder {
-        BulkDeleteMetadataBuilder::default()
+impl<T: Serialize, U: Serialize> BulkRetryResponse<T, U> {
+    /// Whether any operation failed
+    pub fn has_failures(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    /// Append the outcome of a following request
+    pub fn merge(&mut self, other: BulkRetryResponse<T, U>) {
+        self.took += other.took;
+        self.items.extend(other.items);
+        self.failures.extend(other.failures);
+        self.retried += other.retried;
     }
 }
 
-/// Update document wrapper for bulk update operations
-#[derive(Debug, Clone, Serialize, Builder)]
-#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
-pub struct BulkUpdateDocument<T>
+/// A chunk of [`BulkNamespace::from_stream`] that could not be sent
+///
+/// Holds the operations of the chunk that did not get a final response, so
+/// they can be sent again or written to a dead-letter sink. Operations that
+/// got one were applied or failed permanently before the error occurred.
+#[derive(Debug)]
+pub struct BulkStreamError<T, U = T>
 where
     T: Serialize,
+    U: Serialize,
 {
-    /// Document data to update
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub doc: Option<T>,
+    /// Operations of the chunk without a final response
+    pub operations: Vec<BulkOperation<T, U>>,
 
-    /// Whether this should be an upsert
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub doc_as_upsert: Option<bool>,
+    /// Error that stopped the chunk
+    pub error: Error,
+}
 
-    /// Script to execute
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[builder(default)]
-    pub script: Option<crate::types::script::Script>,
+impl<T: Serialize, U: Serialize> fmt::Display for BulkStreamError<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "bulk chunk failed with {} operations not sent: {}",
+            self.operations.len(),
+            self.error
+        )
+    }
 }
 
-impl<T: Serialize> BulkUpdateDocument<T> {
-    /// Create a new builder for BulkUpdateDocument
-    pub fn builder(doc: T) -> BulkUpdateDocumentBuilder<T> {
-        BulkUpdateDocumentBuilder::default().doc(doc)
+impl<T, U> std::error::Error for BulkStreamError<T, U>
+where
+    T: Serialize + fmt::Debug,
+    U: Serialize + fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
     }
+}
 
-    /// Create a new builder for script-based updates
-    pub fn script_builder(script: crate::types::script::Script) -> BulkUpdateDocumentBuilder<T> {
-        BulkUpdateDocumentBuilder::default().script(script)
+impl<T: Serialize, U: Serialize> From<BulkStreamError<T, U>> for Error {
+    fn from(err: BulkStreamError<T, U>) -> Self {
+        err.error
     }
 }
 
-impl<T> BulkNamespace<T>
+impl<T, U> BulkNamespace<T, U>
 where
     T: Serialize + Clone,
+    U: Serialize + Clone,
 {
     /// Create a new bulk namespace instance
     pub fn new(client: Client) -> Self {
@@ -276,9 +307,23 @@ where
             client,
             operations: Vec::new(),
             params: None,
+            max_request_bytes: None,
+            id_strategy: None,
+            invalid_operation: None,
         }
     }
 
+    /// Queue an operation, generating its ID first when a strategy is set
+    fn push(&mut self, mut operation: BulkOperation<T, U>) {
+        if let Some(strategy) = &self.id_strategy {
+            if let Err(err) = operation.assign_id(strategy) {
+                self.invalid_operation
+                    .get_or_insert(format!("failed to generate document ID: {}", err));
+            }
+        }
+        self.operations.push(operation);
+    }
+
     /// Add an index operation (create or update) to the bulk request
     ///
     /// # Arguments
@@ -294,10 +339,39 @@ where
         id: Option<impl Into<String>>,
         document: &T,
     ) -> Self {
-        self.operations.push(BulkOperation::Index {
+        self.push(BulkOperation::Index {
             metadata: BulkIndexMetadata {
                 index: Some(index.into()),
                 id: id.map(|id| id.into()),
+                ..Default::default()
+            },
+            document: document.clone(),
+        });
+        self
+    }
+
+    /// Add an index operation for a [`Document`] to the bulk request
+    ///
+    /// The index, ID, routing and version are taken from the document itself.
+    /// When the document has a version, it is indexed with `version_type=external`.
+    ///
+    /// # Arguments
+    /// * `document` - The document to index
+    ///
+    /// # Returns
+    /// The BulkNamespace with the added operation
+    pub fn index_doc(mut self, document: &T) -> Self
+    where
+        T: Document,
+    {
+        let version = document.version();
+        self.push(BulkOperation::Index {
+            metadata: BulkIndexMetadata {
+                index: Some(T::index_name().to_string()),
+                id: document.id(),
+                routing: document.routing(),
+                version,
+                version_type: version.map(|_| VersionType::External),
             },
             document: document.clone(),
         });
@@ -319,10 +393,11 @@ where
         id: Option<impl Into<String>>,
         document: &T,
     ) -> Self {
-        self.operations.push(BulkOperation::Create {
+        self.push(BulkOperation::Create {
             metadata: BulkIndexMetadata {
                 index: Some(index.into()),
                 id: id.map(|id| id.into()),
+                ..Default::default()
             },
             document: document.clone(),
         });
@@ -339,16 +414,16 @@ where
     /// # Returns
     /// The BulkNamespace with the added operation
     pub fn update(mut self, index: impl Into<String>, id: impl Into<String>, document: &T) -> Self {
-        self.operations.push(BulkOperation::Update {
+        self.push(BulkOperation::Update {
             metadata: BulkUpdateMetadata {
                 index: Some(index.into()),
                 id: id.into(),
+                ..Default::default()
             },
-            document: BulkUpdateDocument {
+            document: Box::new(BulkUpdateDocument {
                 doc: Some(document.clone()),
-                doc_as_upsert: None,
-                script: None,
-            },
+                ..Default::default()
+            }),
         });
         self
     }
@@ -368,20 +443,46 @@ where
         id: impl Into<String>,
         script: crate::types::script::Script,
     ) -> Self {
-        self.operations.push(BulkOperation::Update {
+        self.push(BulkOperation::Update {
             metadata: BulkUpdateMetadata {
                 index: Some(index.into()),
                 id: id.into(),
+                ..Default::default()
             },
-            document: BulkUpdateDocument {
-                doc: None,
-                doc_as_upsert: None,
+            document: Box::new(BulkUpdateDocument {
                 script: Some(script),
-            },
+                ..Default::default()
+            }),
         });
         self
     }
 
+    /// Add an update operation that inserts a full document when the target does not exist
+    ///
+    /// # Arguments
+    /// * `index` - The index containing the document
+    /// * `id` - Document ID to update
+    /// * `document` - The partial document applied to an existing document
+    /// * `upsert` - The document inserted when none exists
+    ///
+    /// # Returns
+    /// The BulkNamespace with the added operation
+    pub fn upsert(
+        mut self,
+        index: impl Into<String>,
+        id: impl Into<String>,
+        document: &T,
+        upsert: &U,
+    ) -> Self {
+        self.push(BulkOperation::upsert(
+            Some(index.into()),
+            id.into(),
+            document.clone(),
+            upsert.clone(),
+        ));
+        self
+    }
+
     /// Add a delete operation to the bulk request
     ///
     /// # Arguments
@@ -390,12 +491,12 @@ where
     ///
     /// # Returns
     /// The BulkNamespace with the added operation
-    pub fn delete<U: Serialize + Clone>(
+    pub fn delete<D: Serialize + Clone>(
         mut self,
         index: impl Into<String>,
         id: impl Into<String>,
     ) -> Self {
-        self.operations.push(BulkOperation::Delete {
+        self.push(BulkOperation::Delete {
             metadata: BulkDeleteMetadata {
                 index: Some(index.into()),
                 id: id.into(),
@@ -421,7 +522,7 @@ where
 
         let metadata = configure(builder).build().unwrap();
 
-        self.operations.push(BulkOperation::Index {
+        self.push(BulkOperation::Index {
             metadata,
             document: document.clone(),
         });
@@ -445,7 +546,7 @@ where
 
         let metadata = configure(builder).build().unwrap();
 
-        self.operations.push(BulkOperation::Create {
+        self.push(BulkOperation::Create {
             metadata,
             document: document.clone(),
         });
@@ -467,13 +568,12 @@ where
 
         let metadata = configure(builder).build().unwrap();
 
-        self.operations.push(BulkOperation::Update {
+        self.push(BulkOperation::Update {
             metadata,
-            document: BulkUpdateDocument {
+            document: Box::new(BulkUpdateDocument {
                 doc: Some(document.to_owned()),
-                doc_as_upsert: None,
-                script: None,
-            },
+                ..Default::default()
+            }),
         });
 
         self
@@ -531,36 +631,42 @@ where
         mut self,
         index: impl Into<String>,
         id: impl Into<String>,
-        document: impl FnOnce(BulkUpdateDocumentBuilder<T>) -> BulkUpdateDocumentBuilder<T>,
+        document: impl FnOnce(BulkUpdateDocumentBuilder<T, U>) -> BulkUpdateDocumentBuilder<T, U>,
     ) -> Self {
         let metadata = BulkUpdateMetadata {
             index: Some(index.into()),
             id: id.into(),
+            ..Default::default()
         };
 
         // Start with a default builder and let the caller configure it
-        let doc_builder = document(BulkUpdateDocumentBuilder::default());
-        let update_doc = doc_builder.build().unwrap();
-
-        self.operations.push(BulkOperation::Update {
-            metadata,
-            document: update_doc,
-        });
+        match document(BulkUpdateDocumentBuilder::default()).build() {
+            Ok(update_doc) => self.push(BulkOperation::Update {
+                metadata,
+                document: Box::new(update_doc),
+            }),
+            Err(err) => {
+                self.invalid_operation.get_or_insert(err.to_string());
+            }
+        }
 
         self
     }
 
     /// Add a delete operation with additional metadata configuration
-    pub fn delete_with_metadata<U: Serialize + Clone>(
+    pub fn delete_with_metadata<D: Serialize + Clone>(
         self,
         index: impl Into<String>,
         id: impl Into<String>,
         configure: impl FnOnce(BulkDeleteMetadataBuilder) -> BulkDeleteMetadataBuilder,
-    ) -> BulkNamespace<U> {
+    ) -> BulkNamespace<D> {
         let mut new_namespace = BulkNamespace {
             client: self.client.clone(),
             operations: Vec::new(),
             params: self.params,
+            max_request_bytes: self.max_request_bytes,
+            id_strategy: None,
+            invalid_operation: self.invalid_operation,
         };
 
         let builder = BulkDeleteMetadataBuilder::default()
@@ -630,162 +736,652 @@ where
         self
     }
 
+    /// Split the operations into several `_bulk` requests of at most this many bytes
+    ///
+    /// The size of each operation is measured on its serialized NDJSON lines,
+    /// so requests stay under the cluster's `http.max_content_length`. The
+    /// responses of all requests are merged into one, with the items in the
+    /// order the operations were added. Sending fails before any request is
+    /// made when a single operation is larger than the limit.
+    ///
+    /// Requests are sent one after the other, so sending is not atomic across
+    /// them: when a request fails, the operations of the previous ones were
+    /// already applied and only the error is returned. Streams sent with
+    /// [`BulkNamespace::from_stream`] are split the same way, yielding the
+    /// outcome of every request.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let mut bulk = client.bulk().max_request_bytes(10 * 1024 * 1024);
+    ///     for i in 0..100_000 {
+    ///         bulk = bulk.index("my_index", None::<String>, &json!({"body": "x".repeat(i % 1000)}));
+    ///     }
+    ///     let response = bulk.send().await?;
+    ///     assert_eq!(response.items.len(), 100_000);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn max_request_bytes(mut self, max_request_bytes: usize) -> Self {
+        self.max_request_bytes = Some(max_request_bytes);
+        self
+    }
+
+    /// Generate the IDs of index and create operations added without one
+    ///
+    /// IDs are generated on the client as operations are added, including the
+    /// ones already added, and stored on the operations themselves. Operations
+    /// retried by [`BulkNamespace::send_with_retry`], sent again from a clone of
+    /// the bulk request or replayed from a dead-letter sink therefore overwrite
+    /// the same document rather than indexing a duplicate.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use opensearch_api::bulk::{BulkRetryPolicy, IdStrategy};
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let response = client
+    ///         .bulk()
+    ///         .id_strategy(IdStrategy::UuidV7)
+    ///         .index("events", None::<String>, &json!({"event": "login"}))
+    ///         .index("events", None::<String>, &json!({"event": "logout"}))
+    ///         .send_with_retry(BulkRetryPolicy::default())
+    ///         .await?;
+    ///     assert!(!response.has_failures());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn id_strategy(mut self, id_strategy: IdStrategy<T>) -> Self {
+        self.id_strategy = Some(id_strategy);
+        let queued = std::mem::take(&mut self.operations);
+        for operation in queued {
+            self.push(operation);
+        }
+        self
+    }
+
     /// Add multiple operations at once
-    pub fn operations(mut self, operations: Vec<BulkOperation<T>>) -> Self {
-        self.operations.extend(operations);
+    pub fn operations(mut self, operations: Vec<BulkOperation<T, U>>) -> Self {
+        for operation in operations {
+            self.push(operation);
+        }
         self
     }
 
-    /// Execute the bulk request with all added operations
-    pub async fn send(self) -> Result<BulkResponse, Error> {
-        let mut path = String::from("/_bulk");
+    /// Write the request body to an NDJSON writer, in the format sent by [`BulkNamespace::send`]
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    /// use serde_json::json;
+    /// use std::fs::File;
+    ///
+    /// fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let bulk = client
+    ///         .bulk()
+    ///         .index("my_index", Some("doc1"), &json!({"field": "value1"}))
+    ///         .delete::<serde_json::Value>("my_index", "doc2");
+    ///     bulk.write_ndjson(File::create("fixture.ndjson")?)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn write_ndjson<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_all(self.to_ndjson()?.as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
 
-        // Add query parameters
-        if let Some(params) = self.params {
-            let mut query_parts = Vec::new();
+    /// Build the NDJSON request body, in the format sent by [`BulkNamespace::send`]
+    pub fn to_ndjson(&self) -> Result<String, Error> {
+        self.check_operations()?;
+        let mut payload = String::new();
+        for operation in &self.operations {
+            operation.write_ndjson(&mut payload)?;
+        }
+        Ok(payload)
+    }
 
-            if let Some(refresh) = params.refresh {
-                query_parts.push(format!("refresh={}", refresh));
-            }
+    /// Fail when an operation could not be built
+    fn check_operations(&self) -> Result<(), Error> {
+        match &self.invalid_operation {
+            Some(err) => Err(Error::validation(err.clone())),
+            None => Ok(()),
+        }
+    }
 
-            if let Some(index) = params.index {
-                query_parts.push(format!("index={}", index));
+    /// Execute the bulk request with all added operations
+    ///
+    /// When the request is split with [`BulkNamespace::max_request_bytes`], an
+    /// error in one of the requests is returned without the responses of the
+    /// previous ones, whose operations were already applied.
+    pub async fn send(self) -> Result<BulkResponse, Error> {
+        self.check_operations()?;
+        let path = bulk_path(self.params.as_ref());
+        let entries = serialize_entries(&self.operations)?;
+
+        let mut merged: Option<BulkResponse> = None;
+        for chunk in chunk_ranges(&entries, self.max_request_bytes)? {
+            // Build the NDJSON payload
+            let payload = entries[chunk].concat();
+
+            // Set headers for NDJSON content
+            let headers = vec![("Content-Type", "application/x-ndjson")];
+
+            // Send the request using the client's request method
+            let response: BulkResponse = self
+                .client
+                .request_with_headers(Method::POST, &path, Some(payload), Some(headers))
+                .await?;
+            match &mut merged {
+                Some(merged) => merged.merge(response),
+                None => merged = Some(response),
             }
+        }
 
-            if let Some(routing) = params.routing {
-                query_parts.push(format!("routing={}", routing));
-            }
+        Ok(merged.expect("there is always at least one chunk"))
+    }
 
-            if let Some(timeout) = params.timeout {
-                query_parts.push(format!("timeout={}", timeout));
+    /// Execute the bulk request, retrying operations rejected by the cluster
+    ///
+    /// Each response item is matched back to the operation it belongs to.
+    /// Rejected operations are sent again with exponential backoff until they
+    /// succeed or the policy runs out of retries. Permanent failures, such as
+    /// mapping errors or version conflicts, are returned with their operation.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use opensearch_api::bulk::BulkRetryPolicy;
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let response = client
+    ///         .bulk()
+    ///         .index("my_index", Some("doc1"), &json!({"field": "value1"}))
+    ///         .index("my_index", Some("doc2"), &json!({"field": "value2"}))
+    ///         .send_with_retry(BulkRetryPolicy::builder().max_retries(5u32).build()?)
+    ///         .await?;
+    ///
+    ///     for failure in &response.failures {
+    ///         println!("{:?} failed: {:?}", failure.document(), failure.error);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_with_retry(
+        self,
+        policy: BulkRetryPolicy,
+    ) -> Result<BulkRetryResponse<T, U>, Error> {
+        self.check_operations()?;
+        let path = bulk_path(self.params.as_ref());
+        let entries = serialize_entries(&self.operations)?;
+        let chunks = chunk_ranges(&entries, self.max_request_bytes)?;
+
+        let mut operations = self.operations.into_iter();
+        let mut merged: Option<BulkRetryResponse<T, U>> = None;
+        for chunk in chunks {
+            let chunk_operations = operations.by_ref().take(chunk.len()).collect();
+            let response = send_operations(
+                &self.client,
+                &path,
+                chunk_operations,
+                &entries[chunk],
+                &policy,
+            )
+            .await?;
+            match &mut merged {
+                Some(merged) => merged.merge(response),
+                None => merged = Some(response),
             }
+        }
 
-            if let Some(wait_for_active_shards) = params.wait_for_active_shards {
-                query_parts.push(format!("wait_for_active_shards={}", wait_for_active_shards));
-            }
+        Ok(merged.expect("there is always at least one chunk"))
+    }
 
-            if let Some(pipeline) = params.pipeline {
-                query_parts.push(format!("pipeline={}", pipeline));
-            }
+    /// Send the operations of a stream in chunks, yielding the outcome of every chunk
+    ///
+    /// The stream is consumed lazily: at most `concurrency` chunks are read
+    /// ahead and in flight, so a slow cluster slows down the source instead of
+    /// buffering it in memory. A chunk is sent once it holds `chunk_size`
+    /// operations, or when `flush_interval` has elapsed since its first one, so
+    /// a long-lived source sending few operations does not hold them back.
+    /// Operations already added to the bulk request are sent first. A chunk
+    /// over [`BulkNamespace::max_request_bytes`] is split into several requests.
+    /// One result is yielded per request in the order of the chunks, and every
+    /// request is retried according to the configured policy. A request that
+    /// could not be sent is yielded as a [`BulkStreamError`] holding its operations.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use opensearch_api::bulk::{BulkOperation, BulkStreamConfig};
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let operations = futures::stream::iter(0..10_000).map(|i| {
+    ///         BulkOperation::index(Some("events".to_string()), None, json!({"event": i}))
+    ///     });
+    ///
+    ///     let config = BulkStreamConfig::builder()
+    ///         .chunk_size(500usize)
+    ///         .concurrency(4usize)
+    ///         .build()?;
+    ///     let mut results = Box::pin(client.bulk().from_stream(operations, config));
+    ///     while let Some(result) = results.next().await {
+    ///         match result {
+    ///             Ok(response) => println!("{} failed operations", response.failures.len()),
+    ///             Err(err) => println!("{} operations not sent: {}", err.operations.len(), err.error),
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_stream<S>(
+        self,
+        operations: S,
+        config: BulkStreamConfig,
+    ) -> impl Stream<Item = Result<BulkRetryResponse<T, U>, BulkStreamError<T, U>>>
+    where
+        S: Stream<Item = BulkOperation<T, U>>,
+    {
+        let client = self.client;
+        let path = bulk_path(self.params.as_ref());
+        let retry = config.retry;
+        let max_request_bytes = self.max_request_bytes;
+        let id_strategy = self.id_strategy;
+        let invalid_operation = self.invalid_operation;
+
+        // Operations of the stream get their ID as they are read, like added ones
+        let operations = operations.map(move |mut operation| {
+            let assigned = match &id_strategy {
+                Some(strategy) => operation.assign_id(strategy),
+                None => Ok(()),
+            };
+            (operation, assigned)
+        });
 
-            if let Some(require_alias) = params.require_alias {
-                query_parts.push(format!("require_alias={}", require_alias));
-            }
+        let added = self
+            .operations
+            .into_iter()
+            .map(|operation| (operation, Ok(())));
+        let chunks = chunks_within(
+            stream::iter(added).chain(operations),
+            config.chunk_size.max(1),
+            config.flush_interval,
+        );
+        chunks
+            .flat_map(move |chunk| {
+                stream::iter(stream_requests(
+                    chunk,
+                    invalid_operation.as_deref(),
+                    max_request_bytes,
+                ))
+            })
+            .map(move |request| {
+                let client = client.clone();
+                let path = path.clone();
+                let retry = retry.clone();
+                async move {
+                    let request = request?;
+                    send_operations(&client, &path, request.operations, &request.entries, &retry)
+                        .await
+                }
+            })
+            .buffered(config.concurrency.max(1))
+    }
 
-            if !query_parts.is_empty() {
-                path.push('?');
-                path.push_str(&query_parts.join("&"));
+    /// Index the documents of a stream in chunks, yielding the outcome of every chunk
+    ///
+    /// `id` derives the document ID from each document, returning `None` to
+    /// fall back to the [`IdStrategy`] of the bulk request, or to let
+    /// OpenSearch generate one without it. See [`BulkNamespace::from_stream`].
+    pub fn from_document_stream<S, F>(
+        self,
+        index: impl Into<String>,
+        documents: S,
+        id: F,
+        config: BulkStreamConfig,
+    ) -> impl Stream<Item = Result<BulkRetryResponse<T, U>, BulkStreamError<T, U>>>
+    where
+        S: Stream<Item = T>,
+        F: Fn(&T) -> Option<String>,
+    {
+        let index = index.into();
+        let operations = documents.map(move |document| BulkOperation::Index {
+            metadata: BulkIndexMetadata {
+                index: Some(index.clone()),
+                id: id(&document),
+                ..Default::default()
+            },
+            document,
+        });
+        self.from_stream(operations, config)
+    }
+}
+
+/// Group the items of a stream in chunks of at most `size` items
+///
+/// With an interval, a partial chunk is yielded once the interval has elapsed
+/// since its first item instead of waiting for more items.
+fn chunks_within<S: Stream>(
+    items: S,
+    size: usize,
+    interval: Option<Duration>,
+) -> impl Stream<Item = Vec<S::Item>> {
+    stream::unfold(Box::pin(items.fuse()), move |mut items| async move {
+        let mut chunk = vec![items.next().await?];
+        let deadline = interval.map(|interval| Instant::now() + interval);
+        while chunk.len() < size {
+            let next = match deadline {
+                Some(deadline) => match tokio::time::timeout_at(deadline, items.next()).await {
+                    Ok(next) => next,
+                    Err(_) => break,
+                },
+                None => items.next().await,
+            };
+            match next {
+                Some(item) => chunk.push(item),
+                None => break,
             }
         }
+        Some((chunk, items))
+    })
+}
 
+/// Operations of a stream sent in one `_bulk` request, with their NDJSON entries
+struct StreamRequest<T: Serialize, U: Serialize> {
+    operations: Vec<BulkOperation<T, U>>,
+    entries: Vec<String>,
+}
 
-        // Build the NDJSON payload
-        let mut payload = String::new();
-        for operation in self.operations {
-            match operation {
-                BulkOperation::Index { metadata, document } => {
-                    // Add action line
-                    payload.push_str(&serde_json::to_string(&json!({ "index": metadata }))?);
-                    payload.push('\n');
-                    // Add document data
-                    payload.push_str(&serde_json::to_string(&document)?);
-                    payload.push('\n');
-                }
-                BulkOperation::Create { metadata, document } => {
-                    // Add action line
-                    payload.push_str(&serde_json::to_string(&json!({ "create": metadata }))?);
-                    payload.push('\n');
-                    // Add document data
-                    payload.push_str(&serde_json::to_string(&document)?);
-                    payload.push('\n');
-                }
-                BulkOperation::Update { metadata, document } => {
-                    // Add action line
-                    payload.push_str(&serde_json::to_string(&json!({ "update": metadata }))?);
-                    payload.push('\n');
-                    // Add document data
-                    payload.push_str(&serde_json::to_string(&document)?);
-                    payload.push('\n');
-                }
-                BulkOperation::Delete { metadata } => {
-                    // Add action line (no document for delete)
-                    payload.push_str(&serde_json::to_string(&json!({ "delete": metadata }))?);
-                    payload.push('\n');
-                }
-            }
+/// Prepare the requests sending a chunk of stream operations
+///
+/// The chunk is split into several requests when it is over `max_bytes`.
+fn stream_requests<T: Serialize, U: Serialize>(
+    chunk: Vec<(BulkOperation<T, U>, Result<(), Error>)>,
+    invalid_operation: Option<&str>,
+    max_bytes: Option<usize>,
+) -> Vec<Result<StreamRequest<T, U>, BulkStreamError<T, U>>> {
+    let mut error = invalid_operation.map(Error::validation);
+    let mut operations = Vec::with_capacity(chunk.len());
+    for (operation, assigned) in chunk {
+        if let Err(err) = assigned {
+            error.get_or_insert(err);
         }
+        operations.push(operation);
+    }
 
+    let prepared = match error {
+        Some(error) => Err(error),
+        None => serialize_entries(&operations)
+            .and_then(|entries| chunk_ranges(&entries, max_bytes).map(|ranges| (ranges, entries))),
+    };
+    let (ranges, entries) = match prepared {
+        Ok(prepared) => prepared,
+        Err(error) => return vec![Err(BulkStreamError { operations, error })],
+    };
+
+    let mut operations = operations.into_iter();
+    let mut entries = entries.into_iter();
+    ranges
+        .into_iter()
+        .map(|range| {
+            Ok(StreamRequest {
+                operations: operations.by_ref().take(range.len()).collect(),
+                entries: entries.by_ref().take(range.len()).collect(),
+            })
+        })
+        .collect()
+}
 
-        // Set headers for NDJSON content
-        let headers = vec![("Content-Type", "application/x-ndjson")];
+/// Serialize every operation to its own NDJSON entry
+fn serialize_entries<T: Serialize, U: Serialize>(
+    operations: &[BulkOperation<T, U>],
+) -> Result<Vec<String>, Error> {
+    operations
+        .iter()
+        .map(|operation| {
+            let mut entry = String::new();
+            operation.write_ndjson(&mut entry)?;
+            Ok(entry)
+        })
+        .collect()
+}
 
-        // Send the request using the client's request method
-        self.client
-            .request_with_headers(Method::POST, &path, Some(payload), Some(headers))
-            .await
+/// Split entries into consecutive ranges whose total size stays within the limit
+///
+/// Always returns at least one range, and a single range without a limit.
+fn chunk_ranges(entries: &[String], max_bytes: Option<usize>) -> Result<Vec<Range<usize>>, Error> {
+    let mut chunks = Vec::new();
+    let Some(max_bytes) = max_bytes else {
+        chunks.push(0..entries.len());
+        return Ok(chunks);
+    };
+
+    let mut start = 0;
+    let mut size = 0;
+    for (position, entry) in entries.iter().enumerate() {
+        if entry.len() > max_bytes {
+            return Err(Error::validation(format!(
+                "bulk operation {} is {} bytes, over the {} bytes request limit",
+                position,
+                entry.len(),
+                max_bytes
+            )));
+        }
+        if size + entry.len() > max_bytes {
+            chunks.push(start..position);
+            start = position;
+            size = 0;
+        }
+        size += entry.len();
     }
+    chunks.push(start..entries.len());
+    Ok(chunks)
 }
 
-impl<T> BulkOperation<T>
-where
-    T: Serialize,
-{
-    /// Create an index operation
-    pub fn index(index: Option<String>, id: Option<String>, document: T) -> Self {
-        BulkOperation::Index {
-            metadata: BulkIndexMetadata { index, id },
-            document,
-        }
+/// Send operations with retries, matching every response item to its operation
+async fn send_operations<T: Serialize, U: Serialize>(
+    client: &Client,
+    path: &str,
+    operations: Vec<BulkOperation<T, U>>,
+    entries: &[String],
+    policy: &BulkRetryPolicy,
+) -> Result<BulkRetryResponse<T, U>, BulkStreamError<T, U>> {
+    let run = send_entries(client, path, entries, policy, |_| {}).await;
+    if let Some(error) = run.error {
+        // Operations with a final item were applied or failed permanently
+        let operations = operations
+            .into_iter()
+            .zip(run.items)
+            .filter_map(|(operation, item)| item.is_none().then_some(operation))
+            .collect();
+        return Err(BulkStreamError { operations, error });
     }
 
-    /// Create a create operation
-    pub fn create(index: Option<String>, id: Option<String>, document: T) -> Self {
-        BulkOperation::Create {
-            metadata: BulkIndexMetadata { index, id },
-            document,
+    let mut items = Vec::with_capacity(entries.len());
+    let mut failures = Vec::new();
+    for ((operation, item), attempts) in operations.into_iter().zip(run.items).zip(run.attempts) {
+        let item = item.expect("every operation has a final item when no error occurred");
+        if item.is_failed() {
+            let response = item.response();
+            failures.push(BulkFailure {
+                operation,
+                status: response.map_or(0, |response| response.status),
+                error: response.and_then(|response| response.error.clone()),
+                attempts,
+            });
         }
+        items.push(item);
     }
 
-    /// Create an update operation
-    pub fn update(index: Option<String>, id: String, document: T) -> Self {
-        BulkOperation::Update {
-            metadata: BulkUpdateMetadata {
-                index,
-                id,
-            },
-            document: BulkUpdateDocument {
-                doc: Some(document),
-                doc_as_upsert: None,
-                script: None,
-            },
+    Ok(BulkRetryResponse {
+        took: run.took,
+        items,
+        failures,
+        retried: run.retried,
+    })
+}
+
+/// Result of sending serialized operations with retries
+pub(crate) struct BulkRetryRun {
+    /// Total time in milliseconds reported by all attempts
+    pub(crate) took: u64,
+    /// Final response item of every entry, `None` when an error interrupted it
+    pub(crate) items: Vec<Option<BulkResponseItem>>,
+    /// Number of times every entry was sent
+    pub(crate) attempts: Vec<u32>,
+    /// Number of entries that were sent again
+    pub(crate) retried: u64,
+    /// Error that stopped the run before every entry got a final item
+    pub(crate) error: Option<Error>,
+}
+
+/// Send the NDJSON entries of bulk operations, retrying the rejected ones
+///
+/// `on_request` is called with the payload size of every request sent.
+pub(crate) async fn send_entries(
+    client: &Client,
+    path: &str,
+    entries: &[String],
+    policy: &BulkRetryPolicy,
+    mut on_request: impl FnMut(usize),
+) -> BulkRetryRun {
+    let mut run = BulkRetryRun {
+        took: 0,
+        items: vec![None; entries.len()],
+        attempts: vec![0; entries.len()],
+        retried: 0,
+        error: None,
+    };
+    let mut pending: Vec<usize> = (0..entries.len()).collect();
+    let mut retry = 0;
+
+    while !pending.is_empty() {
+        let mut payload = String::new();
+        for &position in &pending {
+            payload.push_str(&entries[position]);
+            run.attempts[position] += 1;
         }
-    }
+        on_request(payload.len());
 
-    /// Create a script-based update operation
-    pub fn update_with_script(
-        index: Option<String>,
-        id: String,
-        script: crate::types::script::Script,
-    ) -> Self {
-        BulkOperation::Update {
-            metadata: BulkUpdateMetadata {
-                index,
-                id,
-            },
-            document: BulkUpdateDocument {
-                doc: None,
-                doc_as_upsert: None,
-                script: Some(script),
-            },
+        let headers = vec![("Content-Type", "application/x-ndjson")];
+        let result = client
+            .request_with_headers::<String, BulkResponse>(
+                Method::POST,
+                path,
+                Some(payload),
+                Some(headers),
+            )
+            .await;
+        let can_retry = retry < policy.max_retries;
+
+        pending = match result {
+            Ok(response) if response.items.len() != pending.len() => {
+                run.error = Some(Error::BulkItemsMismatch {
+                    expected: pending.len(),
+                    received: response.items.len(),
+                });
+                return run;
+            }
+            Ok(response) => {
+                run.took += response.took;
+                let mut rejected = Vec::new();
+                // Items are returned in the order the operations were sent
+                for (position, item) in pending.into_iter().zip(response.items) {
+                    if can_retry && item.is_retryable() {
+                        rejected.push(position);
+                    } else {
+                        run.items[position] = Some(item);
+                    }
+                }
+                rejected
+            }
+            // The whole request was rejected
+            Err(Error::ApiError {
+                status_code: 429, ..
+            }) if can_retry => pending,
+            Err(err) => {
+                run.error = Some(err);
+                return run;
+            }
+        };
+
+        if !pending.is_empty() {
+            log::debug!("Retrying {} rejected bulk operations", pending.len());
+            run.retried += pending.len() as u64;
+            tokio::time::sleep(policy.backoff(retry)).await;
+            retry += 1;
         }
     }
 
-    /// Create a delete operation
-    pub fn delete(index: Option<String>, id: String) -> Self {
-        BulkOperation::Delete {
-            metadata: BulkDeleteMetadata { index, id },
+    run
+}
+
+/// Build the `_bulk` request path, adding the parameters as query string
+pub(crate) fn bulk_path(params: Option<&BulkParams>) -> String {
+    let mut path = String::from("/_bulk");
+
+    // Add query parameters
+    if let Some(params) = params {
+        let mut query_parts = Vec::new();
+
+        if let Some(refresh) = &params.refresh {
+            query_parts.push(format!("refresh={}", refresh));
+        }
+
+        if let Some(index) = &params.index {
+            query_parts.push(format!("index={}", index));
+        }
+
+        if let Some(routing) = &params.routing {
+            query_parts.push(format!("routing={}", routing));
+        }
+
+        if let Some(timeout) = &params.timeout {
+            query_parts.push(format!("timeout={}", timeout));
+        }
+
+        if let Some(wait_for_active_shards) = &params.wait_for_active_shards {
+            query_parts.push(format!("wait_for_active_shards={}", wait_for_active_shards));
+        }
+
+        if let Some(pipeline) = &params.pipeline {
+            query_parts.push(format!("pipeline={}", pipeline));
+        }
+
+        if let Some(require_alias) = params.require_alias {
+            query_parts.push(format!("require_alias={}", require_alias));
+        }
+
+        if !query_parts.is_empty() {
+            path.push('?');
+            path.push_str(&query_parts.join("&"));
         }
     }
+
+    path
 }
 
 impl crate::client::Client {
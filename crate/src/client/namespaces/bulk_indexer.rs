@@ -0,0 +1,458 @@
+//! Auto-flushing bulk indexer for OpenSearch
+//!
+//! [`BulkIndexer`] is a long-lived, clonable handle that accepts bulk operations
+//! one at a time from any number of tasks. Operations are buffered by a
+//! background worker and sent as `_bulk` requests whenever the buffer reaches a
+//! document count or byte size, or when the flush interval elapses. Up to
+//! `concurrency` requests are in flight at the same time. Operations rejected
+//! by an overloaded cluster are retried according to the configured
+//! [`BulkRetryPolicy`]; operations that fail permanently can be kept in a
+//! [`DeadLetterSink`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use opensearch_api::bulk::BulkOperation;
+//! use opensearch_api::bulk_indexer::BulkIndexerConfig;
+//! use opensearch_api::{Client, ClientConfig, Error};
+//! use serde_json::json;
+//! use std::time::Duration;
+//!
+//! async fn example() -> Result<(), Error> {
+//!     let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+//!     let client = Client::builder().config(config).build()?;
+//!
+//!     let indexer = client.bulk_indexer(
+//!         BulkIndexerConfig::builder()
+//!             .flush_documents(500usize)
+//!             .flush_interval(Duration::from_secs(1))
+//!             .concurrency(4usize)
+//!             .build()?,
+//!     )?;
+//!
+//!     for i in 0..10_000 {
+//!         let document = json!({"message": format!("event {}", i)});
+//!         indexer
+//!             .add(BulkOperation::index(Some("events".to_string()), None, document))
+//!             .await?;
+//!     }
+//!
+//!     let stats = indexer.close().await?;
+//!     println!("Indexed {} documents, {} failed", stats.indexed, stats.failed);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::client::namespaces::bulk::{
+    bulk_path, send_entries, BulkOperation, BulkParams, BulkRetryPolicy,
+};
+use crate::client::namespaces::dead_letter::{DeadLetter, DeadLetterSink};
+use crate::client::Client;
+use crate::error::Error;
+use derive_builder::Builder;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, Semaphore};
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+
+/// Configuration for a [`BulkIndexer`]
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+pub struct BulkIndexerConfig {
+    /// Number of buffered operations that triggers a flush
+    #[builder(default = "1000")]
+    pub flush_documents: usize,
+
+    /// Size in bytes of the buffered payload that triggers a flush
+    #[builder(default = "5 * 1024 * 1024")]
+    pub flush_bytes: usize,
+
+    /// Interval after which buffered operations are flushed, `None` to only flush on size
+    #[builder(default = "Some(Duration::from_secs(5))")]
+    pub flush_interval: Option<Duration>,
+
+    /// Maximum number of concurrent in-flight `_bulk` requests
+    #[builder(default = "1")]
+    pub concurrency: usize,
+
+    /// Parameters sent with every `_bulk` request
+    #[builder(default)]
+    pub params: Option<BulkParams>,
+
+    /// Retry policy for operations rejected by the cluster
+    #[builder(default)]
+    pub retry: BulkRetryPolicy,
+
+    /// Sink receiving the operations that failed permanently
+    #[builder(default, setter(custom))]
+    pub dead_letter: Option<Arc<dyn DeadLetterSink>>,
+}
+
+impl BulkIndexerConfig {
+    /// Create a new builder for BulkIndexerConfig
+    pub fn builder() -> BulkIndexerConfigBuilder {
+        BulkIndexerConfigBuilder::default()
+    }
+}
+
+impl BulkIndexerConfigBuilder {
+    /// Write the operations that failed permanently to a dead-letter sink
+    pub fn dead_letter(mut self, sink: impl DeadLetterSink + 'static) -> Self {
+        self.dead_letter = Some(Some(Arc::new(sink)));
+        self
+    }
+}
+
+impl Default for BulkIndexerConfig {
+    fn default() -> Self {
+        BulkIndexerConfig::builder()
+            .build()
+            .expect("all fields have defaults")
+    }
+}
+
+/// Aggregate statistics of a [`BulkIndexer`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BulkIndexerStats {
+    /// Number of operations added to the indexer
+    pub added: u64,
+
+    /// Number of operations that succeeded
+    pub indexed: u64,
+
+    /// Number of operations that failed
+    pub failed: u64,
+
+    /// Number of operations that were sent again after a failure
+    pub retried: u64,
+
+    /// Number of `_bulk` requests sent
+    pub requests: u64,
+
+    /// Number of payload bytes sent
+    pub bytes: u64,
+}
+
+/// Counters shared between the indexer handles and the in-flight requests
+#[derive(Debug, Default)]
+struct Counters {
+    added: AtomicU64,
+    indexed: AtomicU64,
+    failed: AtomicU64,
+    retried: AtomicU64,
+    requests: AtomicU64,
+    bytes: AtomicU64,
+}
+
+impl Counters {
+    fn snapshot(&self) -> BulkIndexerStats {
+        BulkIndexerStats {
+            added: self.added.load(Ordering::Relaxed),
+            indexed: self.indexed.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            retried: self.retried.load(Ordering::Relaxed),
+            requests: self.requests.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Messages sent from the handles to the background worker
+#[derive(Debug)]
+enum Command {
+    /// Buffer the NDJSON lines of one operation
+    Add(String),
+    /// Send everything buffered and wait for in-flight requests
+    Flush(oneshot::Sender<()>),
+    /// Flush, stop accepting operations and shut the worker down
+    Close(oneshot::Sender<()>),
+}
+
+/// Clonable handle for concurrent, auto-flushing bulk ingestion
+///
+/// The background worker is spawned on the current Tokio runtime when the
+/// indexer is created, and keeps running until [`BulkIndexer::close`] is
+/// called or every handle is dropped.
+#[derive(Debug, Clone)]
+pub struct BulkIndexer {
+    sender: mpsc::Sender<Command>,
+    counters: Arc<Counters>,
+}
+
+impl BulkIndexer {
+    /// Create a new bulk indexer and spawn its background worker
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn new(client: Client, config: BulkIndexerConfig) -> Result<Self, Error> {
+        if config.flush_documents == 0 {
+            return Err(Error::InvalidConfiguration(
+                "flush_documents must be greater than zero".to_string(),
+            ));
+        }
+        if config.concurrency == 0 {
+            return Err(Error::InvalidConfiguration(
+                "concurrency must be greater than zero".to_string(),
+            ));
+        }
+
+        let (sender, receiver) = mpsc::channel(config.flush_documents);
+        let counters = Arc::new(Counters::default());
+        let worker = Worker {
+            client,
+            path: bulk_path(config.params.as_ref()),
+            semaphore: Arc::new(Semaphore::new(config.concurrency)),
+            counters: counters.clone(),
+            buffer: Vec::new(),
+            buffered_bytes: 0,
+            config,
+        };
+        tokio::spawn(worker.run(receiver));
+
+        Ok(BulkIndexer { sender, counters })
+    }
+
+    /// Add an operation to the indexer
+    ///
+    /// Waits when the buffer is full and all requests are in flight.
+    pub async fn add<T: Serialize>(&self, operation: BulkOperation<T>) -> Result<(), Error> {
+        let mut entry = String::new();
+        operation.write_ndjson(&mut entry)?;
+
+        self.sender
+            .send(Command::Add(entry))
+            .await
+            .map_err(|_| Error::BulkIndexerClosed)?;
+        self.counters.added.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Send all buffered operations and wait until every in-flight request completes
+    pub async fn flush(&self) -> Result<(), Error> {
+        let (done, wait) = oneshot::channel();
+        self.sender
+            .send(Command::Flush(done))
+            .await
+            .map_err(|_| Error::BulkIndexerClosed)?;
+        wait.await.map_err(|_| Error::BulkIndexerClosed)
+    }
+
+    /// Return a snapshot of the current statistics
+    pub fn stats(&self) -> BulkIndexerStats {
+        self.counters.snapshot()
+    }
+
+    /// Flush all buffered operations, shut the indexer down and return its statistics
+    ///
+    /// Other handles of the same indexer fail with [`Error::BulkIndexerClosed`]
+    /// once it is closed.
+    pub async fn close(self) -> Result<BulkIndexerStats, Error> {
+        let (done, wait) = oneshot::channel();
+        self.sender
+            .send(Command::Close(done))
+            .await
+            .map_err(|_| Error::BulkIndexerClosed)?;
+        wait.await.map_err(|_| Error::BulkIndexerClosed)?;
+        Ok(self.counters.snapshot())
+    }
+}
+
+/// Background task owning the buffer
+struct Worker {
+    client: Client,
+    config: BulkIndexerConfig,
+    path: String,
+    semaphore: Arc<Semaphore>,
+    counters: Arc<Counters>,
+    buffer: Vec<String>,
+    buffered_bytes: usize,
+}
+
+impl Worker {
+    async fn run(mut self, mut receiver: mpsc::Receiver<Command>) {
+        let mut ticker = self.config.flush_interval.map(|period| {
+            let mut ticker = tokio::time::interval_at(Instant::now() + period, period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            ticker
+        });
+
+        loop {
+            tokio::select! {
+                command = receiver.recv() => match command {
+                    Some(Command::Add(entry)) => self.push(entry).await,
+                    Some(Command::Flush(done)) => {
+                        self.flush().await;
+                        self.wait_in_flight().await;
+                        let _ = done.send(());
+                    }
+                    Some(Command::Close(done)) => {
+                        // Drain what was sent before the channel was closed
+                        receiver.close();
+                        let mut waiting = vec![done];
+                        while let Some(command) = receiver.recv().await {
+                            match command {
+                                Command::Add(entry) => self.push(entry).await,
+                                Command::Flush(done) | Command::Close(done) => waiting.push(done),
+                            }
+                        }
+                        self.flush().await;
+                        self.wait_in_flight().await;
+                        for done in waiting {
+                            let _ = done.send(());
+                        }
+                        return;
+                    }
+                    None => {
+                        // Every handle was dropped without closing
+                        self.flush().await;
+                        self.wait_in_flight().await;
+                        return;
+                    }
+                },
+                _ = tick(&mut ticker) => self.flush().await,
+            }
+        }
+    }
+
+    /// Buffer one operation, flushing before or after it when a limit is reached
+    async fn push(&mut self, entry: String) {
+        if !self.buffer.is_empty() && self.buffered_bytes + entry.len() > self.config.flush_bytes {
+            self.flush().await;
+        }
+
+        self.buffered_bytes += entry.len();
+        self.buffer.push(entry);
+
+        if self.buffer.len() >= self.config.flush_documents
+            || self.buffered_bytes >= self.config.flush_bytes
+        {
+            self.flush().await;
+        }
+    }
+
+    /// Send the buffered operations once a request slot is available
+    async fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let entries = std::mem::take(&mut self.buffer);
+        self.buffered_bytes = 0;
+        let permit = self
+            .semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("bulk indexer semaphore is never closed");
+
+        let client = self.client.clone();
+        let path = self.path.clone();
+        let retry = self.config.retry.clone();
+        let dead_letter = self.config.dead_letter.clone();
+        let counters = self.counters.clone();
+        tokio::spawn(async move {
+            let letters = send_batch(&client, &path, &entries, &retry, &counters).await;
+            if let (Some(sink), false) = (dead_letter, letters.is_empty()) {
+                if let Err(err) = sink.write(letters).await {
+                    log::error!("Failed to write bulk dead letters: {}", err);
+                }
+            }
+            drop(permit);
+        });
+    }
+
+    /// Wait until every in-flight request has completed
+    async fn wait_in_flight(&self) {
+        let _permits = self
+            .semaphore
+            .acquire_many(self.config.concurrency as u32)
+            .await
+            .expect("bulk indexer semaphore is never closed");
+    }
+}
+
+/// Wait for the next flush interval tick, or forever when flushing on interval is disabled
+async fn tick(ticker: &mut Option<Interval>) {
+    match ticker {
+        Some(ticker) => {
+            ticker.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Send one batch of operations, retrying rejected ones, and record its outcome
+///
+/// Returns a dead letter for every operation that failed.
+async fn send_batch(
+    client: &Client,
+    path: &str,
+    entries: &[String],
+    retry: &BulkRetryPolicy,
+    counters: &Counters,
+) -> Vec<DeadLetter> {
+    let run = send_entries(client, path, entries, retry, |bytes| {
+        counters.requests.fetch_add(1, Ordering::Relaxed);
+        counters.bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    })
+    .await;
+    counters.retried.fetch_add(run.retried, Ordering::Relaxed);
+
+    let mut letters = Vec::new();
+    for (entry, item) in entries.iter().zip(&run.items) {
+        match item {
+            Some(item) if !item.is_failed() => {}
+            Some(item) => {
+                let response = item.response();
+                let error = response.and_then(|response| response.error.as_ref());
+                letters.push(DeadLetter::new(
+                    entry.as_str(),
+                    response.map_or(0, |response| response.status),
+                    error.map_or("", |error| error.error_type.as_str()),
+                    error.map_or("", |error| error.reason.as_str()),
+                ));
+            }
+            // Operations without a final item were cut short by a request error
+            None => letters.push(DeadLetter::new(
+                entry.as_str(),
+                0,
+                "request_error",
+                run.error
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            )),
+        }
+    }
+
+    if let Some(err) = &run.error {
+        log::error!(
+            "Bulk request with {} operations failed: {}",
+            entries.len(),
+            err
+        );
+    }
+    let failed = letters.len() as u64;
+    if failed > 0 {
+        log::warn!("{} of {} bulk operations failed", failed, entries.len());
+    }
+    counters.failed.fetch_add(failed, Ordering::Relaxed);
+    counters
+        .indexed
+        .fetch_add(entries.len() as u64 - failed, Ordering::Relaxed);
+    letters
+}
+
+impl Client {
+    /// Create a [`BulkIndexer`] sending its requests through this client
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn bulk_indexer(&self, config: BulkIndexerConfig) -> Result<BulkIndexer, Error> {
+        BulkIndexer::new(self.clone(), config)
+    }
+}
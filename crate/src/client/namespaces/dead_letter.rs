@@ -0,0 +1,361 @@
+//! Dead-letter sinks for bulk operations that failed permanently
+//!
+//! Operations rejected with a mapping error, a parsing error or any other
+//! non-retryable failure are turned into [`DeadLetter`] records, holding the
+//! error and the original NDJSON payload of the operation. Records are written
+//! to a [`DeadLetterSink`]:
+//!
+//! * [`MemoryDeadLetterSink`] keeps them in memory, mostly for tests
+//! * [`FileDeadLetterSink`] appends them to an NDJSON file
+//! * [`IndexDeadLetterSink`] indexes them into a dedicated OpenSearch index
+//!
+//! Once the cause of the failure is fixed, the records can be sent again with
+//! [`BulkNamespace::replay`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use opensearch_api::bulk::BulkRetryPolicy;
+//! use opensearch_api::dead_letter::FileDeadLetterSink;
+//! use opensearch_api::{Client, ClientConfig, Error};
+//! use serde_json::json;
+//!
+//! async fn example() -> Result<(), Error> {
+//!     let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+//!     let client = Client::builder().config(config).build()?;
+//!     let sink = FileDeadLetterSink::new("failed.ndjson");
+//!
+//!     let response = client
+//!         .bulk()
+//!         .index("my_index", Some("doc1"), &json!({"count": "not a number"}))
+//!         .send_with_retry(BulkRetryPolicy::default())
+//!         .await?;
+//!     response.write_dead_letters(&sink).await?;
+//!
+//!     // Later, after fixing the mapping
+//!     let letters = sink.read().await?;
+//!     client.bulk().replay(&letters)?.send().await?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::client::namespaces::bulk::{
+    BulkFailure, BulkNamespace, BulkOperation, BulkRetryResponse, BulkStreamError,
+};
+use crate::client::Client;
+use crate::error::Error;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
+
+/// A bulk operation that failed permanently
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// Time of the failure, in milliseconds since the Unix epoch
+    pub timestamp: u64,
+
+    /// Target index of the operation, if it named one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<String>,
+
+    /// Document ID of the operation, if it named one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// HTTP status of the failure, `0` when the request itself failed
+    pub status: u16,
+
+    /// Type of the error, e.g. `mapper_parsing_exception`
+    pub error_type: String,
+
+    /// Reason of the error
+    pub reason: String,
+
+    /// Original NDJSON lines of the operation
+    pub payload: String,
+}
+
+impl DeadLetter {
+    /// Create a dead letter from the NDJSON lines of a failed operation
+    pub fn new(
+        payload: impl Into<String>,
+        status: u16,
+        error_type: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        let payload = payload.into();
+        let metadata = payload
+            .lines()
+            .next()
+            .and_then(|line| serde_json::from_str::<Value>(line).ok())
+            .and_then(|action| action.as_object()?.values().next().cloned());
+        let field = |name: &str| {
+            metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get(name)?.as_str().map(str::to_string))
+        };
+
+        DeadLetter {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_millis() as u64),
+            index: field("_index"),
+            id: field("_id"),
+            status,
+            error_type: error_type.into(),
+            reason: reason.into(),
+            payload,
+        }
+    }
+
+    /// Rebuild the bulk operation from the original payload
+    pub fn to_operation(&self) -> Result<BulkOperation<Value>, Error> {
+        let mut operations = BulkOperation::parse_ndjson(&self.payload)?;
+        match operations.len() {
+            1 => Ok(operations.remove(0)),
+            count => Err(Error::validation(format!(
+                "dead letter payload holds {} operations instead of one",
+                count
+            ))),
+        }
+    }
+}
+
+/// Destination of bulk operations that failed permanently
+#[async_trait]
+pub trait DeadLetterSink: Debug + Send + Sync {
+    /// Store a batch of dead letters
+    async fn write(&self, letters: Vec<DeadLetter>) -> Result<(), Error>;
+}
+
+/// Dead-letter sink keeping the records in memory
+///
+/// Clones share the same records.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryDeadLetterSink {
+    letters: Arc<Mutex<Vec<DeadLetter>>>,
+}
+
+impl MemoryDeadLetterSink {
+    /// Create an empty in-memory sink
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return a copy of the stored records
+    pub fn letters(&self) -> Vec<DeadLetter> {
+        self.letters
+            .lock()
+            .expect("dead letters lock poisoned")
+            .clone()
+    }
+
+    /// Remove and return the stored records
+    pub fn take(&self) -> Vec<DeadLetter> {
+        std::mem::take(&mut *self.letters.lock().expect("dead letters lock poisoned"))
+    }
+}
+
+#[async_trait]
+impl DeadLetterSink for MemoryDeadLetterSink {
+    async fn write(&self, letters: Vec<DeadLetter>) -> Result<(), Error> {
+        self.letters
+            .lock()
+            .expect("dead letters lock poisoned")
+            .extend(letters);
+        Ok(())
+    }
+}
+
+/// Dead-letter sink appending the records to an NDJSON file, one per line
+#[derive(Debug)]
+pub struct FileDeadLetterSink {
+    path: PathBuf,
+    /// Serializes appends so lines of concurrent batches never interleave
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl FileDeadLetterSink {
+    /// Create a sink appending to the given file, which is created when missing
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileDeadLetterSink {
+            path: path.into(),
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Path of the NDJSON file
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    /// Read back every record of the file
+    pub async fn read(&self) -> Result<Vec<DeadLetter>, Error> {
+        let content = match tokio::fs::read_to_string(&self.path).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl DeadLetterSink for FileDeadLetterSink {
+    async fn write(&self, letters: Vec<DeadLetter>) -> Result<(), Error> {
+        let mut lines = String::new();
+        for letter in &letters {
+            lines.push_str(&serde_json::to_string(letter)?);
+            lines.push('\n');
+        }
+
+        let _guard = self.lock.lock().await;
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(lines.as_bytes()).await?;
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+/// Dead-letter sink indexing the records into an OpenSearch index
+///
+/// The original payload is stored as a string, so records of documents with
+/// conflicting mappings can live in the same index.
+#[derive(Debug, Clone)]
+pub struct IndexDeadLetterSink {
+    client: Client,
+    index: String,
+}
+
+impl IndexDeadLetterSink {
+    /// Create a sink writing to the given index
+    pub fn new(client: Client, index: impl Into<String>) -> Self {
+        IndexDeadLetterSink {
+            client,
+            index: index.into(),
+        }
+    }
+
+    /// Name of the dead-letter index
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+}
+
+#[async_trait]
+impl DeadLetterSink for IndexDeadLetterSink {
+    async fn write(&self, letters: Vec<DeadLetter>) -> Result<(), Error> {
+        if letters.is_empty() {
+            return Ok(());
+        }
+
+        let mut bulk = self.client.bulk::<DeadLetter>();
+        for letter in &letters {
+            bulk = bulk.index(&self.index, None::<String>, letter);
+        }
+        let response = bulk.send().await?;
+
+        match response
+            .items
+            .iter()
+            .find_map(|item| item.response().filter(|response| response.is_failed()))
+        {
+            Some(failed) => Err(Error::ApiError {
+                status_code: failed.status,
+                message: failed.error.as_ref().map_or_else(
+                    || "failed to index dead letter".to_string(),
+                    |error| format!("{}: {}", error.error_type, error.reason),
+                ),
+                request_body_info: String::new(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Serialize, U: Serialize> BulkFailure<T, U> {
+    /// Turn the failure into a dead letter holding the original payload
+    pub fn to_dead_letter(&self) -> Result<DeadLetter, Error> {
+        let mut payload = String::new();
+        self.operation.write_ndjson(&mut payload)?;
+        let (error_type, reason) = self.error.as_ref().map_or_else(
+            || (String::new(), String::new()),
+            |error| (error.error_type.clone(), error.reason.clone()),
+        );
+        Ok(DeadLetter::new(payload, self.status, error_type, reason))
+    }
+}
+
+impl<T: Serialize, U: Serialize> BulkRetryResponse<T, U> {
+    /// Write every permanent failure to a dead-letter sink
+    ///
+    /// Returns the number of records written.
+    pub async fn write_dead_letters(&self, sink: &dyn DeadLetterSink) -> Result<usize, Error> {
+        let letters = self
+            .failures
+            .iter()
+            .map(BulkFailure::to_dead_letter)
+            .collect::<Result<Vec<_>, Error>>()?;
+        let written = letters.len();
+        if written > 0 {
+            sink.write(letters).await?;
+        }
+        Ok(written)
+    }
+}
+
+impl<T: Serialize, U: Serialize> BulkStreamError<T, U> {
+    /// Write every operation that was not sent to a dead-letter sink
+    ///
+    /// The records have status `0` and the `request_error` type, with the error
+    /// as reason. Returns the number of records written.
+    pub async fn write_dead_letters(&self, sink: &dyn DeadLetterSink) -> Result<usize, Error> {
+        let reason = self.error.to_string();
+        let letters = self
+            .operations
+            .iter()
+            .map(|operation| {
+                let mut payload = String::new();
+                operation.write_ndjson(&mut payload)?;
+                Ok(DeadLetter::new(
+                    payload,
+                    0,
+                    "request_error",
+                    reason.as_str(),
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let written = letters.len();
+        if written > 0 {
+            sink.write(letters).await?;
+        }
+        Ok(written)
+    }
+}
+
+impl BulkNamespace<Value> {
+    /// Add the operations of dead letters to the bulk request, to send them again
+    pub fn replay<'a>(
+        self,
+        letters: impl IntoIterator<Item = &'a DeadLetter>,
+    ) -> Result<Self, Error> {
+        let operations = letters
+            .into_iter()
+            .map(DeadLetter::to_operation)
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(self.operations(operations))
+    }
+}
@@ -1,12 +1,14 @@
 //! Documents namespace for OpenSearch
 
 use crate::error::Error;
+use crate::Document;
 use derive_builder::Builder;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
-use crate::types::document::{BulkOptions, DeleteOptions, ExistsOptions, GetOptions, IndexOptions, MgetOptions, UpdateOptions, WaitForActiveShards};
+use crate::types::bulk::{BulkOperation, BulkParams, BulkResponse};
+use crate::types::document::{BulkOptions, DeleteOptions, ExistsOptions, GetOptions, IndexOptions, MTermVectorsDoc, MTermVectorsResponse, MgetOptions, TermVectorsFilter, TermVectorsOptions, TermVectorsResponse, UpdateOptions, WaitForActiveShards};
 /// Re-export document types for easier access
 pub use crate::types::document::{DeleteResponse, GetResponse, IndexResponse, UpdateResponse};
 
@@ -861,6 +863,390 @@ impl<'a> ExistsRequest<'a> {
     }
 }
 
+/// Builder for get source requests
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct GetSourceRequest<'a, T: Clone + for<'de> Deserialize<'de> + Send + Sync> {
+    /// Documents namespace reference
+    #[builder(pattern = "immutable")]
+    client: &'a DocumentsNamespace,
+    /// Index to get the document source from
+    #[builder(pattern = "immutable")]
+    index: String,
+    /// Document ID
+    #[builder(pattern = "immutable")]
+    id: String,
+    /// Get options
+    #[builder(default)]
+    options: Option<GetOptions>,
+    /// Type parameter marker
+    #[builder(setter(skip), default = "std::marker::PhantomData")]
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: Clone + for<'de> Deserialize<'de> + Send + Sync> GetSourceRequestBuilder<'a, T> {
+    /// Set the source_includes option
+    pub fn source_includes(mut self, source_includes: Vec<impl Into<String>>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.source_includes = Some(source_includes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the source_excludes option
+    pub fn source_excludes(mut self, source_excludes: Vec<impl Into<String>>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.source_excludes = Some(source_excludes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the routing option
+    pub fn routing(mut self, routing: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.routing = Some(routing.into());
+        self
+    }
+
+    /// Set the preference option
+    pub fn preference(mut self, preference: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.preference = Some(preference.into());
+        self
+    }
+
+    /// Set the realtime option
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.realtime = Some(realtime);
+        self
+    }
+
+    /// Set the refresh option
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.refresh = Some(refresh);
+        self
+    }
+
+    /// Set the version option
+    pub fn version(mut self, version: i64) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.version = Some(version);
+        self
+    }
+
+    /// Set the version_type option
+    pub fn version_type(mut self, version_type: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.version_type = Some(version_type.into());
+        self
+    }
+
+    /// Build and send the get source request
+    pub async fn send(self) -> Result<Option<T>, Error> {
+        self.build()?.send().await
+    }
+}
+
+impl<'a, T: Clone + for<'de> Deserialize<'de> + Send + Sync> GetSourceRequest<'a, T> {
+    /// Build and send the get source request
+    pub async fn send(self) -> Result<Option<T>, Error> {
+        let index_str = self.index;
+        let id_str = self.id;
+        let mut path = format!("/{index_str}/_source/{id_str}");
+
+        // Add query parameters from options
+        let mut query_params = Vec::new();
+        if let Some(options) = &self.options {
+            if let Some(source_includes) = &options.source_includes {
+                let includes = source_includes.join(",");
+                query_params.push(format!("_source_includes={}", includes));
+            }
+
+            if let Some(source_excludes) = &options.source_excludes {
+                let excludes = source_excludes.join(",");
+                query_params.push(format!("_source_excludes={}", excludes));
+            }
+
+            if let Some(routing) = &options.routing {
+                query_params.push(format!("routing={}", routing));
+            }
+
+            if let Some(preference) = &options.preference {
+                query_params.push(format!("preference={}", preference));
+            }
+
+            if let Some(realtime) = options.realtime {
+                query_params.push(format!("realtime={}", realtime));
+            }
+
+            if let Some(refresh) = options.refresh {
+                query_params.push(format!("refresh={}", refresh));
+            }
+
+            if let Some(version) = options.version {
+                query_params.push(format!("version={}", version));
+            }
+
+            if let Some(version_type) = &options.version_type {
+                query_params.push(format!("version_type={}", version_type));
+            }
+        }
+
+        // Add query parameters to path
+        if !query_params.is_empty() {
+            path.push_str(&format!("?{}", query_params.join("&")));
+        }
+
+        log::debug!("Sending GET source request to path: {}", path);
+
+        // Make a direct request to properly handle 404 responses
+        let url = self
+            .client
+            .client
+            .base_url
+            .join(&path)
+            .map_err(Error::UrlParseError)?;
+        let response = self
+            .client
+            .client
+            .http_client
+            .get(url)
+            .send()
+            .await
+            .map_err(Error::HttpRequestError)?;
+        let status = response.status();
+        log::debug!("GET source request returned status: {}", status);
+
+        // Return None for 404 responses
+        if status == reqwest::StatusCode::NOT_FOUND {
+            log::debug!("Document not found (404), returning None");
+            return Ok(None);
+        }
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(Error::ApiError {
+                status_code: status.as_u16(),
+                message: error_text,
+                request_body_info: String::new(),
+            });
+        }
+
+        let response_text = response.text().await.map_err(Error::HttpRequestError)?;
+        serde_json::from_str::<T>(&response_text)
+            .map(Some)
+            .map_err(|err| {
+                Error::deserialization_with_response(
+                    err,
+                    response_text,
+                    "",
+                    std::any::type_name::<T>(),
+                )
+            })
+    }
+}
+
+/// Builder for source exists requests
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct SourceExistsRequest<'a> {
+    /// Documents namespace reference
+    #[builder(pattern = "immutable")]
+    client: &'a DocumentsNamespace,
+    /// Index to check for the document source in
+    #[builder(pattern = "immutable")]
+    index: String,
+    /// Document ID
+    #[builder(pattern = "immutable")]
+    id: String,
+    /// Exists options
+    #[builder(default)]
+    options: Option<ExistsOptions>,
+}
+
+impl<'a> SourceExistsRequestBuilder<'a> {
+    /// Set the routing option
+    pub fn routing(mut self, routing: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.routing = Some(routing.into());
+        self
+    }
+
+    /// Set the preference option
+    pub fn preference(mut self, preference: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.preference = Some(preference.into());
+        self
+    }
+
+    /// Set the realtime option
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.realtime = Some(realtime);
+        self
+    }
+
+    /// Set the refresh option
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.refresh = Some(refresh);
+        self
+    }
+
+    /// Build and send the source exists request
+    pub async fn send(self) -> Result<bool, Error> {
+        self.build()?.send().await
+    }
+}
+
+impl<'a> SourceExistsRequest<'a> {
+    /// Send the source exists request to the server
+    pub async fn send(self) -> Result<bool, Error> {
+        let index_str = self.index;
+        let id_str = self.id;
+        let mut path = format!("/{index_str}/_source/{id_str}");
+
+        // Add query parameters from options
+        let mut query_params = Vec::new();
+        if let Some(options) = &self.options {
+            if let Some(routing) = &options.routing {
+                query_params.push(format!("routing={}", routing));
+            }
+
+            if let Some(preference) = &options.preference {
+                query_params.push(format!("preference={}", preference));
+            }
+
+            if let Some(realtime) = options.realtime {
+                query_params.push(format!("realtime={}", realtime));
+            }
+
+            if let Some(refresh) = options.refresh {
+                query_params.push(format!("refresh={}", refresh));
+            }
+        }
+
+        // Add query parameters to path
+        if !query_params.is_empty() {
+            path.push_str(&format!("?{}", query_params.join("&")));
+        }
+
+        log::debug!("Checking document source existence at path: {}", path);
+
+        // A missing document and a document stored without _source both return 404
+        self.client.client.exists(&path).await
+    }
+}
+
+/// Builder for create-only document requests
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct CreateRequest<'a, T: Serialize + Clone> {
+    /// Documents namespace reference
+    #[builder(pattern = "immutable")]
+    client: &'a DocumentsNamespace,
+
+    /// Index to store the document in
+    #[builder(pattern = "immutable")]
+    index: String,
+
+    /// Document ID
+    #[builder(pattern = "immutable")]
+    id: String,
+
+    /// Document to create
+    document: &'a T,
+
+    /// Index options
+    #[builder(default)]
+    options: Option<IndexOptions>,
+}
+
+impl<'a, T: Serialize + Clone> CreateRequestBuilder<'a, T> {
+    /// Set the refresh option
+    pub fn refresh(mut self, refresh: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.refresh = Some(refresh.into());
+        self
+    }
+
+    /// Set the routing option
+    pub fn routing(mut self, routing: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.routing = Some(routing.into());
+        self
+    }
+
+    /// Set the timeout option
+    pub fn timeout(mut self, timeout: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Set the wait_for_active_shards option
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: WaitForActiveShards) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.wait_for_active_shards = Some(wait_for_active_shards);
+        self
+    }
+
+    /// Build and send the create request
+    pub async fn send(self) -> Result<IndexResponse, Error> {
+        self.build()?.send().await
+    }
+}
+
+impl<'a, T: Serialize + Clone> CreateRequest<'a, T> {
+    /// Send the create request to the server
+    ///
+    /// Fails with [`Error::DocumentAlreadyExists`] if a document with the same ID exists.
+    pub async fn send(self) -> Result<IndexResponse, Error> {
+        let mut path = format!("/{}/_create/{}", self.index, self.id);
+
+        // Add query parameters from options
+        if let Some(options) = &self.options {
+            let mut query_params = Vec::new();
+
+            if let Some(refresh) = &options.refresh {
+                query_params.push(format!("refresh={}", refresh));
+            }
+
+            if let Some(routing) = &options.routing {
+                query_params.push(format!("routing={}", routing));
+            }
+
+            if let Some(timeout) = &options.timeout {
+                query_params.push(format!("timeout={}", timeout));
+            }
+
+            if let Some(wait_for_active_shards) = &options.wait_for_active_shards {
+                let value = match wait_for_active_shards {
+                    WaitForActiveShards::Value(v) => v.to_string(),
+                    WaitForActiveShards::Count(n) => n.to_string(),
+                };
+                query_params.push(format!("wait_for_active_shards={}", value));
+            }
+
+            if !query_params.is_empty() {
+                path.push_str(&format!("?{}", query_params.join("&")));
+            }
+        }
+
+        match self
+            .client
+            .client
+            .request::<_, IndexResponse>(Method::PUT, &path, Some(self.document))
+            .await
+        {
+            Err(Error::ApiError {
+                status_code: 409, ..
+            }) => Err(Error::DocumentAlreadyExists(self.id, self.index)),
+            result => result,
+        }
+    }
+}
+
 /// Builder for refresh requests
 #[derive(Debug, Clone, Builder)]
 #[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
@@ -951,6 +1337,54 @@ impl DocumentsNamespace {
         IndexRequest::new(self, index)
     }
 
+    /// Create a builder for indexing a [`Document`]
+    ///
+    /// The index, ID, routing and version are taken from the document itself.
+    /// When the document has a version, it is indexed with `version_type=external`.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "derive", doc = "```no_run")]
+    #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+    /// # use opensearch_api::{Client, ClientConfig, Document, Error};
+    /// # use serde::{Deserialize, Serialize};
+    /// #[derive(Clone, Serialize, Deserialize, Document)]
+    /// #[opensearch(index = "products")]
+    /// struct Product {
+    ///     #[opensearch(id)]
+    ///     sku: String,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    /// let product = Product { sku: "sku-1".to_string(), name: "Widget".to_string() };
+    /// let response = client.documents()
+    ///     .save(&product)
+    ///     .refresh("true")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn save<'a, T>(&'a self, document: &'a T) -> IndexRequestBuilder<'a, T>
+    where
+        T: Document + Clone,
+    {
+        let mut builder = IndexRequest::new(self, T::index_name()).document(document);
+        if let Some(id) = document.id() {
+            builder = builder.id(id);
+        }
+        if let Some(routing) = document.routing() {
+            builder = builder.routing(routing);
+        }
+        if let Some(version) = document.version() {
+            builder = builder.version(version).version_type("external");
+        }
+        builder
+    }
+
     /// Create a builder for getting a document
     ///
     /// This allows for a fluent API to set options and execute the get operation.
@@ -982,6 +1416,39 @@ impl DocumentsNamespace {
         GetRequest::new(self, index, id)
     }
 
+    /// Create a builder for getting a [`Document`] by ID from its own index
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "derive", doc = "```no_run")]
+    #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+    /// # use opensearch_api::{Client, ClientConfig, Document, Error};
+    /// # use serde::{Deserialize, Serialize};
+    /// #[derive(Clone, Serialize, Deserialize, Document)]
+    /// #[opensearch(index = "products")]
+    /// struct Product {
+    ///     #[opensearch(id)]
+    ///     sku: String,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    /// let response = client.documents()
+    ///     .get_doc::<Product>("sku-1")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_doc<'a, T>(&'a self, id: impl Into<String>) -> GetRequestBuilder<'a, T>
+    where
+        T: Document + Clone + for<'de> Deserialize<'de> + Send + Sync,
+    {
+        GetRequest::new(self, T::index_name(), id)
+    }
+
     /// Create a builder for updating a document
     ///
     /// This allows for a fluent API to set options and execute the update operation.
@@ -1059,6 +1526,89 @@ impl DocumentsNamespace {
         ExistsRequest::new(self, index, id)
     }
 
+    /// Create a builder for getting only the source of a document
+    ///
+    /// Returns `None` if the document does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use opensearch_api::{Client, ClientConfig, Error};
+    /// # async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    /// let source = client.documents()
+    ///     .get_source::<serde_json::Value>("my_index", "doc1")
+    ///     .source_includes(vec!["title"])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_source<T>(
+        &self,
+        index: impl Into<String>,
+        id: impl Into<String>,
+    ) -> GetSourceRequestBuilder<'_, T>
+    where
+        T: Clone + for<'de> Deserialize<'de> + Send + Sync,
+    {
+        GetSourceRequestBuilder::default()
+            .client(self)
+            .index(index)
+            .id(id)
+    }
+
+    /// Create a builder for checking if a document source exists
+    pub fn source_exists(
+        &self,
+        index: impl Into<String>,
+        id: impl Into<String>,
+    ) -> SourceExistsRequestBuilder<'_> {
+        SourceExistsRequestBuilder::default()
+            .client(self)
+            .index(index)
+            .id(id)
+    }
+
+    /// Create a builder for indexing a document only if its ID is not taken yet
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use opensearch_api::{Client, ClientConfig, Error};
+    /// # use serde_json::json;
+    /// # async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    /// let event = json!({"type": "signup", "user": "user1"});
+    /// match client.documents()
+    ///     .create("events", "event-1")
+    ///     .document(&event)
+    ///     .send()
+    ///     .await
+    /// {
+    ///     Ok(response) => println!("Created {}", response.id),
+    ///     Err(Error::DocumentAlreadyExists(id, _)) => println!("Skipped duplicate {}", id),
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create<'a, T>(
+        &'a self,
+        index: impl Into<String>,
+        id: impl Into<String>,
+    ) -> CreateRequestBuilder<'a, T>
+    where
+        T: Serialize + Clone,
+    {
+        CreateRequestBuilder::default()
+            .client(self)
+            .index(index)
+            .id(id)
+    }
+
     /// Create a builder for refreshing an index
     ///
     /// This allows for a fluent API to execute the refresh operation.
@@ -1088,28 +1638,26 @@ impl DocumentsNamespace {
     /// # Example
     ///
     /// ```no_run
-    /// # use opensearch_api::{Client, Error};
+    /// # use opensearch_api::{Client, ClientConfig, Error};
+    /// # use opensearch_api::bulk::BulkOperation;
     /// # use serde_json::json;
     /// # async fn example() -> Result<(), Error> {
-    /// # let client = Client::builder().base_url("http://localhost:9200").build()?;
-    /// let bulk_body = [
-    ///     json!({"index": {"_index": "test", "_id": "1"}}),
-    ///     json!({"field": "value1"}),
-    ///     json!({"index": {"_index": "test", "_id": "2"}}),
-    ///     json!({"field": "value2"}),
-    /// ];
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
     /// let response = client.documents()
     ///     .bulk()
-    ///     .operations(bulk_body.as_slice())
+    ///     .operation(BulkOperation::index(Some("test".to_string()), Some("1".to_string()), json!({"field": "value1"})))
+    ///     .operation(BulkOperation::index(Some("test".to_string()), Some("2".to_string()), json!({"field": "value2"})))
     ///     .refresh("true")
     ///     .build()?
     ///     .send()
     ///     .await?;
+    /// println!("Indexed {:?}", response.succeeded_ids().collect::<Vec<_>>());
     /// Ok(())
     /// }
     /// ```
-    pub fn bulk(&self) -> BulkRequestBuilder {
-        BulkRequest::new(self)
+    pub fn bulk<T: Serialize + Clone>(&self) -> BulkRequestBuilder<'_, T> {
+        BulkRequestBuilder::default().client(self)
     }
 
     /// Create a builder for multi-get operations
@@ -1142,6 +1690,72 @@ impl DocumentsNamespace {
     {
         MgetRequest::new(self)
     }
+
+    /// Create a builder for retrieving the term vectors of a stored document
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use opensearch_api::{Client, ClientConfig, Error};
+    /// # async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    /// let response = client.documents()
+    ///     .termvectors("my_index", "1")
+    ///     .fields(vec!["text"])
+    ///     .term_statistics(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn termvectors(
+        &self,
+        index: impl Into<String>,
+        id: impl Into<String>,
+    ) -> TermVectorsRequestBuilder<'_> {
+        TermVectorsRequestBuilder::default()
+            .client(self)
+            .index(index)
+            .id(id)
+    }
+
+    /// Create a builder for retrieving the term vectors of an artificial document
+    ///
+    /// The document is analyzed with the mappings of the index but is not stored.
+    pub fn termvectors_doc(
+        &self,
+        index: impl Into<String>,
+        doc: serde_json::Value,
+    ) -> TermVectorsRequestBuilder<'_> {
+        TermVectorsRequestBuilder::default()
+            .client(self)
+            .index(index)
+            .doc(doc)
+    }
+
+    /// Create a builder for multi term vectors operations
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use opensearch_api::{Client, ClientConfig, Error};
+    /// # async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    /// let response = client.documents()
+    ///     .mtermvectors()
+    ///     .index("my_index")
+    ///     .ids(vec!["1".to_string(), "2".to_string()])
+    ///     .fields(vec!["text"])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mtermvectors(&self) -> MTermVectorsRequestBuilder<'_> {
+        MTermVectorsRequestBuilder::default().client(self)
+    }
 }
 
 impl crate::client::Client {
@@ -1154,21 +1768,33 @@ impl crate::client::Client {
 /// Builder for bulk operation requests
 #[derive(Debug, Clone, Builder)]
 #[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
-pub struct BulkRequest<'a> {
+pub struct BulkRequest<'a, T: Serialize + Clone = serde_json::Value> {
     /// Documents namespace reference
     #[builder(pattern = "immutable")]
     client: &'a DocumentsNamespace,
 
     /// Operations to perform in bulk
-    #[builder(default)]
-    operations: Option<&'a [serde_json::Value]>,
+    #[builder(default, setter(custom))]
+    operations: Vec<BulkOperation<T>>,
 
     /// Bulk options
     #[builder(default)]
     options: Option<BulkOptions>,
 }
 
-impl<'a> BulkRequestBuilder<'a> {
+impl<'a, T: Serialize + Clone> BulkRequestBuilder<'a, T> {
+    /// Add an operation to the request
+    pub fn operation(mut self, operation: BulkOperation<T>) -> Self {
+        self.operations.get_or_insert_default().push(operation);
+        self
+    }
+
+    /// Add several operations to the request
+    pub fn operations(mut self, operations: impl IntoIterator<Item = BulkOperation<T>>) -> Self {
+        self.operations.get_or_insert_default().extend(operations);
+        self
+    }
+
     /// Set the refresh option
     pub fn refresh(mut self, refresh: impl Into<String>) -> Self {
         let options = self.options.get_or_insert_default().get_or_insert_default();
@@ -1191,58 +1817,35 @@ impl<'a> BulkRequestBuilder<'a> {
     }
 
     /// Build and send the bulk request
-    pub async fn send(self) -> Result<serde_json::Value, Error> {
-        self.build().unwrap().send().await
+    pub async fn send(self) -> Result<BulkResponse, Error> {
+        self.build()?.send().await
     }
 }
 
-impl<'a> BulkRequest<'a> {
-    /// Create a new bulk request builder
-    pub(crate) fn new(client: &'a DocumentsNamespace) -> BulkRequestBuilder<'a> {
-        BulkRequestBuilder::default().client(client)
-    }
-
+impl<'a, T: Serialize + Clone> BulkRequest<'a, T> {
     /// Build and send the bulk request
-    pub async fn send(self) -> Result<serde_json::Value, Error> {
-        let mut path = "/_bulk".to_string();
-
-        // Add query parameters from options
-        let mut query_params = Vec::new();
-        if let Some(options) = &self.options {
-            if let Some(refresh) = &options.refresh {
-                query_params.push(format!("refresh={}", refresh));
-            }
-
-            if let Some(timeout) = &options.timeout {
-                query_params.push(format!("timeout={}", timeout));
-            }
-
-            if let Some(wait_for_active_shards) = &options.wait_for_active_shards {
-                let value = match wait_for_active_shards {
-                    WaitForActiveShards::Value(v) => v.to_string(),
-                    WaitForActiveShards::Count(n) => n.to_string(),
-                };
-                query_params.push(format!("wait_for_active_shards={}", value));
-            }
-        }
-
-        // Add query parameters to path
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
-
-        // Create the request body
-        let mut body = String::new();
-        if let Some(operations) = self.operations {
-            for operation in operations {
-                body.push_str(&(serde_json::to_string(operation).unwrap() + "\n"));
-            }
+    ///
+    /// The request is sent the same way as [`Client::bulk`](crate::Client::bulk).
+    pub async fn send(self) -> Result<BulkResponse, Error> {
+        let mut params = BulkParams::default();
+        if let Some(options) = self.options {
+            params.refresh = options.refresh.map(|refresh| refresh.parse()).transpose()?;
+            params.timeout = options.timeout;
+            params.wait_for_active_shards =
+                options
+                    .wait_for_active_shards
+                    .map(|wait_for_active_shards| match wait_for_active_shards {
+                        WaitForActiveShards::Value(v) => v.to_string(),
+                        WaitForActiveShards::Count(n) => n.to_string(),
+                    });
         }
 
-        log::debug!("Sending BULK request to path: {}", path);
         self.client
             .client
-            .request_with_string_body::<serde_json::Value>(Method::POST, &path, Some(body))
+            .bulk::<T>()
+            .params(params)
+            .operations(self.operations)
+            .send()
             .await
     }
 }
@@ -1358,3 +1961,330 @@ impl<'a, T: Clone + for<'de> Deserialize<'de> + Send + Sync> MgetRequest<'a, T>
             .await
     }
 }
+
+/// Builder for term vectors requests
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct TermVectorsRequest<'a> {
+    /// Documents namespace reference
+    #[builder(pattern = "immutable")]
+    client: &'a DocumentsNamespace,
+
+    /// Index containing the document
+    #[builder(pattern = "immutable")]
+    index: String,
+
+    /// ID of a stored document
+    #[builder(default)]
+    id: Option<String>,
+
+    /// Artificial document to analyze instead of a stored one
+    #[builder(default)]
+    doc: Option<serde_json::Value>,
+
+    /// Filter limiting the returned terms
+    #[builder(default)]
+    filter: Option<TermVectorsFilter>,
+
+    /// Term vectors options
+    #[builder(default)]
+    options: Option<TermVectorsOptions>,
+}
+
+impl<'a> TermVectorsRequestBuilder<'a> {
+    /// Set the fields to return term vectors for
+    pub fn fields(mut self, fields: Vec<impl Into<String>>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the offsets option
+    pub fn offsets(mut self, offsets: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.offsets = Some(offsets);
+        self
+    }
+
+    /// Set the positions option
+    pub fn positions(mut self, positions: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.positions = Some(positions);
+        self
+    }
+
+    /// Set the payloads option
+    pub fn payloads(mut self, payloads: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.payloads = Some(payloads);
+        self
+    }
+
+    /// Set the term_statistics option
+    pub fn term_statistics(mut self, term_statistics: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.term_statistics = Some(term_statistics);
+        self
+    }
+
+    /// Set the field_statistics option
+    pub fn field_statistics(mut self, field_statistics: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.field_statistics = Some(field_statistics);
+        self
+    }
+
+    /// Set the routing option
+    pub fn routing(mut self, routing: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.routing = Some(routing.into());
+        self
+    }
+
+    /// Set the preference option
+    pub fn preference(mut self, preference: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.preference = Some(preference.into());
+        self
+    }
+
+    /// Set the realtime option
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.realtime = Some(realtime);
+        self
+    }
+
+    /// Build and send the term vectors request
+    pub async fn send(self) -> Result<TermVectorsResponse, Error> {
+        self.build()?.send().await
+    }
+}
+
+impl<'a> TermVectorsRequest<'a> {
+    /// Build and send the term vectors request
+    pub async fn send(self) -> Result<TermVectorsResponse, Error> {
+        let mut path = match (&self.id, &self.doc) {
+            (Some(id), None) => format!("/{}/_termvectors/{}", self.index, id),
+            (None, Some(_)) => format!("/{}/_termvectors", self.index),
+            _ => {
+                return Err(Error::InvalidArgument(
+                    "Exactly one of 'id' or 'doc' must be specified".to_string(),
+                ))
+            }
+        };
+
+        // Add query parameters from options
+        let mut query_params = Vec::new();
+        if let Some(options) = &self.options {
+            push_termvectors_params(&mut query_params, options);
+        }
+
+        // Add query parameters to path
+        if !query_params.is_empty() {
+            path.push_str(&format!("?{}", query_params.join("&")));
+        }
+
+        // Artificial documents and filters are only accepted in the body
+        let mut body = json!({});
+        if let Some(doc) = self.doc {
+            body["doc"] = doc;
+        }
+        if let Some(filter) = self.filter {
+            body["filter"] = json!(filter);
+        }
+
+        log::debug!("Sending TERMVECTORS request to path: {}", path);
+        self.client
+            .client
+            .request::<_, TermVectorsResponse>(Method::POST, &path, Some(&body))
+            .await
+    }
+}
+
+/// Builder for multi term vectors requests
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct MTermVectorsRequest<'a> {
+    /// Documents namespace reference
+    #[builder(pattern = "immutable")]
+    client: &'a DocumentsNamespace,
+
+    /// Default index for the documents (optional)
+    #[builder(default)]
+    index: Option<String>,
+
+    /// Document IDs to get term vectors for (when index is specified)
+    #[builder(default)]
+    ids: Option<Vec<String>>,
+
+    /// Documents to get term vectors for, each with its own settings
+    #[builder(default)]
+    docs: Option<Vec<MTermVectorsDoc>>,
+
+    /// Default term vectors options applied to every document
+    #[builder(default)]
+    options: Option<TermVectorsOptions>,
+}
+
+impl<'a> MTermVectorsRequestBuilder<'a> {
+    /// Add a document to the request
+    pub fn add_doc(mut self, doc: MTermVectorsDoc) -> Self {
+        self.docs
+            .get_or_insert_default()
+            .get_or_insert_default()
+            .push(doc);
+        self
+    }
+
+    /// Set the default fields to return term vectors for
+    pub fn fields(mut self, fields: Vec<impl Into<String>>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the default offsets option
+    pub fn offsets(mut self, offsets: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.offsets = Some(offsets);
+        self
+    }
+
+    /// Set the default positions option
+    pub fn positions(mut self, positions: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.positions = Some(positions);
+        self
+    }
+
+    /// Set the default payloads option
+    pub fn payloads(mut self, payloads: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.payloads = Some(payloads);
+        self
+    }
+
+    /// Set the default term_statistics option
+    pub fn term_statistics(mut self, term_statistics: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.term_statistics = Some(term_statistics);
+        self
+    }
+
+    /// Set the default field_statistics option
+    pub fn field_statistics(mut self, field_statistics: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.field_statistics = Some(field_statistics);
+        self
+    }
+
+    /// Set the default routing option
+    pub fn routing(mut self, routing: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.routing = Some(routing.into());
+        self
+    }
+
+    /// Set the preference option
+    pub fn preference(mut self, preference: impl Into<String>) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.preference = Some(preference.into());
+        self
+    }
+
+    /// Set the realtime option
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        let options = self.options.get_or_insert_default().get_or_insert_default();
+        options.realtime = Some(realtime);
+        self
+    }
+
+    /// Build and send the multi term vectors request
+    pub async fn send(self) -> Result<MTermVectorsResponse, Error> {
+        self.build()?.send().await
+    }
+}
+
+impl<'a> MTermVectorsRequest<'a> {
+    /// Build and send the multi term vectors request
+    pub async fn send(self) -> Result<MTermVectorsResponse, Error> {
+        let mut path = if let Some(index) = &self.index {
+            format!("/{}/_mtermvectors", index)
+        } else {
+            "/_mtermvectors".to_string()
+        };
+
+        // Add query parameters from options
+        let mut query_params = Vec::new();
+        if let Some(options) = &self.options {
+            push_termvectors_params(&mut query_params, options);
+        }
+
+        // Add query parameters to path
+        if !query_params.is_empty() {
+            path.push_str(&format!("?{}", query_params.join("&")));
+        }
+
+        // Create the request body
+        let body = if let Some(ids) = &self.ids {
+            if self.index.is_none() {
+                return Err(Error::InvalidArgument(
+                    "Index must be specified when using IDs".to_string(),
+                ));
+            }
+            json!({ "ids": ids })
+        } else if let Some(docs) = &self.docs {
+            json!({ "docs": docs })
+        } else {
+            return Err(Error::InvalidArgument(
+                "Either 'ids' or 'docs' must be specified".to_string(),
+            ));
+        };
+
+        log::debug!("Sending MTERMVECTORS request to path: {}", path);
+        self.client
+            .client
+            .request::<_, MTermVectorsResponse>(Method::POST, &path, Some(&body))
+            .await
+    }
+}
+
+/// Append the query parameters shared by the term vectors APIs
+fn push_termvectors_params(query_params: &mut Vec<String>, options: &TermVectorsOptions) {
+    if let Some(fields) = &options.fields {
+        query_params.push(format!("fields={}", fields.join(",")));
+    }
+
+    if let Some(offsets) = options.offsets {
+        query_params.push(format!("offsets={}", offsets));
+    }
+
+    if let Some(positions) = options.positions {
+        query_params.push(format!("positions={}", positions));
+    }
+
+    if let Some(payloads) = options.payloads {
+        query_params.push(format!("payloads={}", payloads));
+    }
+
+    if let Some(term_statistics) = options.term_statistics {
+        query_params.push(format!("term_statistics={}", term_statistics));
+    }
+
+    if let Some(field_statistics) = options.field_statistics {
+        query_params.push(format!("field_statistics={}", field_statistics));
+    }
+
+    if let Some(routing) = &options.routing {
+        query_params.push(format!("routing={}", routing));
+    }
+
+    if let Some(preference) = &options.preference {
+        query_params.push(format!("preference={}", preference));
+    }
+
+    if let Some(realtime) = options.realtime {
+        query_params.push(format!("realtime={}", realtime));
+    }
+}
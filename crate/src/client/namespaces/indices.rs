@@ -864,6 +864,68 @@ impl RefreshIndexRequest {
     }
 }
 
+/// Validate query request
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
+pub struct ValidateQueryRequest {
+    /// The index or indices to validate the query against
+    #[builder(setter(into))]
+    pub index: IndexList,
+
+    /// The query to validate
+    #[builder(setter(into), default)]
+    pub query: crate::types::query::Query,
+
+    /// Whether to return detailed information when the query is invalid
+    #[builder(setter(into, strip_option), default)]
+    pub explain: Option<bool>,
+
+    /// Whether to return the rewritten Lucene query
+    #[builder(setter(into, strip_option), default)]
+    pub rewrite: Option<bool>,
+
+    /// Client reference
+    #[builder(private)]
+    client: crate::client::Client,
+}
+
+impl ValidateQueryRequest {
+    /// Create a new validate query request builder
+    pub fn builder() -> ValidateQueryRequestBuilder {
+        ValidateQueryRequestBuilder::default()
+    }
+
+    /// Send the request to the server
+    pub async fn send(self) -> Result<crate::types::indices::ValidateQueryResponse, Error> {
+        let mut path = format!("/{}/_validate/query", self.index);
+        let mut query_params = Vec::new();
+
+        if let Some(explain) = self.explain {
+            query_params.push(format!("explain={}", explain));
+        }
+
+        if let Some(rewrite) = self.rewrite {
+            query_params.push(format!("rewrite={}", rewrite));
+        }
+
+        if !query_params.is_empty() {
+            path = format!("{}?{}", path, query_params.join("&"));
+        }
+
+        let body = json!({
+            "query": self.query,
+        });
+
+        self.client
+            .request::<Value, crate::types::indices::ValidateQueryResponse>(
+                Method::POST,
+                &path,
+                Some(&body),
+            )
+            .await
+    }
+}
+
 impl IndicesNamespace {
     /// Create an index with the given settings
     /// Note: Create index API only supports a single index name
@@ -952,4 +1014,12 @@ impl IndicesNamespace {
         builder.client(self.client.clone());
         builder
     }
+
+    /// Validate a query without executing it
+    pub fn validate_query(&self, index: impl Into<IndexList>) -> ValidateQueryRequestBuilder {
+        let mut builder = ValidateQueryRequestBuilder::default();
+        builder.index(index.into());
+        builder.client(self.client.clone());
+        builder
+    }
 }
@@ -7,5 +7,8 @@ pub mod indices;
 pub mod documents;
 pub mod cluster;
 pub mod search;
-mod bulk;
+pub mod bulk;
+pub mod bulk_indexer;
+pub mod dead_letter;
 
+pub mod async_search;
@@ -6,10 +6,13 @@ use crate::types::query::*;
 use crate::types::search::*;
 use crate::Client;
 use derive_builder::Builder;
+use futures::stream::{self, Stream, TryStreamExt};
 use reqwest::Method;
 use serde::Deserialize;
 use serde_json::json;
 use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
 
 /// Builder for creating and executing search queries
 #[derive(Debug, Clone, Builder)]
@@ -91,10 +94,179 @@ where
     #[builder(setter(strip_option), default)]
     min_score: Option<f64>,
 
+    /// Whether and how precisely to count the matching hits
+    #[builder(setter(strip_option), default)]
+    track_total_hits: Option<TrackTotalHits>,
+
+    /// Query filtering the hits after aggregations are computed
+    #[builder(setter(strip_option), default)]
+    post_filter: Option<Query>,
+
+    /// Collapsing of the hits on a field
+    #[builder(setter(strip_option), default)]
+    collapse: Option<Collapse>,
+
+    /// Rescoring of the top hits, applied in order
+    #[builder(setter(strip_option), default)]
+    rescore: Option<Vec<Rescore>>,
+
+    /// Boosts of the scores of hits from specific indices
+    #[builder(setter(strip_option), default)]
+    indices_boost: Option<Vec<IndexBoost>>,
+
+    /// Doc-value fields to return
+    #[builder(setter(strip_option), default)]
+    docvalue_fields: Option<Vec<FieldAndFormat>>,
+
+    /// Fields to return from the mapping, with optional formats
+    #[builder(setter(strip_option), default)]
+    fields: Option<Vec<FieldAndFormat>>,
+
+    /// Time to wait for each shard, such as `10s`
+    #[builder(setter(strip_option), default)]
+    timeout: Option<String>,
+
+    /// Maximum number of documents to collect per shard
+    #[builder(setter(strip_option), default)]
+    terminate_after: Option<u64>,
+
+    /// Whether to include the sequence number and primary term of the hits
+    #[builder(setter(strip_option), default)]
+    seq_no_primary_term: Option<bool>,
+
+    /// Statistics groups to associate the search with
+    #[builder(setter(strip_option), default)]
+    stats: Option<Vec<String>>,
+
+    /// Whether to profile the execution of the search
+    #[builder(setter(strip_option), default)]
+    profile: Option<bool>,
+
+    /// Sections of the body used by plugins
+    #[builder(setter(strip_option), default)]
+    ext: Option<serde_json::Value>,
+
+    /// Suggesters of terms, phrases or completions similar to a text
+    #[builder(setter(strip_option), default)]
+    suggest: Option<Suggest>,
+
+    /// Node or shard preference for executing the search
+    #[builder(setter(strip_option), default)]
+    preference: Option<String>,
+
+    /// Routing value used to target specific shards
+    #[builder(setter(strip_option), default)]
+    routing: Option<String>,
+
+    /// How document frequencies are computed for scoring
+    #[builder(setter(strip_option), default)]
+    search_type: Option<SearchType>,
+
+    /// Whether to use the shard request cache
+    #[builder(setter(strip_option), default)]
+    request_cache: Option<bool>,
+
+    /// Whether to return partial results when some shards fail or time out
+    #[builder(setter(strip_option), default)]
+    allow_partial_search_results: Option<bool>,
+
     /// Scroll parameter for cursor-based pagination
     #[builder(setter(strip_option), default)]
     scroll: Option<String>,
 
+    /// Point in time to search, in place of the index
+    #[builder(setter(strip_option), default)]
+    pit: Option<PointInTime>,
+
+    /// Slice of a scroll or point-in-time search to read
+    #[builder(setter(strip_option), default)]
+    slice: Option<Slice>,
+
+    /// Type marker for the document type
+    #[builder(setter(skip), default = "std::marker::PhantomData")]
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// Builder for counting the documents matching a query
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+pub struct CountQuery {
+    /// The namespace for performing search operations
+    #[builder(setter(into))]
+    client: Client,
+
+    /// The index to count documents in (required)
+    #[builder(setter(into))]
+    index: String,
+
+    /// The query documents must match to be counted, all documents if unset
+    #[builder(setter(strip_option), default)]
+    query: Option<Query>,
+
+    /// Routing value used to target specific shards
+    #[builder(setter(strip_option), default)]
+    routing: Option<String>,
+
+    /// Node or shard preference for executing the count
+    #[builder(setter(strip_option), default)]
+    preference: Option<String>,
+
+    /// Minimum score a document must have to be counted
+    #[builder(setter(strip_option), default)]
+    min_score: Option<f64>,
+
+    /// Maximum number of documents to count per shard
+    #[builder(setter(strip_option), default)]
+    terminate_after: Option<u64>,
+
+    /// Whether to ignore missing or closed indices
+    #[builder(setter(strip_option), default)]
+    ignore_unavailable: Option<bool>,
+}
+
+/// Builder for explaining how a document scores against a query
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+pub struct ExplainQuery<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync,
+{
+    /// The namespace for performing search operations
+    #[builder(setter(into))]
+    client: Client,
+
+    /// The index containing the document (required)
+    #[builder(setter(into))]
+    index: String,
+
+    /// The ID of the document to explain (required)
+    #[builder(setter(into))]
+    id: String,
+
+    /// The query to explain the document's score against
+    #[builder(default)]
+    query: Query,
+
+    /// Routing value used when the document was indexed
+    #[builder(setter(strip_option), default)]
+    routing: Option<String>,
+
+    /// Node or shard preference for executing the explanation
+    #[builder(setter(strip_option), default)]
+    preference: Option<String>,
+
+    /// Whether to return the document source alongside the explanation
+    #[builder(setter(strip_option), default)]
+    source: Option<bool>,
+
     /// Type marker for the document type
     #[builder(setter(skip), default = "std::marker::PhantomData")]
     _marker: std::marker::PhantomData<T>,
@@ -159,83 +331,897 @@ where
 
 /// Builder for creating point-in-time search contexts
 #[derive(Debug, Clone, Builder)]
-#[builder(pattern = "mutable")]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
 pub struct PointInTimeQuery {
     /// The namespace for performing search operations
     #[builder(setter(into))]
     client: Client,
 
-    /// The index to create point-in-time for (required)
-    #[builder(setter(into), default)]
-    index: String,
+    /// The index to create point-in-time for (required)
+    #[builder(setter(into), default)]
+    index: String,
+
+    /// How long to keep the search context alive
+    #[builder(setter(into), default)]
+    keep_alive: String,
+
+    /// Routing value used to target specific shards
+    #[builder(setter(into, strip_option), default)]
+    routing: Option<String>,
+
+    /// Node or shard preference for creating the point in time
+    #[builder(setter(into, strip_option), default)]
+    preference: Option<String>,
+}
+
+/// Builder for deleting point-in-time search contexts
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
+pub struct DeletePointInTimeQuery {
+    /// The namespace for performing search operations
+    #[builder(setter(into))]
+    client: Client,
+
+    /// The point-in-time ID to delete
+    #[builder(setter(into), default)]
+    pit_id: String,
+}
+
+/// Builder for searching with a stored or inline search template
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+pub struct SearchTemplateQuery<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync,
+{
+    /// The namespace for performing search operations
+    #[builder(setter(into))]
+    client: Client,
+
+    /// The index to search (required)
+    #[builder(setter(into))]
+    index: String,
+
+    /// ID of a stored template
+    #[builder(setter(strip_option), default)]
+    id: Option<String>,
+
+    /// Inline mustache template
+    #[builder(setter(strip_option), default)]
+    source: Option<serde_json::Value>,
+
+    /// Values of the template parameters
+    #[builder(setter(strip_option), default)]
+    params: Option<HashMap<String, serde_json::Value>>,
+
+    /// Whether to explain the scoring
+    #[builder(setter(strip_option), default)]
+    explain: Option<bool>,
+
+    /// Whether to profile the execution of the search
+    #[builder(setter(strip_option), default)]
+    profile: Option<bool>,
+
+    /// Scroll parameter for cursor-based pagination
+    #[builder(setter(strip_option), default)]
+    scroll: Option<String>,
+
+    /// Node or shard preference for executing the search
+    #[builder(setter(strip_option), default)]
+    preference: Option<String>,
+
+    /// Routing value used to target specific shards
+    #[builder(setter(strip_option), default)]
+    routing: Option<String>,
+
+    /// How document frequencies are computed for scoring
+    #[builder(setter(strip_option), default)]
+    search_type: Option<SearchType>,
+
+    /// Type marker for the document type
+    #[builder(setter(skip), default = "std::marker::PhantomData")]
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> SearchTemplateQueryBuilder<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync,
+{
+    /// Set the value of a template parameter
+    pub fn param(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> &mut Self {
+        self.params
+            .get_or_insert_with(|| Some(HashMap::new()))
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+}
+
+/// Builder for multi-search template queries
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
+pub struct MSearchTemplateQuery<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync,
+{
+    /// The namespace for performing search operations
+    #[builder(setter(into))]
+    client: Client,
+
+    /// Templated search requests to execute
+    #[builder(default)]
+    templates: Vec<MSearchTemplateItem>,
+
+    /// Type marker for the document type
+    #[builder(setter(skip), default = "std::marker::PhantomData")]
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// Builder for rendering a search template without running the search
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+pub struct RenderSearchTemplateQuery {
+    /// The namespace for performing search operations
+    #[builder(setter(into))]
+    client: Client,
+
+    /// ID of a stored template
+    #[builder(setter(strip_option), default)]
+    id: Option<String>,
+
+    /// Inline mustache template
+    #[builder(setter(strip_option), default)]
+    source: Option<serde_json::Value>,
+
+    /// Values of the template parameters
+    #[builder(setter(strip_option), default)]
+    params: Option<HashMap<String, serde_json::Value>>,
+}
+
+impl RenderSearchTemplateQueryBuilder {
+    /// Set the value of a template parameter
+    pub fn param(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> &mut Self {
+        self.params
+            .get_or_insert_with(|| Some(HashMap::new()))
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+}
+
+/// Builder for storing a mustache search template
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
+pub struct PutSearchTemplateQuery {
+    /// The namespace for performing search operations
+    #[builder(setter(into))]
+    client: Client,
+
+    /// Template ID
+    #[builder(setter(into))]
+    id: String,
+
+    /// Mustache template, as a search body object or a string
+    #[builder(setter(into))]
+    source: serde_json::Value,
+}
+
+/// Builder for getting a stored search template
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
+pub struct GetSearchTemplateQuery {
+    /// The namespace for performing search operations
+    #[builder(setter(into))]
+    client: Client,
+
+    /// Template ID
+    #[builder(setter(into))]
+    id: String,
+}
+
+/// Builder for deleting a stored search template
+#[derive(Debug, Clone, Builder)]
+#[builder(pattern = "mutable", build_fn(error = "crate::Error"))]
+pub struct DeleteSearchTemplateQuery {
+    /// The namespace for performing search operations
+    #[builder(setter(into))]
+    client: Client,
+
+    /// Template ID
+    #[builder(setter(into))]
+    id: String,
+}
+
+impl<T> SearchQuery<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    /// Execute the search query and return results
+    pub async fn send(self) -> Result<SearchResponse<T>, Error> {
+        // A point in time already targets its indices
+        let mut path = match &self.pit {
+            Some(_) => "/_search".to_string(),
+            None => format!("/{}/_search", self.index),
+        };
+        let client = self.client.clone();
+        let (_, query_params, body) = self.into_parts();
+
+        // Add query parameters to path if any exist
+        if !query_params.is_empty() {
+            let query_string: Vec<String> = query_params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            path = format!("{}?{}", path, query_string.join("&"));
+        }
+
+        client
+            .request::<_, SearchResponse<T>>(Method::POST, &path, Some(&body))
+            .await
+    }
+
+    /// Index, query-string parameters and body of the search request
+    pub(crate) fn into_parts(self) -> (String, Vec<(&'static str, String)>, serde_json::Value) {
+        let mut query_params = Vec::new();
+
+        // Add parameters that should be in query string according to the OpenSearch API
+        if let Some(scroll_val) = &self.scroll {
+            query_params.push(("scroll", scroll_val.clone()));
+        }
+
+        if let Some(from_val) = &self.from {
+            query_params.push(("from", from_val.to_string()));
+        }
+
+        if let Some(size_val) = &self.size {
+            query_params.push(("size", size_val.to_string()));
+        }
+
+        // Add source parameters to query string if they are simple forms
+        if let Some(source_val) = &self.source {
+            query_params.push(("_source", source_val.to_string()));
+        }
+
+        if let Some(source_includes_val) = &self.source_includes {
+            query_params.push(("_source_includes", source_includes_val.join(",")));
+        }
+
+        if let Some(source_excludes_val) = &self.source_excludes {
+            query_params.push(("_source_excludes", source_excludes_val.join(",")));
+        }
+
+        if let Some(stored_fields_val) = &self.stored_fields {
+            query_params.push(("stored_fields", stored_fields_val.join(",")));
+        }
+
+        if let Some(explain_val) = &self.explain {
+            query_params.push(("explain", explain_val.to_string()));
+        }
+
+        if let Some(version_val) = &self.version {
+            query_params.push(("version", version_val.to_string()));
+        }
+
+        if let Some(preference_val) = &self.preference {
+            query_params.push(("preference", preference_val.clone()));
+        }
+
+        if let Some(routing_val) = &self.routing {
+            query_params.push(("routing", routing_val.clone()));
+        }
+
+        if let Some(search_type_val) = &self.search_type {
+            query_params.push(("search_type", search_type_val.as_str().to_string()));
+        }
+
+        if let Some(request_cache_val) = &self.request_cache {
+            query_params.push(("request_cache", request_cache_val.to_string()));
+        }
+
+        if let Some(allow_partial_val) = &self.allow_partial_search_results {
+            query_params.push((
+                "allow_partial_search_results",
+                allow_partial_val.to_string(),
+            ));
+        }
+
+        // Start with just the query in the body
+        let mut body = json!({
+            "query": self.query,
+        });
+
+        // These complex parameters stay in the request body
+        if let Some(highlight_val) = self.highlight {
+            body["highlight"] = json!(highlight_val);
+        }
+
+        if let Some(aggs_val) = self.aggregations {
+            body["aggs"] = json!(aggs_val);
+        }
+
+        if let Some(search_after_val) = self.search_after {
+            body["search_after"] = json!(search_after_val);
+        }
+
+        if let Some(pit_val) = self.pit {
+            body["pit"] = json!(pit_val);
+        }
+
+        if let Some(slice_val) = self.slice {
+            body["slice"] = json!(slice_val);
+        }
+
+        if let Some(script_fields_val) = self.script_fields {
+            body["script_fields"] = json!(script_fields_val);
+        }
+
+        if let Some(min_score_val) = self.min_score {
+            body["min_score"] = json!(min_score_val);
+        }
+
+        if let Some(track_total_hits_val) = self.track_total_hits {
+            body["track_total_hits"] = json!(track_total_hits_val);
+        }
+
+        if let Some(post_filter_val) = self.post_filter {
+            body["post_filter"] = json!(post_filter_val);
+        }
+
+        if let Some(collapse_val) = self.collapse {
+            body["collapse"] = json!(collapse_val);
+        }
+
+        if let Some(rescore_val) = self.rescore {
+            body["rescore"] = json!(rescore_val);
+        }
+
+        if let Some(indices_boost_val) = self.indices_boost {
+            body["indices_boost"] = json!(indices_boost_val);
+        }
+
+        if let Some(docvalue_fields_val) = self.docvalue_fields {
+            body["docvalue_fields"] = json!(docvalue_fields_val);
+        }
+
+        if let Some(fields_val) = self.fields {
+            body["fields"] = json!(fields_val);
+        }
+
+        if let Some(timeout_val) = self.timeout {
+            body["timeout"] = json!(timeout_val);
+        }
+
+        if let Some(terminate_after_val) = self.terminate_after {
+            body["terminate_after"] = json!(terminate_after_val);
+        }
+
+        if let Some(seq_no_primary_term_val) = self.seq_no_primary_term {
+            body["seq_no_primary_term"] = json!(seq_no_primary_term_val);
+        }
+
+        if let Some(stats_val) = self.stats {
+            body["stats"] = json!(stats_val);
+        }
+
+        if let Some(profile_val) = self.profile {
+            body["profile"] = json!(profile_val);
+        }
+
+        if let Some(ext_val) = self.ext {
+            body["ext"] = json!(ext_val);
+        }
+
+        if let Some(suggest_val) = self.suggest {
+            body["suggest"] = json!(suggest_val);
+        }
+
+        // Add sort to the request body per OpenSearch docs
+        if let Some(sort_val) = self.sort {
+            body["sort"] = json!(sort_val);
+        }
+
+        (self.index, query_params, body)
+    }
+}
+
+impl<T> SearchQueryBuilder<T>
+where
+    T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    /// Stream every hit of the search with the scroll API
+    ///
+    /// Pages are fetched lazily as the stream is polled, each scroll request
+    /// following the latest `_scroll_id`. The scroll context is cleared when
+    /// the last page has been read, when a request fails, or when the stream
+    /// is dropped before the end. `size` sets the number of hits per page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let mut hits = Box::pin(
+    ///         client
+    ///             .search::<serde_json::Value>("logs")
+    ///             .size(1000)
+    ///             .scroll_stream("1m"),
+    ///     );
+    ///     while let Some(hit) = hits.try_next().await? {
+    ///         println!("{}: {:?}", hit.id, hit.source);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn scroll_stream(
+        &self,
+        keep_alive: impl Into<String>,
+    ) -> impl Stream<Item = Result<SearchHit<T>, Error>> {
+        let keep_alive = keep_alive.into();
+        let mut builder = self.clone();
+        builder.scroll(keep_alive.clone());
+        let query = builder.build().map(Box::new);
+        let context = SearchContext {
+            client: query.as_ref().map(|query| query.client.clone()).ok(),
+            kind: ContextKind::Scroll,
+            id: None,
+        };
+
+        let state = (ScrollStep::Search(query), context);
+        stream::unfold(state, move |(step, mut context)| {
+            let keep_alive = keep_alive.clone();
+            async move {
+                let response = match step {
+                    ScrollStep::Search(query) => match query {
+                        Ok(query) => query
+                            .send()
+                            .await
+                            .map(|response| (response.scroll_id, response.hits.hits)),
+                        Err(err) => Err(err),
+                    },
+                    ScrollStep::Scroll(client) => {
+                        let scroll_id = context.id.clone().unwrap_or_default();
+                        ScrollQuery::<T> {
+                            client,
+                            scroll_id,
+                            scroll: keep_alive,
+                            _marker: std::marker::PhantomData,
+                        }
+                        .send()
+                        .await
+                        .map(|response| (Some(response.scroll_id), response.hits.hits))
+                    }
+                    ScrollStep::Done => return None,
+                };
+
+                match response {
+                    Ok((scroll_id, hits)) => {
+                        if scroll_id.is_some() {
+                            context.id = scroll_id;
+                        }
+                        match (&context.client, &context.id) {
+                            (Some(client), Some(_)) if !hits.is_empty() => {
+                                let next = ScrollStep::Scroll(client.clone());
+                                Some((Ok(hits), (next, context)))
+                            }
+                            _ => {
+                                context.clear().await;
+                                Some((Ok(hits), (ScrollStep::Done, context)))
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        context.clear().await;
+                        Some((Err(err), (ScrollStep::Done, context)))
+                    }
+                }
+            }
+        })
+        .map_ok(|hits| stream::iter(hits.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Stream every hit of the search from a point in time with `search_after`
+    ///
+    /// A point in time is opened on the index, then pages are fetched lazily
+    /// as the stream is polled, each one continuing after the sort values of
+    /// the last hit. `_id` is added to the sort as a tiebreaker unless the
+    /// sort already contains it, so no hit is skipped or repeated. Every
+    /// request extends the point in time by `keep_alive`, and the point in
+    /// time is deleted when the last page has been read, when a request
+    /// fails, or when the stream is dropped before the end. `size` sets the
+    /// number of hits per page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use opensearch_api::types::search::{SortOptions, SortOrder, SortTerm};
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let by_timestamp = SortOptions::builder().order(SortOrder::Asc).build()?;
+    ///     let mut hits = Box::pin(
+    ///         client
+    ///             .search::<serde_json::Value>("logs")
+    ///             .sort(vec![SortTerm::from(("@timestamp", by_timestamp))])
+    ///             .size(1000)
+    ///             .pit_stream("1m"),
+    ///     );
+    ///     while let Some(hit) = hits.try_next().await? {
+    ///         println!("{}: {:?}", hit.id, hit.source);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn pit_stream(
+        &self,
+        keep_alive: impl Into<String>,
+    ) -> impl Stream<Item = Result<SearchHit<T>, Error>> {
+        pit_pages(
+            self.pit_query(),
+            Arc::new(OnceCell::new()),
+            keep_alive.into(),
+        )
+    }
+
+    /// Split the search into slices read in parallel with the scroll API
+    ///
+    /// Each slice is a [`scroll_stream`](Self::scroll_stream) over its own
+    /// scroll context, selected with the `slice` parameter, so an error ends
+    /// only the slice it happened in and every context is cleared when its
+    /// slice ends or is dropped. The slices can be merged into one stream
+    /// with [`futures::stream::select_all`] or handed to separate workers.
+    /// A single slice searches without the `slice` parameter.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::stream::{self, TryStreamExt};
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let slices = client
+    ///         .search::<serde_json::Value>("logs")
+    ///         .size(1000)
+    ///         .scroll_slices("1m", 4);
+    ///     let mut hits = stream::select_all(slices.into_iter().map(Box::pin));
+    ///     while let Some(hit) = hits.try_next().await? {
+    ///         println!("{}: {:?}", hit.id, hit.source);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn scroll_slices(
+        &self,
+        keep_alive: impl Into<String>,
+        slices: u32,
+    ) -> Vec<impl Stream<Item = Result<SearchHit<T>, Error>>> {
+        let keep_alive = keep_alive.into();
+        (0..slices.max(1))
+            .map(|id| {
+                let mut builder = self.clone();
+                if slices > 1 {
+                    builder.slice(Slice::new(id, slices));
+                }
+                builder.scroll_stream(keep_alive.clone())
+            })
+            .collect()
+    }
+
+    /// Split the search into slices read in parallel from one point in time
+    ///
+    /// Each slice pages through the same point in time with `search_after`
+    /// like [`pit_stream`](Self::pit_stream), selected with the `slice`
+    /// parameter. The point in time is opened by the first slice polled and
+    /// deleted once every slice has ended or been dropped, and an error ends
+    /// only the slice it happened in. A single slice searches without the
+    /// `slice` parameter.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let slices = client
+    ///         .search::<serde_json::Value>("logs")
+    ///         .size(1000)
+    ///         .pit_slices("1m", 4);
+    ///
+    ///     // Each slice is exported by its own task
+    ///     let workers: Vec<_> = slices
+    ///         .into_iter()
+    ///         .map(|slice| {
+    ///             tokio::spawn(async move {
+    ///                 let mut slice = Box::pin(slice);
+    ///                 let mut exported = 0;
+    ///                 while slice.try_next().await?.is_some() {
+    ///                     exported += 1;
+    ///                 }
+    ///                 Ok::<_, Error>(exported)
+    ///             })
+    ///         })
+    ///         .collect();
+    ///     for (slice, worker) in workers.into_iter().enumerate() {
+    ///         match worker.await {
+    ///             Ok(Ok(exported)) => println!("slice {}: {} hits", slice, exported),
+    ///             Ok(Err(err)) => eprintln!("slice {} failed: {}", slice, err),
+    ///             Err(err) => eprintln!("slice {} panicked: {}", slice, err),
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn pit_slices(
+        &self,
+        keep_alive: impl Into<String>,
+        slices: u32,
+    ) -> Vec<impl Stream<Item = Result<SearchHit<T>, Error>>> {
+        let keep_alive = keep_alive.into();
+        let context = Arc::new(OnceCell::new());
+        (0..slices.max(1))
+            .map(|id| {
+                let mut builder = self.clone();
+                if slices > 1 {
+                    builder.slice(Slice::new(id, slices));
+                }
+                pit_pages(builder.pit_query(), context.clone(), keep_alive.clone())
+            })
+            .collect()
+    }
+
+    /// Build the query of a point-in-time search, sorted with a tiebreaker
+    fn pit_query(&self) -> Result<Box<SearchQuery<T>>, Error> {
+        self.build().map(|mut query| {
+            let mut sort = query.sort.take().unwrap_or_default();
+            if !sort
+                .iter()
+                .any(|term| term.0.iter().any(|entry| entry.field == "_id"))
+            {
+                let ascending = SortOptions {
+                    order: Some(SortOrder::Asc),
+                    ..Default::default()
+                };
+                sort.push(SortTerm::from(("_id", ascending)));
+            }
+            query.sort = Some(sort);
+            query.from = None;
+            query.scroll = None;
+            Box::new(query)
+        })
+    }
+}
 
-    /// How long to keep the search context alive
-    #[builder(setter(into), default)]
+/// Stream the hits of a point-in-time search page by page with `search_after`
+///
+/// The point in time is opened on the first page if no other slice sharing
+/// the context has opened it yet.
+fn pit_pages<T>(
+    query: Result<Box<SearchQuery<T>>, Error>,
+    context: Arc<OnceCell<SearchContext>>,
     keep_alive: String,
+) -> impl Stream<Item = Result<SearchHit<T>, Error>>
+where
+    T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    stream::unfold(PitStep::Page(query, context), move |step| {
+        let keep_alive = keep_alive.clone();
+        async move {
+            let PitStep::Page(query, mut context) = step else {
+                return None;
+            };
+
+            let page = async {
+                let mut query = *query?;
+                let opened = context
+                    .get_or_try_init(|| SearchContext::open_point_in_time(&query, &keep_alive))
+                    .await?;
+                let pit_id = opened.id.clone().unwrap_or_default();
+                query.pit = Some(PointInTime::new(pit_id).keep_alive(keep_alive.clone()));
+                let next = query.clone();
+                query.send().await.map(|response| (response, next))
+            }
+            .await;
+
+            match page {
+                Ok((response, mut next)) => {
+                    // The ID of the point in time may change between requests,
+                    // which is only followed when no other slice shares it
+                    let opened = Arc::get_mut(&mut context).and_then(OnceCell::get_mut);
+                    if let (Some(pit_id), Some(opened)) = (response.pit_id, opened) {
+                        opened.id = Some(pit_id);
+                    }
+                    let page_size = next.size.unwrap_or(10).max(0) as usize;
+                    let hits = response.hits.hits;
+                    match hits.last().and_then(|hit| hit.sort.clone()) {
+                        Some(search_after) if hits.len() >= page_size => {
+                            next.search_after = Some(search_after);
+                            Some((Ok(hits), PitStep::Page(Ok(Box::new(next)), context)))
+                        }
+                        _ => {
+                            SearchContext::clear_shared(context).await;
+                            Some((Ok(hits), PitStep::Done))
+                        }
+                    }
+                }
+                Err(err) => {
+                    SearchContext::clear_shared(context).await;
+                    Some((Err(err), PitStep::Done))
+                }
+            }
+        }
+    })
+    .map_ok(|hits| stream::iter(hits.into_iter().map(Ok)))
+    .try_flatten()
 }
 
-/// Builder for deleting point-in-time search contexts
-#[derive(Debug, Clone, Builder)]
-#[builder(pattern = "mutable")]
-pub struct DeletePointInTimeQuery {
-    /// The namespace for performing search operations
-    #[builder(setter(into))]
-    client: Client,
-
-    /// The point-in-time ID to delete
-    #[builder(setter(into), default)]
-    pit_id: String,
+/// Next request of a scroll stream
+enum ScrollStep<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync,
+{
+    /// Initial search opening the scroll context
+    Search(Result<Box<SearchQuery<T>>, Error>),
+    /// Request for the next page of the scroll context
+    Scroll(Client),
+    /// The stream has ended
+    Done,
 }
 
-impl<T> SearchQuery<T>
+/// Next request of a point-in-time stream
+enum PitStep<T>
 where
-    T: Default + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    T: Default + for<'de> Deserialize<'de> + Send + Sync,
 {
-    /// Execute the search query and return results
-    pub async fn send(self) -> Result<SearchResponse<T>, Error> {
-        let index_str = self.index;
-        let mut path = format!("/{}/_search", index_str);
-        let mut query_params = Vec::new();
+    /// Search for the next page of the point in time, possibly shared with other slices
+    Page(
+        Result<Box<SearchQuery<T>>, Error>,
+        Arc<OnceCell<SearchContext>>,
+    ),
+    /// The stream has ended
+    Done,
+}
 
-        // Add parameters that should be in query string according to the OpenSearch API
-        if let Some(scroll_val) = &self.scroll {
-            query_params.push(("scroll", scroll_val.clone()));
+/// Kind of server-side context kept open by a stream of hits
+#[derive(Debug, Clone, Copy)]
+enum ContextKind {
+    Scroll,
+    PointInTime,
+}
+
+/// Open scroll or point-in-time context, released when dropped if it was not released before
+struct SearchContext {
+    client: Option<Client>,
+    kind: ContextKind,
+    id: Option<String>,
+}
+
+impl SearchContext {
+    /// Open a point in time on the index of the query
+    async fn open_point_in_time<T>(query: &SearchQuery<T>, keep_alive: &str) -> Result<Self, Error>
+    where
+        T: Default + for<'de> Deserialize<'de> + Send + Sync,
+    {
+        let response = PointInTimeQuery {
+            client: query.client.clone(),
+            index: query.index.clone(),
+            keep_alive: keep_alive.to_string(),
+            routing: None,
+            preference: None,
         }
+        .send()
+        .await?;
+        Ok(Self {
+            client: Some(query.client.clone()),
+            kind: ContextKind::PointInTime,
+            id: Some(response.pit_id),
+        })
+    }
 
-        if let Some(from_val) = &self.from {
-            query_params.push(("from", from_val.to_string()));
+    /// Release a context shared between slices once the last of them is done with it
+    async fn clear_shared(context: Arc<OnceCell<Self>>) {
+        if let Some(mut context) = Arc::into_inner(context).and_then(OnceCell::into_inner) {
+            context.clear().await;
         }
+    }
 
-        if let Some(size_val) = &self.size {
-            query_params.push(("size", size_val.to_string()));
+    /// Release the context, logging failures since the hits were already read
+    async fn clear(&mut self) {
+        if let (Some(client), Some(id)) = (self.client.clone(), self.id.take()) {
+            Self::release(client, self.kind, id).await;
         }
+    }
 
-        // Add source parameters to query string if they are simple forms
-        if let Some(source_val) = &self.source {
-            query_params.push(("_source", source_val.to_string()));
+    async fn release(client: Client, kind: ContextKind, id: String) {
+        let result = match kind {
+            ContextKind::Scroll => ClearScrollQuery {
+                client,
+                scroll_ids: vec![id],
+            }
+            .send()
+            .await
+            .map(|_| ()),
+            ContextKind::PointInTime => DeletePointInTimeQuery { client, pit_id: id }
+                .send()
+                .await
+                .map(|_| ()),
+        };
+        if let Err(err) = result {
+            log::warn!("Failed to release {:?} context: {}", kind, err);
         }
+    }
+}
 
-        if let Some(source_includes_val) = &self.source_includes {
-            query_params.push(("_source_includes", source_includes_val.join(",")));
+impl Drop for SearchContext {
+    fn drop(&mut self) {
+        let (Some(client), Some(id)) = (self.client.take(), self.id.take()) else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            log::warn!(
+                "{:?} context {} left open outside of a runtime",
+                self.kind,
+                id
+            );
+            return;
+        };
+        runtime.spawn(Self::release(client, self.kind, id));
+    }
+}
+
+impl CountQuery {
+    /// Execute the count query and return the number of matching documents
+    pub async fn send(self) -> Result<CountResponse, Error> {
+        let mut path = format!("/{}/_count", self.index);
+        let mut query_params = Vec::new();
+
+        if let Some(routing_val) = &self.routing {
+            query_params.push(("routing", routing_val.clone()));
         }
 
-        if let Some(source_excludes_val) = &self.source_excludes {
-            query_params.push(("_source_excludes", source_excludes_val.join(",")));
+        if let Some(preference_val) = &self.preference {
+            query_params.push(("preference", preference_val.clone()));
         }
 
-        if let Some(stored_fields_val) = &self.stored_fields {
-            query_params.push(("stored_fields", stored_fields_val.join(",")));
+        if let Some(min_score_val) = &self.min_score {
+            query_params.push(("min_score", min_score_val.to_string()));
         }
 
-        if let Some(explain_val) = &self.explain {
-            query_params.push(("explain", explain_val.to_string()));
+        if let Some(terminate_after_val) = &self.terminate_after {
+            query_params.push(("terminate_after", terminate_after_val.to_string()));
         }
 
-        if let Some(version_val) = &self.version {
-            query_params.push(("version", version_val.to_string()));
+        if let Some(ignore_unavailable_val) = &self.ignore_unavailable {
+            query_params.push(("ignore_unavailable", ignore_unavailable_val.to_string()));
         }
 
-        // Add query parameters to path if any exist
         if !query_params.is_empty() {
             let query_string: Vec<String> = query_params
                 .iter()
@@ -244,39 +1230,49 @@ where
             path = format!("{}?{}", path, query_string.join("&"));
         }
 
-        // Start with just the query in the body
-        let mut body = json!({
-            "query": self.query,
-        });
+        let body = self.query.map(|query| json!({ "query": query }));
 
-        // These complex parameters stay in the request body
-        if let Some(highlight_val) = self.highlight {
-            body["highlight"] = json!(highlight_val);
-        }
+        self.client
+            .request::<_, CountResponse>(Method::POST, &path, body.as_ref())
+            .await
+    }
+}
 
-        if let Some(aggs_val) = self.aggregations {
-            body["aggs"] = json!(aggs_val);
-        }
+impl<T> ExplainQuery<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    /// Execute the explain request and return the score explanation
+    pub async fn send(self) -> Result<ExplainResponse<T>, Error> {
+        let mut path = format!("/{}/_explain/{}", self.index, self.id);
+        let mut query_params = Vec::new();
 
-        if let Some(search_after_val) = self.search_after {
-            body["search_after"] = json!(search_after_val);
+        if let Some(routing_val) = &self.routing {
+            query_params.push(("routing", routing_val.clone()));
         }
 
-        if let Some(script_fields_val) = self.script_fields {
-            body["script_fields"] = json!(script_fields_val);
+        if let Some(preference_val) = &self.preference {
+            query_params.push(("preference", preference_val.clone()));
         }
 
-        if let Some(min_score_val) = self.min_score {
-            body["min_score"] = json!(min_score_val);
+        if let Some(source_val) = &self.source {
+            query_params.push(("_source", source_val.to_string()));
         }
 
-        // Add sort to the request body per OpenSearch docs
-        if let Some(sort_val) = self.sort {
-            body["sort"] = json!(sort_val);
+        if !query_params.is_empty() {
+            let query_string: Vec<String> = query_params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            path = format!("{}?{}", path, query_string.join("&"));
         }
 
+        let body = json!({
+            "query": self.query,
+        });
+
         self.client
-            .request::<_, SearchResponse<T>>(Method::POST, &path, Some(&body))
+            .request::<_, ExplainResponse<T>>(Method::POST, &path, Some(&body))
             .await
     }
 }
@@ -374,14 +1370,21 @@ where
 impl PointInTimeQuery {
     /// Execute the point-in-time creation request
     pub async fn send(self) -> Result<PointInTimeResponse, Error> {
-        let path = format!("/{}/_pit", self.index);
+        let mut path = format!(
+            "/{}/_search/point_in_time?keep_alive={}",
+            self.index, self.keep_alive
+        );
 
-        let body = json!({
-            "keep_alive": self.keep_alive
-        });
+        if let Some(routing_val) = &self.routing {
+            path = format!("{}&routing={}", path, routing_val);
+        }
+
+        if let Some(preference_val) = &self.preference {
+            path = format!("{}&preference={}", path, preference_val);
+        }
 
         self.client
-            .request::<_, PointInTimeResponse>(Method::POST, &path, Some(&body))
+            .request::<(), PointInTimeResponse>(Method::POST, &path, None)
             .await
     }
 }
@@ -389,10 +1392,10 @@ impl PointInTimeQuery {
 impl DeletePointInTimeQuery {
     /// Execute the point-in-time deletion request
     pub async fn send(self) -> Result<DeletePointInTimeResponse, Error> {
-        let path = "/_pit";
+        let path = "/_search/point_in_time";
 
         let body = json!({
-            "id": self.pit_id
+            "pit_id": [self.pit_id]
         });
 
         self.client
@@ -401,6 +1404,151 @@ impl DeletePointInTimeQuery {
     }
 }
 
+impl<T> SearchTemplateQuery<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    /// Render the template and execute the search
+    pub async fn send(self) -> Result<SearchResponse<T>, Error> {
+        let template = SearchTemplate {
+            id: self.id,
+            source: self.source,
+            params: self.params,
+            explain: self.explain,
+            profile: self.profile,
+        };
+        template.validate()?;
+
+        let mut path = format!("/{}/_search/template", self.index);
+        let mut query_params = Vec::new();
+
+        if let Some(scroll_val) = &self.scroll {
+            query_params.push(("scroll", scroll_val.clone()));
+        }
+
+        if let Some(preference_val) = &self.preference {
+            query_params.push(("preference", preference_val.clone()));
+        }
+
+        if let Some(routing_val) = &self.routing {
+            query_params.push(("routing", routing_val.clone()));
+        }
+
+        if let Some(search_type_val) = &self.search_type {
+            query_params.push(("search_type", search_type_val.as_str().to_string()));
+        }
+
+        if !query_params.is_empty() {
+            let query_string: Vec<String> = query_params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            path = format!("{}?{}", path, query_string.join("&"));
+        }
+
+        self.client
+            .request::<_, SearchResponse<T>>(Method::POST, &path, Some(&template))
+            .await
+    }
+}
+
+impl<T> MSearchTemplateQuery<T>
+where
+    T: Default + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    /// Add a templated search to the multi-search template query
+    pub fn add_template(mut self, template_item: MSearchTemplateItem) -> Self {
+        self.templates.push(template_item);
+        self
+    }
+
+    /// Execute the multi-search template query and return results
+    pub async fn send(self) -> Result<MSearchResponse<T>, Error> {
+        let path = "/_msearch/template";
+
+        // Header and template lines as newline-delimited JSON, like msearch
+        let mut body = String::new();
+        for item in self.templates {
+            item.body.validate()?;
+
+            body.push_str(&serde_json::to_string(&item.header)?);
+            body.push('\n');
+
+            body.push_str(&serde_json::to_string(&item.body)?);
+            body.push('\n');
+        }
+        body.push('\n');
+
+        self.client
+            .request_with_headers::<String, MSearchResponse<T>>(
+                Method::POST,
+                path,
+                Some(body),
+                Some(vec![("Content-Type", "application/x-ndjson")]),
+            )
+            .await
+    }
+}
+
+impl RenderSearchTemplateQuery {
+    /// Execute the render request and return the generated search body
+    pub async fn send(self) -> Result<RenderSearchTemplateResponse, Error> {
+        let path = "/_render/template";
+
+        let template = SearchTemplate {
+            id: self.id,
+            source: self.source,
+            params: self.params,
+            ..SearchTemplate::default()
+        };
+        template.validate()?;
+
+        self.client
+            .request::<_, RenderSearchTemplateResponse>(Method::POST, path, Some(&template))
+            .await
+    }
+}
+
+impl PutSearchTemplateQuery {
+    /// Execute the put stored template request
+    pub async fn send(self) -> Result<PutSearchTemplateResponse, Error> {
+        let path = format!("/_scripts/{}", self.id);
+
+        let body = json!({
+            "script": {
+                "lang": "mustache",
+                "source": self.source
+            }
+        });
+
+        self.client
+            .request::<_, PutSearchTemplateResponse>(Method::PUT, &path, Some(&body))
+            .await
+    }
+}
+
+impl GetSearchTemplateQuery {
+    /// Execute the get stored template request
+    pub async fn send(self) -> Result<GetSearchTemplateResponse, Error> {
+        let path = format!("/_scripts/{}", self.id);
+
+        self.client
+            .request::<(), GetSearchTemplateResponse>(Method::GET, &path, None)
+            .await
+    }
+}
+
+impl DeleteSearchTemplateQuery {
+    /// Execute the delete stored template request
+    pub async fn send(self) -> Result<DeleteSearchTemplateResponse, Error> {
+        let path = format!("/_scripts/{}", self.id);
+
+        self.client
+            .request::<(), DeleteSearchTemplateResponse>(Method::DELETE, &path, None)
+            .await
+    }
+}
+
 impl Client {
     /// Create a search query builder
     pub fn search<T>(&self, index: impl Into<String>) -> SearchQueryBuilder<T>
@@ -413,6 +1561,30 @@ impl Client {
         builder
     }
 
+    /// Create a count query builder
+    pub fn count(&self, index: impl Into<String>) -> CountQueryBuilder {
+        let mut builder = CountQueryBuilder::default();
+        builder.client(self.clone());
+        builder.index(index);
+        builder
+    }
+
+    /// Create a builder explaining how a document scores against a query
+    pub fn explain<T>(
+        &self,
+        index: impl Into<String>,
+        id: impl Into<String>,
+    ) -> ExplainQueryBuilder<T>
+    where
+        T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        let mut builder = ExplainQueryBuilder::default();
+        builder.client(self.clone());
+        builder.index(index);
+        builder.id(id);
+        builder
+    }
+
     /// Create a multi-search query builder
     pub fn msearch<T>(&self) -> MSearchQueryBuilder<T>
     where
@@ -439,4 +1611,148 @@ impl Client {
         builder.client(self.clone());
         builder
     }
+
+    /// Create a builder opening a point in time on an index
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use opensearch_api::types::search::PointInTime;
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let pit = client
+    ///         .create_pit("logs", "5m")
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     let response = client
+    ///         .search::<serde_json::Value>("logs")
+    ///         .pit(PointInTime::new(&pit.pit_id).keep_alive("5m"))
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///     println!("{} hits", response.hits.total.value);
+    ///
+    ///     client
+    ///         .delete_pit(pit.pit_id)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn create_pit(
+        &self,
+        index: impl Into<String>,
+        keep_alive: impl Into<String>,
+    ) -> PointInTimeQueryBuilder {
+        let mut builder = PointInTimeQueryBuilder::default();
+        builder.client(self.clone());
+        builder.index(index);
+        builder.keep_alive(keep_alive);
+        builder
+    }
+
+    /// Create a builder deleting a point in time
+    pub fn delete_pit(&self, pit_id: impl Into<String>) -> DeletePointInTimeQueryBuilder {
+        let mut builder = DeletePointInTimeQueryBuilder::default();
+        builder.client(self.clone());
+        builder.pit_id(pit_id);
+        builder
+    }
+
+    /// Create a builder searching with a stored or inline search template
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    /// use serde_json::json;
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     client
+    ///         .put_search_template(
+    ///             "products-by-name",
+    ///             json!({"query": {"match": {"name": "{{name}}"}}, "size": "{{size}}"}),
+    ///         )
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///
+    ///     let response = client
+    ///         .search_template::<serde_json::Value>("products")
+    ///         .id("products-by-name")
+    ///         .param("name", "lamp")
+    ///         .param("size", 5)
+    ///         .build()?
+    ///         .send()
+    ///         .await?;
+    ///     println!("{} hits", response.hits.total.value);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn search_template<T>(&self, index: impl Into<String>) -> SearchTemplateQueryBuilder<T>
+    where
+        T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        let mut builder = SearchTemplateQueryBuilder::default();
+        builder.client(self.clone());
+        builder.index(index);
+        builder
+    }
+
+    /// Create a multi-search template query builder
+    pub fn msearch_template<T>(&self) -> MSearchTemplateQueryBuilder<T>
+    where
+        T: Default + Clone + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        let mut builder = MSearchTemplateQueryBuilder::default();
+        builder.client(self.clone());
+        builder
+    }
+
+    /// Create a builder rendering a search template into the search body it generates
+    pub fn render_search_template(&self) -> RenderSearchTemplateQueryBuilder {
+        let mut builder = RenderSearchTemplateQueryBuilder::default();
+        builder.client(self.clone());
+        builder
+    }
+
+    /// Create a builder storing a mustache search template under an ID
+    pub fn put_search_template(
+        &self,
+        id: impl Into<String>,
+        source: impl Into<serde_json::Value>,
+    ) -> PutSearchTemplateQueryBuilder {
+        let mut builder = PutSearchTemplateQueryBuilder::default();
+        builder.client(self.clone());
+        builder.id(id);
+        builder.source(source);
+        builder
+    }
+
+    /// Create a builder getting a stored search template
+    pub fn get_search_template(&self, id: impl Into<String>) -> GetSearchTemplateQueryBuilder {
+        let mut builder = GetSearchTemplateQueryBuilder::default();
+        builder.client(self.clone());
+        builder.id(id);
+        builder
+    }
+
+    /// Create a builder deleting a stored search template
+    pub fn delete_search_template(
+        &self,
+        id: impl Into<String>,
+    ) -> DeleteSearchTemplateQueryBuilder {
+        let mut builder = DeleteSearchTemplateQueryBuilder::default();
+        builder.client(self.clone());
+        builder.id(id);
+        builder
+    }
 }
@@ -1,5 +1,54 @@
 use serde::{Deserialize, Serialize};
 
+/// A document type that knows where it is stored in OpenSearch
+///
+/// Implementing this trait lets document and bulk operations infer the target
+/// index, ID, routing and version from the document itself instead of taking
+/// them as separate arguments. It is usually derived with `#[derive(Document)]`
+/// (requires the `derive` feature):
+///
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use opensearch_api::Document;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Clone, Serialize, Deserialize, Document)]
+/// #[opensearch(index = "products")]
+/// struct Product {
+///     #[opensearch(id)]
+///     sku: String,
+///     #[opensearch(routing)]
+///     tenant: String,
+///     name: String,
+/// }
+///
+/// let product = Product {
+///     sku: "sku-1".to_string(),
+///     tenant: "acme".to_string(),
+///     name: "Widget".to_string(),
+/// };
+/// assert_eq!(Product::index_name(), "products");
+/// assert_eq!(product.id().as_deref(), Some("sku-1"));
+/// assert_eq!(product.routing().as_deref(), Some("acme"));
+/// ```
+pub trait Document: Serialize {
+    /// Name of the index documents of this type are stored in
+    fn index_name() -> &'static str;
+
+    /// Document ID, or `None` to let OpenSearch generate one
+    fn id(&self) -> Option<String>;
+
+    /// Custom routing value for this document
+    fn routing(&self) -> Option<String> {
+        None
+    }
+
+    /// External version of this document, used with `version_type=external`
+    fn version(&self) -> Option<i64> {
+        None
+    }
+}
+
 /// Document metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocumentMetadata {
@@ -25,3 +74,99 @@ pub struct DocumentMetadata {
 }
 
 // Use RefreshPolicy and VersionType from common.rs
+
+/// A document type decoded from search hits of several indices
+///
+/// Searches over patterns such as `logs-*,audit-*` return hits whose `_source`
+/// shape depends on the index they come from. An implementation picks the
+/// Rust type from the index name or the source itself, and is usually derived
+/// on an enum with `#[derive(SearchHitDecoder)]` (requires the `derive` feature).
+/// Hits are decoded with [`SearchHit::decode`](crate::types::search::SearchHit::decode)
+/// or [`SearchHits::decode`](crate::types::search::SearchHits::decode).
+///
+/// Each newtype variant is matched with comma-separated index patterns, or by
+/// default with the index name of its [`Document`] type. When the enum names a
+/// `discriminator` field, variants are matched on its string value instead.
+/// An `other` variant receives the hits no other variant matches.
+///
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use opensearch_api::{Document, SearchHitDecoder};
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Serialize, Deserialize, Document)]
+/// #[opensearch(index = "users")]
+/// struct User {
+///     #[opensearch(id)]
+///     name: String,
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// struct LogLine {
+///     message: String,
+/// }
+///
+/// #[derive(Debug, SearchHitDecoder)]
+/// enum Federated {
+///     #[opensearch(index = "logs-*,app-logs-*")]
+///     Log(LogLine),
+///     User(User),
+///     #[opensearch(other)]
+///     Unknown(serde_json::Value),
+/// }
+///
+/// let hit = Federated::decode_hit("logs-2024.06", json!({"message": "started"}))?;
+/// assert!(matches!(hit, Federated::Log(line) if line.message == "started"));
+/// let hit = Federated::decode_hit("users", json!({"name": "ada"}))?;
+/// assert!(matches!(hit, Federated::User(_)));
+/// let hit = Federated::decode_hit("metrics", json!({"cpu": 0.5}))?;
+/// assert!(matches!(hit, Federated::Unknown(_)));
+/// # Ok::<(), opensearch_api::Error>(())
+/// ```
+pub trait SearchHitDecoder: Sized {
+    /// Decode the `_source` of a hit stored in `index`
+    fn decode_hit(index: &str, source: serde_json::Value) -> crate::Result<Self>;
+}
+
+/// Whether an index name matches comma-separated index patterns
+///
+/// Patterns support `*` wildcards. A remote cluster prefix such as
+/// `cluster:logs-1` is ignored unless the pattern names a cluster too.
+///
+/// ```
+/// use opensearch_api::matches_index_pattern;
+///
+/// assert!(matches_index_pattern("logs-*,audit-*", "audit-2024"));
+/// assert!(matches_index_pattern("logs-*", "eu:logs-1"));
+/// assert!(!matches_index_pattern("logs-*", "metrics-1"));
+/// ```
+pub fn matches_index_pattern(patterns: &str, index: &str) -> bool {
+    patterns.split(',').map(str::trim).any(|pattern| {
+        let index = match index.split_once(':') {
+            Some((_cluster, local)) if !pattern.contains(':') => local,
+            _ => index,
+        };
+        wildcard_match(pattern, index)
+    })
+}
+
+fn wildcard_match(pattern: &str, value: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = value.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No wildcard, the whole value must match
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
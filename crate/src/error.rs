@@ -13,6 +13,10 @@ pub enum Error {
     #[error("YAML parsing error: {0}")]
     YamlParsing(#[from] serde_yaml::Error),
 
+    /// Error reading or writing a file
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     /// Error in URL construction or parsing
     #[error("URL error: {0}")]
     UrlParseError(#[from] url::ParseError),
@@ -65,6 +69,10 @@ pub enum Error {
     #[error("Document with id '{0}' not found in index '{1}'")]
     DocumentNotFound(String, String),
 
+    /// Document already exists
+    #[error("Document with id '{0}' already exists in index '{1}'")]
+    DocumentAlreadyExists(String, String),
+
     /// Invalid configuration
     #[error("Invalid configuration: {0}")]
     InvalidConfiguration(String),
@@ -96,6 +104,19 @@ pub enum Error {
     /// Invalid argument provided to a function
     #[error("Invalid argument: {0}")]
     InvalidArgument(String),
+
+    /// A bulk response did not contain one item per operation sent
+    #[error("Bulk response has {received} items for {expected} operations")]
+    BulkItemsMismatch {
+        /// Number of operations sent
+        expected: usize,
+        /// Number of items in the response
+        received: usize,
+    },
+
+    /// The bulk indexer was closed and no longer accepts operations
+    #[error("Bulk indexer is closed")]
+    BulkIndexerClosed,
 }
 
 /// Result type for OpenSearch API operations
@@ -47,4 +47,14 @@ pub(crate) mod macros;
 pub use client::namespaces::*;
 #[cfg(feature = "client")]
 pub use client::*;
+pub use document::{matches_index_pattern, Document, SearchHitDecoder};
 pub use error::{Error, Result};
+#[cfg(feature = "derive")]
+pub use opensearch_api_derive::{Document, SearchHitDecoder};
+
+/// Dependencies referred to by the code generated by the derive macros
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+    pub use serde_json;
+}
@@ -1,7 +1,27 @@
+//! Bulk request and response types
+//!
+//! [`BulkOperation`] is the typed model of one bulk action, shared by
+//! [`Client::bulk`](crate::Client::bulk), `documents().bulk()`, the bulk indexer
+//! and the NDJSON import and export. Every entry point returns a [`BulkResponse`].
+
+use crate::error::Error;
+use crate::types::common::{RefreshPolicy, VersionType};
+use crate::types::script::Script;
+use crate::types::search::SourceFilter;
+use derive_builder::Builder;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::HashMap;
+use serde_json::{json, Value};
+#[cfg(feature = "id-strategy")]
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::io::BufRead;
+#[cfg(feature = "id-strategy")]
+use std::sync::Arc;
 use thiserror::Error;
+#[cfg(feature = "id-strategy")]
+use uuid::Uuid;
 
 /// Represents possible errors that can occur during bulk operations
 #[derive(Debug, Error)]
@@ -13,37 +33,604 @@ pub enum BulkError {
     OperationFailed(String),
 }
 
-/// Represents the types of operations that can be performed in a bulk request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Represents the types of actions that can be performed in a bulk request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
-pub enum BulkOperation {
+pub enum BulkAction {
     Index,
     Create,
     Update,
     Delete,
 }
 
-/// Update operation specific fields for bulk requests
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BulkUpdateOperation<T>
+impl Display for BulkAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BulkAction::Index => write!(f, "index"),
+            BulkAction::Create => write!(f, "create"),
+            BulkAction::Update => write!(f, "update"),
+            BulkAction::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+/// Bulk operation parameters
+#[derive(Debug, Clone, Default, Serialize, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct BulkParams {
+    /// Refresh policy after the operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub refresh: Option<RefreshPolicy>,
+
+    /// Index to use for items that don't provide one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub index: Option<String>,
+
+    /// Routing value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub routing: Option<String>,
+
+    /// Timeout for the operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub timeout: Option<String>,
+
+    /// Wait for active shards before proceeding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub wait_for_active_shards: Option<String>,
+
+    /// Pipeline to use for processing the documents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub pipeline: Option<String>,
+
+    /// Whether operations must target an alias
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub require_alias: Option<bool>,
+}
+
+impl BulkParams {
+    /// Create a new builder for BulkParams
+    pub fn builder() -> BulkParamsBuilder {
+        BulkParamsBuilder::default()
+    }
+}
+
+/// Represents a bulk operation
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum BulkOperation<T, U = T>
 where
-    T: Serialize + Clone,
+    T: Serialize,
+    U: Serialize,
 {
-    /// The partial document to be merged with the existing document
+    /// Index a document (create if not exists, update if exists)
+    Index {
+        #[serde(rename = "index")]
+        metadata: BulkIndexMetadata,
+        #[serde(skip)]
+        document: T,
+    },
+
+    /// Create a document (fails if already exists)
+    Create {
+        #[serde(rename = "create")]
+        metadata: BulkIndexMetadata,
+        #[serde(skip)]
+        document: T,
+    },
+
+    /// Update an existing document
+    Update {
+        #[serde(rename = "update")]
+        metadata: BulkUpdateMetadata,
+        #[serde(skip)]
+        document: Box<BulkUpdateDocument<T, U>>,
+    },
+
+    /// Delete a document
+    Delete {
+        #[serde(rename = "delete")]
+        metadata: BulkDeleteMetadata,
+    },
+}
+
+/// Metadata for bulk index and create operations
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct BulkIndexMetadata {
+    /// Target index
+    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub index: Option<String>,
+
+    /// Document ID
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub id: Option<String>,
+
+    /// Custom routing value
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub doc: Option<T>,
+    #[builder(default)]
+    pub routing: Option<String>,
 
-    /// Script to execute for the update
+    /// Document version
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub script: Option<String>,
+    #[builder(default)]
+    pub version: Option<i64>,
 
-    /// Whether to upsert the document if it doesn't exist
+    /// Type of versioning to use
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub version_type: Option<VersionType>,
+}
+
+impl BulkIndexMetadata {
+    /// Create a new builder for BulkIndexMetadata
+    pub fn builder() -> BulkIndexMetadataBuilder {
+        BulkIndexMetadataBuilder::default()
+    }
+}
+
+/// Metadata for bulk update operations
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct BulkUpdateMetadata {
+    /// Target index
+    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub index: Option<String>,
+
+    /// Document ID
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Custom routing value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub routing: Option<String>,
+
+    /// Number of times to retry the update when a version conflict occurs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub retry_on_conflict: Option<i32>,
+
+    /// Whether the target must be an index alias
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub require_alias: Option<bool>,
+}
+
+impl BulkUpdateMetadata {
+    /// Create a new builder for BulkUpdateMetadata
+    pub fn builder() -> BulkUpdateMetadataBuilder {
+        BulkUpdateMetadataBuilder::default()
+    }
+}
+
+/// Metadata for bulk delete operations
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct BulkDeleteMetadata {
+    /// Target index
+    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub index: Option<String>,
+
+    /// Document ID
+    #[serde(rename = "_id")]
+    pub id: String,
+}
+
+impl BulkDeleteMetadata {
+    /// Create a new builder for BulkDeleteMetadata
+    pub fn builder() -> BulkDeleteMetadataBuilder {
+        BulkDeleteMetadataBuilder::default()
+    }
+}
+
+/// Update document wrapper for bulk update operations
+///
+/// The partial document is typed by `T` and the `upsert` document by `U`, so
+/// that a full document of another type can be inserted when the target does
+/// not exist yet. Both are serialized when the NDJSON body is built.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct BulkUpdateDocument<T, U = T>
+where
+    T: Serialize,
+    U: Serialize,
+{
+    /// Document data to update
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub doc: Option<T>,
+
+    /// Whether this should be an upsert
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub doc_as_upsert: Option<bool>,
 
-    /// Document to insert if the document doesn't exist
+    /// Script to execute
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub upsert: Option<T>,
+    #[builder(default)]
+    pub script: Option<Script>,
+
+    /// Document to insert when the target document does not exist
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub upsert: Option<U>,
+
+    /// Whether the script runs whether or not the document exists
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub scripted_upsert: Option<bool>,
+
+    /// Whether an update that changes nothing is reported as a noop
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub detect_noop: Option<bool>,
+
+    /// Source of the updated document to return in the response
+    #[serde(rename = "_source", skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub source: Option<SourceFilter>,
+}
+
+impl<T: Serialize, U: Serialize> Default for BulkUpdateDocument<T, U> {
+    fn default() -> Self {
+        Self {
+            doc: None,
+            doc_as_upsert: None,
+            script: None,
+            upsert: None,
+            scripted_upsert: None,
+            detect_noop: None,
+            source: None,
+        }
+    }
+}
+
+impl<T: Serialize, U: Serialize> BulkUpdateDocument<T, U> {
+    /// Create a new builder for BulkUpdateDocument
+    pub fn builder(doc: T) -> BulkUpdateDocumentBuilder<T, U> {
+        BulkUpdateDocumentBuilder::default().doc(doc)
+    }
+
+    /// Create a new builder for script-based updates
+    pub fn script_builder(script: Script) -> BulkUpdateDocumentBuilder<T, U> {
+        BulkUpdateDocumentBuilder::default().script(script)
+    }
+}
+
+/// Strategy generating the IDs of documents indexed without one
+///
+/// When OpenSearch assigns the ID, sending an operation twice stores the
+/// document twice. An ID generated on the client before the first attempt
+/// makes retried and replayed operations overwrite the same document instead.
+///
+/// Requires the `id-strategy` feature, enabled by `client`.
+#[cfg(feature = "id-strategy")]
+pub enum IdStrategy<T> {
+    /// Random UUID (version 4)
+    UuidV4,
+
+    /// Time-ordered UUID (version 7), keeping documents indexed together close in the index
+    UuidV7,
+
+    /// Hex-encoded SHA-256 digest of the serialized document, so identical documents share an ID
+    ContentHash,
+
+    /// ID computed by a user function from the document
+    Custom(Arc<dyn Fn(&T) -> String + Send + Sync>),
+}
+
+#[cfg(feature = "id-strategy")]
+impl<T: Serialize> IdStrategy<T> {
+    /// Create a strategy computing the ID with a user function
+    pub fn custom(id: impl Fn(&T) -> String + Send + Sync + 'static) -> Self {
+        IdStrategy::Custom(Arc::new(id))
+    }
+
+    /// Generate the ID of a document
+    pub fn generate(&self, document: &T) -> Result<String, Error> {
+        Ok(match self {
+            IdStrategy::UuidV4 => Uuid::new_v4().to_string(),
+            IdStrategy::UuidV7 => Uuid::now_v7().to_string(),
+            IdStrategy::ContentHash => {
+                let digest = Sha256::digest(serde_json::to_vec(document)?);
+                digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+            }
+            IdStrategy::Custom(id) => id(document),
+        })
+    }
+}
+
+#[cfg(feature = "id-strategy")]
+impl<T> Clone for IdStrategy<T> {
+    fn clone(&self) -> Self {
+        match self {
+            IdStrategy::UuidV4 => IdStrategy::UuidV4,
+            IdStrategy::UuidV7 => IdStrategy::UuidV7,
+            IdStrategy::ContentHash => IdStrategy::ContentHash,
+            IdStrategy::Custom(id) => IdStrategy::Custom(id.clone()),
+        }
+    }
+}
+
+#[cfg(feature = "id-strategy")]
+impl<T> std::fmt::Debug for IdStrategy<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdStrategy::UuidV4 => f.write_str("UuidV4"),
+            IdStrategy::UuidV7 => f.write_str("UuidV7"),
+            IdStrategy::ContentHash => f.write_str("ContentHash"),
+            IdStrategy::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+impl<T, U> BulkOperation<T, U>
+where
+    T: Serialize,
+    U: Serialize,
+{
+    /// The action of this operation
+    pub fn action(&self) -> BulkAction {
+        match self {
+            BulkOperation::Index { .. } => BulkAction::Index,
+            BulkOperation::Create { .. } => BulkAction::Create,
+            BulkOperation::Update { .. } => BulkAction::Update,
+            BulkOperation::Delete { .. } => BulkAction::Delete,
+        }
+    }
+
+    /// Append the NDJSON lines of this operation to a bulk payload
+    pub(crate) fn write_ndjson(&self, payload: &mut String) -> Result<(), Error> {
+        match self {
+            BulkOperation::Index { metadata, document } => {
+                // Add action line
+                payload.push_str(&serde_json::to_string(&json!({ "index": metadata }))?);
+                payload.push('\n');
+                // Add document data
+                payload.push_str(&serde_json::to_string(document)?);
+                payload.push('\n');
+            }
+            BulkOperation::Create { metadata, document } => {
+                // Add action line
+                payload.push_str(&serde_json::to_string(&json!({ "create": metadata }))?);
+                payload.push('\n');
+                // Add document data
+                payload.push_str(&serde_json::to_string(document)?);
+                payload.push('\n');
+            }
+            BulkOperation::Update { metadata, document } => {
+                // Add action line
+                payload.push_str(&serde_json::to_string(&json!({ "update": metadata }))?);
+                payload.push('\n');
+                // Add document data
+                payload.push_str(&serde_json::to_string(document)?);
+                payload.push('\n');
+            }
+            BulkOperation::Delete { metadata } => {
+                // Add action line (no document for delete)
+                payload.push_str(&serde_json::to_string(&json!({ "delete": metadata }))?);
+                payload.push('\n');
+            }
+        }
+        Ok(())
+    }
+
+    /// Create an update operation that inserts `upsert` when the document does not exist
+    pub fn upsert(index: Option<String>, id: String, document: T, upsert: U) -> Self {
+        BulkOperation::Update {
+            metadata: BulkUpdateMetadata {
+                index,
+                id,
+                ..Default::default()
+            },
+            document: Box::new(BulkUpdateDocument {
+                doc: Some(document),
+                upsert: Some(upsert),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Generate the ID of an index or create operation that has none
+    ///
+    /// Operations that already have an ID, updates and deletes are left as they are.
+    #[cfg(feature = "id-strategy")]
+    pub fn assign_id(&mut self, strategy: &IdStrategy<T>) -> Result<(), Error> {
+        match self {
+            BulkOperation::Index { metadata, document }
+            | BulkOperation::Create { metadata, document }
+                if metadata.id.is_none() =>
+            {
+                metadata.id = Some(strategy.generate(document)?);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl<T> BulkOperation<T>
+where
+    T: Serialize,
+{
+    /// Create an index operation
+    pub fn index(index: Option<String>, id: Option<String>, document: T) -> Self {
+        BulkOperation::Index {
+            metadata: BulkIndexMetadata {
+                index,
+                id,
+                ..Default::default()
+            },
+            document,
+        }
+    }
+
+    /// Create a create operation
+    pub fn create(index: Option<String>, id: Option<String>, document: T) -> Self {
+        BulkOperation::Create {
+            metadata: BulkIndexMetadata {
+                index,
+                id,
+                ..Default::default()
+            },
+            document,
+        }
+    }
+
+    /// Create an update operation
+    pub fn update(index: Option<String>, id: String, document: T) -> Self {
+        BulkOperation::Update {
+            metadata: BulkUpdateMetadata {
+                index,
+                id,
+                ..Default::default()
+            },
+            document: Box::new(BulkUpdateDocument {
+                doc: Some(document),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Create a script-based update operation
+    pub fn update_with_script(index: Option<String>, id: String, script: Script) -> Self {
+        BulkOperation::Update {
+            metadata: BulkUpdateMetadata {
+                index,
+                id,
+                ..Default::default()
+            },
+            document: Box::new(BulkUpdateDocument {
+                script: Some(script),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Create a delete operation
+    pub fn delete(index: Option<String>, id: String) -> Self {
+        BulkOperation::Delete {
+            metadata: BulkDeleteMetadata { index, id },
+        }
+    }
+}
+
+impl<T, U> BulkOperation<T, U>
+where
+    T: Serialize + DeserializeOwned,
+    U: Serialize + DeserializeOwned,
+{
+    /// Parse the operations of an NDJSON bulk payload
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use opensearch_api::bulk::BulkOperation;
+    /// use opensearch_api::{Client, ClientConfig, Error};
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    ///
+    /// async fn example() -> Result<(), Error> {
+    /// # let config = ClientConfig::builder().base_url("http://localhost:9200").build()?;
+    /// # let client = Client::builder().config(config).build()?;
+    ///     let file = BufReader::new(File::open("fixture.ndjson")?);
+    ///     let operations = BulkOperation::<serde_json::Value>::read_ndjson(file)?;
+    ///     client.bulk().operations(operations).send().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn read_ndjson<R: BufRead>(reader: R) -> Result<Vec<Self>, Error> {
+        let mut lines = reader
+            .lines()
+            .enumerate()
+            .map(|(number, line)| line.map(|line| (number + 1, line)))
+            .filter(|line| !matches!(line, Ok((_, line)) if line.trim().is_empty()));
+
+        let mut operations = Vec::new();
+        while let Some(line) = lines.next() {
+            let (number, line) = line?;
+            let action: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&line)
+                .map_err(|err| {
+                    Error::validation(format!("invalid bulk action on line {}: {}", number, err))
+                })?;
+            let (kind, metadata) = match action.into_iter().next() {
+                Some(entry) => entry,
+                None => {
+                    return Err(Error::validation(format!(
+                        "empty bulk action on line {}",
+                        number
+                    )))
+                }
+            };
+            let invalid = |err: serde_json::Error| {
+                Error::validation(format!(
+                    "invalid {} metadata on line {}: {}",
+                    kind, number, err
+                ))
+            };
+
+            // Every action except delete is followed by a document line
+            let mut source = || -> Result<String, Error> {
+                match lines.next() {
+                    Some(line) => Ok(line?.1),
+                    None => Err(Error::validation(format!(
+                        "missing document for {} action on line {}",
+                        kind, number
+                    ))),
+                }
+            };
+
+            let operation = match kind.as_str() {
+                "index" => BulkOperation::Index {
+                    metadata: serde_json::from_value(metadata).map_err(invalid)?,
+                    document: parse_document(&source()?, number)?,
+                },
+                "create" => BulkOperation::Create {
+                    metadata: serde_json::from_value(metadata).map_err(invalid)?,
+                    document: parse_document(&source()?, number)?,
+                },
+                "update" => BulkOperation::Update {
+                    metadata: serde_json::from_value(metadata).map_err(invalid)?,
+                    document: Box::new(parse_document(&source()?, number)?),
+                },
+                "delete" => BulkOperation::Delete {
+                    metadata: serde_json::from_value(metadata).map_err(invalid)?,
+                },
+                other => {
+                    return Err(Error::validation(format!(
+                        "unknown bulk action '{}' on line {}",
+                        other, number
+                    )))
+                }
+            };
+            operations.push(operation);
+        }
+
+        Ok(operations)
+    }
+
+    /// Parse the operations of an NDJSON bulk payload held in a string
+    pub fn parse_ndjson(payload: &str) -> Result<Vec<Self>, Error> {
+        Self::read_ndjson(payload.as_bytes())
+    }
+}
+
+/// Parse the document line following the action on the given line
+fn parse_document<D: DeserializeOwned>(line: &str, number: usize) -> Result<D, Error> {
+    serde_json::from_str(line).map_err(|err| {
+        Error::validation(format!("invalid document after line {}: {}", number, err))
+    })
 }
 
 /// Represents the response for a single bulk operation
@@ -81,6 +668,29 @@ pub struct BulkOperationResponse {
 
     /// Error information if the operation failed
     pub error: Option<BulkResponseError>,
+
+    /// Updated document, when the update requested `_source`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub get: Option<BulkUpdateGetResult>,
+}
+
+/// Document returned by a bulk update that requested `_source`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BulkUpdateGetResult {
+    /// Whether the document was found
+    pub found: bool,
+
+    /// Sequence number of the updated document
+    #[serde(rename = "_seq_no", skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u64>,
+
+    /// Primary term of the updated document
+    #[serde(rename = "_primary_term", skip_serializing_if = "Option::is_none")]
+    pub primary_term: Option<u64>,
+
+    /// Source of the updated document
+    #[serde(rename = "_source", skip_serializing_if = "Option::is_none")]
+    pub source: Option<Value>,
 }
 
 /// Represents shard information in a response
@@ -127,15 +737,365 @@ pub struct BulkResponseItem {
     pub delete: Option<BulkOperationResponse>,
 }
 
+impl BulkOperationResponse {
+    /// Whether the operation failed
+    ///
+    /// Like the `errors` flag of the response, only items carrying an error
+    /// count as failed, so deleting a missing document (`404`, `not_found`) does not.
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Whether the operation was rejected because the cluster was overloaded
+    ///
+    /// Rejected operations (HTTP 429 or `es_rejected_execution_exception`) can
+    /// be sent again after a backoff, unlike mapping errors or version conflicts.
+    pub fn is_retryable(&self) -> bool {
+        self.status == 429
+            || self
+                .error
+                .as_ref()
+                .is_some_and(|error| error.error_type == "es_rejected_execution_exception")
+    }
+
+    /// Deserialize the updated document returned for an update with `_source`
+    pub fn source<D: DeserializeOwned>(&self) -> Result<Option<D>, Error> {
+        match self.get.as_ref().and_then(|get| get.source.as_ref()) {
+            Some(source) => Ok(Some(D::deserialize(source)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl BulkResponseItem {
+    /// Response of the operation, whichever its type
+    pub fn response(&self) -> Option<&BulkOperationResponse> {
+        self.index
+            .as_ref()
+            .or(self.create.as_ref())
+            .or(self.update.as_ref())
+            .or(self.delete.as_ref())
+    }
+
+    /// Action of the operation, whichever its type
+    pub fn action(&self) -> Option<BulkAction> {
+        if self.index.is_some() {
+            Some(BulkAction::Index)
+        } else if self.create.is_some() {
+            Some(BulkAction::Create)
+        } else if self.update.is_some() {
+            Some(BulkAction::Update)
+        } else if self.delete.is_some() {
+            Some(BulkAction::Delete)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the operation failed, or the item holds no response at all
+    pub fn is_failed(&self) -> bool {
+        self.response().is_none_or(BulkOperationResponse::is_failed)
+    }
+
+    /// Whether the operation was rejected and can be retried
+    pub fn is_retryable(&self) -> bool {
+        self.response()
+            .is_some_and(BulkOperationResponse::is_retryable)
+    }
+}
+
 /// Represents the complete response from a bulk operation
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BulkResponse {
     /// Time in milliseconds the operation took
     pub took: u64,
 
-    /// Whether the operation timed out
+    /// Whether any operation failed
     pub errors: bool,
 
     /// Array of response items, one for each operation in the request
     pub items: Vec<BulkResponseItem>,
 }
+
+impl BulkResponse {
+    /// Append the response of a following request sent for the same bulk
+    pub fn merge(&mut self, other: BulkResponse) {
+        self.took += other.took;
+        self.errors |= other.errors;
+        self.items.extend(other.items);
+    }
+
+    /// Items of the operations that failed
+    pub fn failed_items(&self) -> impl Iterator<Item = &BulkResponseItem> {
+        self.items.iter().filter(|item| item.is_failed())
+    }
+
+    /// Document IDs of the operations that succeeded
+    pub fn succeeded_ids(&self) -> impl Iterator<Item = &str> {
+        self.items
+            .iter()
+            .filter_map(BulkResponseItem::response)
+            .filter(|response| !response.is_failed())
+            .map(|response| response.id.as_str())
+    }
+
+    /// Pair every item with the operation it answers
+    ///
+    /// Items are returned in the order the operations were sent, so
+    /// `operations` must be the operations of the request, in order.
+    pub fn iter_with_ops<'a, T: Serialize, U: Serialize>(
+        &'a self,
+        operations: &'a [BulkOperation<T, U>],
+    ) -> impl Iterator<Item = (&'a BulkOperation<T, U>, &'a BulkResponseItem)> {
+        operations.iter().zip(&self.items)
+    }
+
+    /// Group the items by HTTP status, `0` for items without a response
+    pub fn by_status(&self) -> BTreeMap<u16, Vec<&BulkResponseItem>> {
+        let mut groups: BTreeMap<u16, Vec<&BulkResponseItem>> = BTreeMap::new();
+        for item in &self.items {
+            let status = item.response().map_or(0, |response| response.status);
+            groups.entry(status).or_default().push(item);
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "id-strategy")]
+    use crate::types::bulk::IdStrategy;
+    use crate::types::bulk::{
+        BulkAction, BulkOperation, BulkResponse, BulkUpdateDocument, BulkUpdateMetadata,
+    };
+    use crate::types::script::{InlineScript, Script};
+    use crate::types::search::SourceFilter;
+    use crate::Error;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    fn sample_response() -> Result<BulkResponse, Error> {
+        Ok(serde_json::from_value(json!({
+            "took": 5,
+            "errors": true,
+            "items": [
+                {"index": {"_index": "docs", "_id": "1", "status": 201, "result": "created"}},
+                {"create": {"_index": "docs", "_id": "2", "status": 409,
+                    "error": {"type": "version_conflict_engine_exception", "reason": "document already exists"}}},
+                {"update": {"_index": "docs", "_id": "3", "status": 200, "result": "updated"}},
+                {"delete": {"_index": "docs", "_id": "4", "status": 404, "result": "not_found"}}
+            ]
+        }))?)
+    }
+
+    #[test]
+    fn test_bulk_response_helpers() -> Result<(), Error> {
+        let response = sample_response()?;
+
+        let failed: Vec<_> = response
+            .failed_items()
+            .filter_map(|item| item.action())
+            .collect();
+        assert_eq!(failed, vec![BulkAction::Create]);
+        assert_eq!(
+            response.succeeded_ids().collect::<Vec<_>>(),
+            vec!["1", "3", "4"]
+        );
+
+        let by_status = response.by_status();
+        assert_eq!(
+            by_status.keys().copied().collect::<Vec<_>>(),
+            vec![200, 201, 404, 409]
+        );
+        assert_eq!(by_status[&409][0].response().unwrap().id, "2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_response_iter_with_ops() -> Result<(), Error> {
+        let response = sample_response()?;
+        let operations = vec![
+            BulkOperation::index(Some("docs".to_string()), Some("1".to_string()), json!({})),
+            BulkOperation::create(Some("docs".to_string()), Some("2".to_string()), json!({})),
+            BulkOperation::update(Some("docs".to_string()), "3".to_string(), json!({})),
+            BulkOperation::delete(Some("docs".to_string()), "4".to_string()),
+        ];
+
+        for (operation, item) in response.iter_with_ops(&operations) {
+            assert_eq!(Some(operation.action()), item.action());
+        }
+        assert_eq!(response.iter_with_ops(&operations).count(), 4);
+
+        Ok(())
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct Patch {
+        views: u32,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct Article {
+        title: String,
+        views: u32,
+    }
+
+    #[test]
+    fn test_bulk_update_with_distinct_upsert() -> Result<(), Error> {
+        let article = Article {
+            title: "first".to_string(),
+            views: 1,
+        };
+        let operation = BulkOperation::Update {
+            metadata: BulkUpdateMetadata::builder()
+                .index("docs")
+                .id("1")
+                .retry_on_conflict(3)
+                .routing("user-1")
+                .build()?,
+            document: Box::new(
+                BulkUpdateDocument::<Patch, Article>::builder(Patch { views: 2 })
+                    .upsert(article.clone())
+                    .detect_noop(false)
+                    .source(SourceFilter::Enabled(true))
+                    .build()?,
+            ),
+        };
+
+        let mut payload = String::new();
+        operation.write_ndjson(&mut payload)?;
+        let lines: Vec<serde_json::Value> = payload
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(
+            lines[0],
+            json!({"update": {"_index": "docs", "_id": "1", "routing": "user-1", "retry_on_conflict": 3}})
+        );
+        assert_eq!(
+            lines[1],
+            json!({"doc": {"views": 2}, "upsert": {"title": "first", "views": 1},
+                "detect_noop": false, "_source": true})
+        );
+
+        // The upsert document survives an NDJSON roundtrip
+        let parsed = BulkOperation::<Patch, Article>::parse_ndjson(&payload)?;
+        assert!(matches!(
+            &parsed[0],
+            BulkOperation::Update { metadata, document }
+                if metadata.retry_on_conflict == Some(3) && document.upsert == Some(article)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_scripted_upsert() -> Result<(), Error> {
+        let script = Script::Inline(InlineScript {
+            source: "ctx._source.views += 1".to_string(),
+            lang: None,
+            params: None,
+            options: None,
+        });
+        let document = BulkUpdateDocument::<Patch, serde_json::Value>::script_builder(script)
+            .scripted_upsert(true)
+            .upsert(json!({}))
+            .build()?;
+
+        assert_eq!(
+            serde_json::to_value(&document)?,
+            json!({"script": {"source": "ctx._source.views += 1"}, "upsert": {}, "scripted_upsert": true})
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_update_response_source() -> Result<(), Error> {
+        let response: BulkResponse = serde_json::from_value(json!({
+            "took": 3,
+            "errors": false,
+            "items": [
+                {"update": {"_index": "docs", "_id": "1", "status": 200, "result": "updated",
+                    "get": {"found": true, "_seq_no": 4, "_primary_term": 1,
+                        "_source": {"title": "first", "views": 2}}}},
+                {"update": {"_index": "docs", "_id": "2", "status": 200, "result": "noop"}}
+            ]
+        }))?;
+
+        let updated = response.items[0].response().unwrap();
+        assert_eq!(
+            updated.source::<Article>()?,
+            Some(Article {
+                title: "first".to_string(),
+                views: 2
+            })
+        );
+        assert_eq!(
+            response.items[1].response().unwrap().source::<Article>()?,
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "id-strategy")]
+    fn test_id_strategies() -> Result<(), Error> {
+        let article = Article {
+            title: "first".to_string(),
+            views: 1,
+        };
+
+        let hash = IdStrategy::ContentHash.generate(&article)?;
+        assert_eq!(hash, IdStrategy::ContentHash.generate(&article.clone())?);
+        assert_eq!(hash.len(), 64);
+
+        // Time-ordered IDs sort in generation order
+        let ids: Vec<String> = (0..100)
+            .map(|_| IdStrategy::UuidV7.generate(&article))
+            .collect::<Result<_, _>>()?;
+        let mut sorted = ids.clone();
+        sorted.sort();
+        assert_eq!(ids, sorted);
+
+        let custom = IdStrategy::custom(|article: &Article| article.title.clone());
+        assert_eq!(custom.generate(&article)?, "first");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "id-strategy")]
+    fn test_assign_id() -> Result<(), Error> {
+        let strategy = IdStrategy::custom(|_: &serde_json::Value| "generated".to_string());
+        let mut operations = vec![
+            BulkOperation::index(Some("docs".to_string()), None, json!({})),
+            BulkOperation::create(
+                Some("docs".to_string()),
+                Some("kept".to_string()),
+                json!({}),
+            ),
+            BulkOperation::update(Some("docs".to_string()), "3".to_string(), json!({})),
+        ];
+        for operation in &mut operations {
+            operation.assign_id(&strategy)?;
+        }
+
+        assert!(matches!(
+            &operations[0],
+            BulkOperation::Index { metadata, .. } if metadata.id.as_deref() == Some("generated")
+        ));
+        assert!(matches!(
+            &operations[1],
+            BulkOperation::Create { metadata, .. } if metadata.id.as_deref() == Some("kept")
+        ));
+        assert!(matches!(
+            &operations[2],
+            BulkOperation::Update { metadata, .. } if metadata.id == "3"
+        ));
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// HTTP method types used in API operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -90,6 +91,23 @@ impl Display for RefreshPolicy {
     }
 }
 
+impl FromStr for RefreshPolicy {
+    type Err = crate::Error;
+
+    /// Parse the value of the `refresh` query parameter
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "true" | "" => Ok(RefreshPolicy::True),
+            "false" => Ok(RefreshPolicy::False),
+            "wait_for" => Ok(RefreshPolicy::Wait),
+            other => Err(crate::Error::validation(format!(
+                "invalid refresh policy '{}', expected true, false or wait_for",
+                other
+            ))),
+        }
+    }
+}
+
 /// Version type for document operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -101,6 +119,7 @@ pub enum VersionType {
     External,
 
     /// External versioning with greater than semantics
+    #[serde(rename = "external_gte")]
     ExternalGte,
 
     /// Force version (deprecated)
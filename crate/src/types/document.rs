@@ -512,48 +512,6 @@ pub struct UpdateByQueryRetries {
     pub search: u64,
 }
 
-/// Definition of a bulk operation
-#[derive(Debug, Clone)]
-pub enum BulkOperation<T> {
-    /// Index operation (create or update a document)
-    Index {
-        /// Index name
-        index: String,
-        /// Document ID (optional, auto-generated if not provided)
-        id: Option<String>,
-        /// Document to index
-        document: T,
-    },
-
-    /// Create operation (create a document, fail if already exists)
-    Create {
-        /// Index name
-        index: String,
-        /// Document ID (optional, auto-generated if not provided)
-        id: Option<String>,
-        /// Document to create
-        document: T,
-    },
-
-    /// Update operation (update an existing document)
-    Update {
-        /// Index name
-        index: String,
-        /// Document ID (required for update)
-        id: String,
-        /// Document or partial document to update
-        document: T,
-    },
-
-    /// Delete operation (delete an existing document)
-    Delete {
-        /// Index name
-        index: String,
-        /// Document ID to delete
-        id: String,
-    },
-}
-
 /// Response for a document indexing operation
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -722,13 +680,271 @@ pub struct RefreshResponse {
     pub _shards: ShardStatistics,
 }
 
+/// Options for term vectors operations
+#[derive(Default, Debug, Clone, Builder)]
+#[builder(setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct TermVectorsOptions {
+    /// Fields to return term vectors for
+    #[builder(default)]
+    pub fields: Option<Vec<String>>,
+
+    /// Whether to include term offsets
+    #[builder(default)]
+    pub offsets: Option<bool>,
+
+    /// Whether to include term positions
+    #[builder(default)]
+    pub positions: Option<bool>,
+
+    /// Whether to include term payloads
+    #[builder(default)]
+    pub payloads: Option<bool>,
+
+    /// Whether to include total term frequency and document frequency
+    #[builder(default)]
+    pub term_statistics: Option<bool>,
+
+    /// Whether to include document count, sum of document frequencies and sum of total term frequencies
+    #[builder(default)]
+    pub field_statistics: Option<bool>,
+
+    /// Custom routing value
+    #[builder(default)]
+    pub routing: Option<String>,
+
+    /// Preference value for executing the request
+    #[builder(default)]
+    pub preference: Option<String>,
+
+    /// Whether to execute the request in realtime or search mode
+    #[builder(default)]
+    pub realtime: Option<bool>,
+}
+
+impl TermVectorsOptions {
+    /// Create a new builder for TermVectorsOptions
+    pub fn builder() -> TermVectorsOptionsBuilder {
+        TermVectorsOptionsBuilder::default()
+    }
+}
+
+/// Filter limiting the terms returned by a term vectors operation
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct TermVectorsFilter {
+    /// Maximum number of terms to return per field
+    #[builder(default)]
+    pub max_num_terms: Option<u32>,
+
+    /// Ignore terms with a lower frequency in the source document
+    #[builder(default)]
+    pub min_term_freq: Option<u32>,
+
+    /// Ignore terms with a higher frequency in the source document
+    #[builder(default)]
+    pub max_term_freq: Option<u32>,
+
+    /// Ignore terms which occur in fewer documents
+    #[builder(default)]
+    pub min_doc_freq: Option<u32>,
+
+    /// Ignore terms which occur in more documents
+    #[builder(default)]
+    pub max_doc_freq: Option<u32>,
+
+    /// Ignore shorter words
+    #[builder(default)]
+    pub min_word_length: Option<u32>,
+
+    /// Ignore longer words
+    #[builder(default)]
+    pub max_word_length: Option<u32>,
+}
+
+impl TermVectorsFilter {
+    /// Create a new builder for TermVectorsFilter
+    pub fn builder() -> TermVectorsFilterBuilder {
+        TermVectorsFilterBuilder::default()
+    }
+}
+
+/// Document to retrieve term vectors for in a multi term vectors operation
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(setter(into, strip_option), build_fn(error = "crate::Error"))]
+pub struct MTermVectorsDoc {
+    /// Index where the document is stored
+    #[serde(rename = "_index")]
+    #[builder(default)]
+    pub index: Option<String>,
+
+    /// Document ID
+    #[serde(rename = "_id")]
+    #[builder(default)]
+    pub id: Option<String>,
+
+    /// Artificial document to analyze instead of a stored one
+    #[builder(default)]
+    pub doc: Option<serde_json::Value>,
+
+    /// Fields to return term vectors for
+    #[builder(default)]
+    pub fields: Option<Vec<String>>,
+
+    /// Whether to include term offsets
+    #[builder(default)]
+    pub offsets: Option<bool>,
+
+    /// Whether to include term positions
+    #[builder(default)]
+    pub positions: Option<bool>,
+
+    /// Whether to include term payloads
+    #[builder(default)]
+    pub payloads: Option<bool>,
+
+    /// Whether to include term statistics
+    #[builder(default)]
+    pub term_statistics: Option<bool>,
+
+    /// Whether to include field statistics
+    #[builder(default)]
+    pub field_statistics: Option<bool>,
+
+    /// Filter limiting the returned terms
+    #[builder(default)]
+    pub filter: Option<TermVectorsFilter>,
+
+    /// Custom routing value
+    #[builder(default)]
+    pub routing: Option<String>,
+}
+
+impl MTermVectorsDoc {
+    /// Create a new builder for MTermVectorsDoc
+    pub fn builder() -> MTermVectorsDocBuilder {
+        MTermVectorsDocBuilder::default()
+    }
+}
+
+/// Response for a term vectors operation
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TermVectorsResponse {
+    /// Index where the document is stored
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// Document ID, absent for artificial documents
+    #[serde(rename = "_id", default)]
+    pub id: Option<String>,
+
+    /// Document version
+    #[serde(rename = "_version", default)]
+    pub version: Option<u64>,
+
+    /// Whether the document was found
+    pub found: bool,
+
+    /// Time taken in milliseconds
+    #[serde(default)]
+    pub took: Option<u64>,
+
+    /// Term vectors keyed by field name
+    #[serde(default)]
+    pub term_vectors: HashMap<String, FieldTermVectors>,
+}
+
+/// Term vectors of a single field
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldTermVectors {
+    /// Statistics about the field, present when field statistics are requested
+    #[serde(default)]
+    pub field_statistics: Option<FieldStatistics>,
+
+    /// Terms of the field keyed by term
+    #[serde(default)]
+    pub terms: HashMap<String, TermVectorsTerm>,
+}
+
+/// Statistics about a field across the shard
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldStatistics {
+    /// Sum of document frequencies of all terms in the field
+    pub sum_doc_freq: u64,
+
+    /// Number of documents containing the field
+    pub doc_count: u64,
+
+    /// Sum of total term frequencies of all terms in the field
+    pub sum_ttf: u64,
+}
+
+/// Information about a single term
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TermVectorsTerm {
+    /// Frequency of the term in the document
+    pub term_freq: u64,
+
+    /// Number of documents containing the term, present when term statistics are requested
+    #[serde(default)]
+    pub doc_freq: Option<u64>,
+
+    /// Total frequency of the term across documents, present when term statistics are requested
+    #[serde(default)]
+    pub ttf: Option<u64>,
+
+    /// Score of the term, present when a filter is used
+    #[serde(default)]
+    pub score: Option<f64>,
+
+    /// Occurrences of the term, present when positions, offsets or payloads are requested
+    #[serde(default)]
+    pub tokens: Option<Vec<TermVectorsToken>>,
+}
+
+/// Single occurrence of a term
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TermVectorsToken {
+    /// Position of the token
+    #[serde(default)]
+    pub position: Option<u32>,
+
+    /// Start character offset of the token
+    #[serde(default)]
+    pub start_offset: Option<u32>,
+
+    /// End character offset of the token
+    #[serde(default)]
+    pub end_offset: Option<u32>,
+
+    /// Base64 encoded payload of the token
+    #[serde(default)]
+    pub payload: Option<String>,
+}
+
+/// Response for a multi term vectors operation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MTermVectorsResponse {
+    /// Term vectors of each requested document
+    pub docs: Vec<TermVectorsResponse>,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::documents::{DeleteResponse, GetResponse, IndexResponse};
     use crate::types::common::ShardStatistics;
-    use crate::types::document::{DocumentMetadata, WaitForActiveShards};
+    use crate::types::document::{
+        DocumentMetadata, FieldStatistics, FieldTermVectors, TermVectorsResponse, TermVectorsTerm,
+        TermVectorsToken, WaitForActiveShards,
+    };
     use crate::Error;
     use serde_json::{json, Value};
+    use std::collections::HashMap;
 
     /// Helper function to test serialization and deserialization roundtrip
     fn test_serde_roundtrip<T>(value: &T, expected_json: &str) -> Result<(), Error>
@@ -892,4 +1108,73 @@ mod tests {
 
         test_serde_roundtrip(&response, expected_json)
     }
+
+    #[test]
+    fn test_termvectors_response() -> Result<(), Error> {
+        let response = TermVectorsResponse {
+            index: "test-index".to_string(),
+            id: Some("1".to_string()),
+            version: Some(1),
+            found: true,
+            took: Some(3),
+            term_vectors: HashMap::from([(
+                "text".to_string(),
+                FieldTermVectors {
+                    field_statistics: Some(FieldStatistics {
+                        sum_doc_freq: 4,
+                        doc_count: 2,
+                        sum_ttf: 6,
+                    }),
+                    terms: HashMap::from([(
+                        "search".to_string(),
+                        TermVectorsTerm {
+                            term_freq: 1,
+                            doc_freq: Some(2),
+                            ttf: Some(3),
+                            score: None,
+                            tokens: Some(vec![TermVectorsToken {
+                                position: Some(0),
+                                start_offset: Some(0),
+                                end_offset: Some(6),
+                                payload: None,
+                            }]),
+                        },
+                    )]),
+                },
+            )]),
+        };
+
+        let expected_json = r#"{
+                    "_index": "test-index",
+                    "_id": "1",
+                    "_version": 1,
+                    "found": true,
+                    "took": 3,
+                    "term_vectors": {
+                        "text": {
+                            "field_statistics": {
+                                "sum_doc_freq": 4,
+                                "doc_count": 2,
+                                "sum_ttf": 6
+                            },
+                            "terms": {
+                                "search": {
+                                    "term_freq": 1,
+                                    "doc_freq": 2,
+                                    "ttf": 3,
+                                    "tokens": [
+                                        {
+                                            "position": 0,
+                                            "start_offset": 0,
+                                            "end_offset": 6
+                                        }
+                                    ]
+                                }
+                            }
+                        }
+                    }
+                }"#;
+
+        test_serde_roundtrip(&response, expected_json)
+    }
 }
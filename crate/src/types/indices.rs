@@ -80,3 +80,40 @@ pub struct CreateIndexResponse {
     #[serde(rename = "index")]
     pub index_name: String,
 }
+
+/// Response from a validate query operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidateQueryResponse {
+    /// Whether the query is valid
+    pub valid: bool,
+
+    /// Information about the shards involved in the validation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _shards: Option<ShardResponse>,
+
+    /// Per-index explanations, only returned when `explain` or `rewrite` is set
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub explanations: Vec<QueryExplanation>,
+}
+
+/// Explanation of a query validation against a single index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryExplanation {
+    /// The index the query was validated against
+    pub index: String,
+
+    /// Whether the query is valid for this index
+    pub valid: bool,
+
+    /// The shard the query was validated on, only set when validating all shards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shard: Option<i32>,
+
+    /// The rewritten Lucene query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+
+    /// Reason the query is invalid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
@@ -5,6 +5,7 @@ pub mod aggregations;
 pub mod common;
 pub mod document;
 pub mod indices;
+pub mod profile;
 pub mod query;
 pub mod script;
 pub mod search;
@@ -0,0 +1,394 @@
+//! Search profiling data types
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Profile of a search, returned when `profile` was requested
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchProfile {
+    /// Profile of each shard involved in the search
+    pub shards: Vec<ShardProfile>,
+}
+
+/// Profile of the search on one shard
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShardProfile {
+    /// Shard identifier, such as `[nodeId][index][0]`
+    pub id: String,
+
+    /// Time spent receiving the shard request, in milliseconds
+    #[serde(default)]
+    pub inbound_network_time_in_millis: Option<u64>,
+
+    /// Time spent sending the shard response, in milliseconds
+    #[serde(default)]
+    pub outbound_network_time_in_millis: Option<u64>,
+
+    /// Profiles of the searches executed on the shard
+    #[serde(default)]
+    pub searches: Vec<SearchPhaseProfile>,
+
+    /// Profiles of the aggregations executed on the shard
+    #[serde(default)]
+    pub aggregations: Vec<AggregationProfile>,
+}
+
+/// Profile of one search executed on a shard
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchPhaseProfile {
+    /// Query trees, as rewritten by Lucene
+    pub query: Vec<QueryProfile>,
+
+    /// Time spent rewriting the query, in nanoseconds
+    pub rewrite_time: u64,
+
+    /// Collectors of the matching documents
+    #[serde(default)]
+    pub collector: Vec<CollectorProfile>,
+}
+
+/// Timing of a Lucene query and its sub-queries
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QueryProfile {
+    /// Lucene class name of the query, such as `TermQuery`
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// Lucene explanation of the query, such as `title:opensearch`
+    pub description: String,
+
+    /// Total time of the query including its children, in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Time and call count of each low-level Lucene method
+    #[serde(default)]
+    pub breakdown: HashMap<String, u64>,
+
+    /// Slowest slice time with concurrent segment search, in nanoseconds
+    #[serde(default)]
+    pub max_slice_time_in_nanos: Option<u64>,
+
+    /// Sub-queries
+    #[serde(default)]
+    pub children: Vec<QueryProfile>,
+}
+
+/// Timing of a collector and its children
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CollectorProfile {
+    /// Lucene class name of the collector
+    pub name: String,
+
+    /// Why the collector was used, such as `search_top_hits`
+    pub reason: String,
+
+    /// Total time of the collector including its children, in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Wrapped collectors
+    #[serde(default)]
+    pub children: Vec<CollectorProfile>,
+}
+
+/// Timing of an aggregation and its sub-aggregations
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AggregationProfile {
+    /// Class name of the aggregator, such as `GlobalOrdinalsStringTermsAggregator`
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// Name of the aggregation in the request
+    pub description: String,
+
+    /// Total time of the aggregation including its children, in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Time and call count of each aggregator method
+    #[serde(default)]
+    pub breakdown: HashMap<String, u64>,
+
+    /// Aggregator-specific debug information
+    #[serde(default)]
+    pub debug: Option<HashMap<String, serde_json::Value>>,
+
+    /// Sub-aggregations
+    #[serde(default)]
+    pub children: Vec<AggregationProfile>,
+}
+
+/// Kind of a profiled search component
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfiledKind {
+    /// Query or sub-query
+    Query,
+    /// Aggregation or sub-aggregation
+    Aggregation,
+}
+
+/// Query or aggregation of a shard profile with its timings
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfiledComponent<'a> {
+    /// Whether the component is a query or an aggregation
+    pub kind: ProfiledKind,
+
+    /// Identifier of the shard that ran the component
+    pub shard: &'a str,
+
+    /// Class name of the query or aggregator
+    pub type_: &'a str,
+
+    /// Query explanation or aggregation name
+    pub description: &'a str,
+
+    /// Depth of the component in its tree, 0 for a root
+    pub depth: usize,
+
+    /// Total time including the children, in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Time not spent in the children, in nanoseconds
+    pub self_time_in_nanos: u64,
+}
+
+impl fmt::Display for ProfiledComponent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} [{}] on {}: {:.3}ms self, {:.3}ms total",
+            match self.kind {
+                ProfiledKind::Query => "query",
+                ProfiledKind::Aggregation => "aggregation",
+            },
+            self.type_,
+            self.description,
+            self.shard,
+            self.self_time_in_nanos as f64 / 1_000_000.0,
+            self.time_in_nanos as f64 / 1_000_000.0,
+        )
+    }
+}
+
+impl SearchProfile {
+    /// Query components of all shards, slowest first by the time spent outside their children
+    pub fn slowest_queries(&self, limit: usize) -> Vec<ProfiledComponent<'_>> {
+        let mut components = Vec::new();
+        for shard in &self.shards {
+            for search in &shard.searches {
+                for query in &search.query {
+                    query.flatten(&shard.id, 0, &mut components);
+                }
+            }
+        }
+        Self::rank(components, limit)
+    }
+
+    /// Aggregations of all shards, slowest first by the time spent outside their children
+    pub fn slowest_aggregations(&self, limit: usize) -> Vec<ProfiledComponent<'_>> {
+        let mut components = Vec::new();
+        for shard in &self.shards {
+            for aggregation in &shard.aggregations {
+                aggregation.flatten(&shard.id, 0, &mut components);
+            }
+        }
+        Self::rank(components, limit)
+    }
+
+    /// Total time of the root queries of each shard, slowest shard first, in nanoseconds
+    pub fn query_time_by_shard(&self) -> Vec<(&str, u64)> {
+        let mut shards: Vec<_> = self
+            .shards
+            .iter()
+            .map(|shard| {
+                let time: u64 = shard
+                    .searches
+                    .iter()
+                    .flat_map(|search| &search.query)
+                    .map(|query| query.time_in_nanos)
+                    .sum();
+                (shard.id.as_str(), time)
+            })
+            .collect();
+        shards.sort_by_key(|shard| std::cmp::Reverse(shard.1));
+        shards
+    }
+
+    fn rank(
+        mut components: Vec<ProfiledComponent<'_>>,
+        limit: usize,
+    ) -> Vec<ProfiledComponent<'_>> {
+        components.sort_by(|a, b| {
+            b.self_time_in_nanos
+                .cmp(&a.self_time_in_nanos)
+                .then(b.time_in_nanos.cmp(&a.time_in_nanos))
+        });
+        components.truncate(limit);
+        components
+    }
+}
+
+impl QueryProfile {
+    fn flatten<'a>(&'a self, shard: &'a str, depth: usize, out: &mut Vec<ProfiledComponent<'a>>) {
+        let children_time: u64 = self.children.iter().map(|child| child.time_in_nanos).sum();
+        out.push(ProfiledComponent {
+            kind: ProfiledKind::Query,
+            shard,
+            type_: &self.type_,
+            description: &self.description,
+            depth,
+            time_in_nanos: self.time_in_nanos,
+            self_time_in_nanos: self.time_in_nanos.saturating_sub(children_time),
+        });
+        for child in &self.children {
+            child.flatten(shard, depth + 1, out);
+        }
+    }
+}
+
+impl AggregationProfile {
+    fn flatten<'a>(&'a self, shard: &'a str, depth: usize, out: &mut Vec<ProfiledComponent<'a>>) {
+        let children_time: u64 = self.children.iter().map(|child| child.time_in_nanos).sum();
+        out.push(ProfiledComponent {
+            kind: ProfiledKind::Aggregation,
+            shard,
+            type_: &self.type_,
+            description: &self.description,
+            depth,
+            time_in_nanos: self.time_in_nanos,
+            self_time_in_nanos: self.time_in_nanos.saturating_sub(children_time),
+        });
+        for child in &self.children {
+            child.flatten(shard, depth + 1, out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn profile() -> SearchProfile {
+        serde_json::from_value(json!({
+            "shards": [
+                {
+                    "id": "[node-1][products][0]",
+                    "inbound_network_time_in_millis": 0,
+                    "outbound_network_time_in_millis": 0,
+                    "searches": [{
+                        "query": [{
+                            "type": "BooleanQuery",
+                            "description": "+name:lamp #color:red",
+                            "time_in_nanos": 900,
+                            "breakdown": {"score": 100, "score_count": 4, "build_scorer": 50},
+                            "children": [
+                                {
+                                    "type": "TermQuery",
+                                    "description": "name:lamp",
+                                    "time_in_nanos": 200,
+                                    "breakdown": {"score": 80}
+                                },
+                                {
+                                    "type": "WildcardQuery",
+                                    "description": "color:re*",
+                                    "time_in_nanos": 600,
+                                    "breakdown": {"build_scorer": 500}
+                                }
+                            ]
+                        }],
+                        "rewrite_time": 1200,
+                        "collector": [{
+                            "name": "SimpleTopScoreDocCollector",
+                            "reason": "search_top_hits",
+                            "time_in_nanos": 300
+                        }]
+                    }],
+                    "aggregations": [{
+                        "type": "GlobalOrdinalsStringTermsAggregator",
+                        "description": "brands",
+                        "time_in_nanos": 5000,
+                        "breakdown": {"collect": 4000},
+                        "debug": {"total_buckets": 3},
+                        "children": [{
+                            "type": "AvgAggregator",
+                            "description": "avg_price",
+                            "time_in_nanos": 1000,
+                            "breakdown": {"collect": 900}
+                        }]
+                    }]
+                },
+                {
+                    "id": "[node-2][products][1]",
+                    "searches": [{
+                        "query": [{
+                            "type": "TermQuery",
+                            "description": "name:lamp",
+                            "time_in_nanos": 1500,
+                            "breakdown": {"score": 1000}
+                        }],
+                        "rewrite_time": 800,
+                        "collector": []
+                    }],
+                    "aggregations": []
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_profile_deserialization() {
+        let profile = profile();
+        let shard = &profile.shards[0];
+        let query = &shard.searches[0].query[0];
+        assert_eq!(query.type_, "BooleanQuery");
+        assert_eq!(query.breakdown["score_count"], 4);
+        assert_eq!(query.children[1].description, "color:re*");
+        assert_eq!(shard.searches[0].collector[0].reason, "search_top_hits");
+        assert_eq!(shard.aggregations[0].children[0].description, "avg_price");
+        assert_eq!(
+            shard.aggregations[0].debug.as_ref().unwrap()["total_buckets"],
+            3
+        );
+    }
+
+    #[test]
+    fn test_profile_analysis() {
+        let profile = profile();
+
+        let queries = profile.slowest_queries(3);
+        let ranked: Vec<_> = queries
+            .iter()
+            .map(|query| (query.shard, query.description, query.self_time_in_nanos))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![
+                ("[node-2][products][1]", "name:lamp", 1500),
+                ("[node-1][products][0]", "color:re*", 600),
+                ("[node-1][products][0]", "name:lamp", 200),
+            ]
+        );
+        assert_eq!(queries[1].depth, 1);
+
+        let aggregations = profile.slowest_aggregations(10);
+        assert_eq!(aggregations.len(), 2);
+        assert_eq!(aggregations[0].description, "brands");
+        assert_eq!(aggregations[0].self_time_in_nanos, 4000);
+        assert_eq!(
+            aggregations[0].to_string(),
+            "aggregation GlobalOrdinalsStringTermsAggregator [brands] on [node-1][products][0]: 0.004ms self, 0.005ms total"
+        );
+
+        assert_eq!(
+            profile.query_time_by_shard(),
+            vec![
+                ("[node-2][products][1]", 1500),
+                ("[node-1][products][0]", 900)
+            ]
+        );
+    }
+}
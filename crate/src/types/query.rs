@@ -1,6 +1,7 @@
 //! Query-related data types for OpenSearch
 
 use crate::types::common::GeoPoint;
+use crate::types::search::InnerHits;
 use crate::{impl_from_query_type, Error};
 use derive_builder::Builder;
 use derive_more::From;
@@ -1858,6 +1859,9 @@ pub struct NestedQueryRule {
     /// Boost factor for this query
     #[builder(default)]
     pub boost: Option<f64>,
+    /// Matching nested objects to return with each hit
+    #[builder(default)]
+    pub inner_hits: Option<InnerHits>,
 }
 
 impl NestedQueryRuleBuilder {
@@ -1914,6 +1918,9 @@ pub struct HasChildQueryParams {
     /// Boost factor for this query
     #[serde(skip_serializing_if = "Option::is_none")]
     pub boost: Option<f64>,
+    /// Matching child documents to return with each hit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner_hits: Option<InnerHits>,
 }
 
 /// Child score mode
@@ -1956,6 +1963,9 @@ pub struct HasParentQueryParams {
     /// Boost factor for this query
     #[serde(skip_serializing_if = "Option::is_none")]
     pub boost: Option<f64>,
+    /// Matching parent document to return with each hit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner_hits: Option<InnerHits>,
 }
 
 /// Parent ID query
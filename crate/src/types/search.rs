@@ -1,9 +1,12 @@
 //! Search-related data types
 
+use crate::document::SearchHitDecoder;
 use crate::types::aggregations::AggregationResponse;
 use crate::types::common::ShardStatistics;
-use crate::types::query::Query;
+use crate::types::profile::SearchProfile;
+use crate::types::query::{Fuzziness, Query};
 use derive_builder::Builder;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, KeyValueMap};
 use std::collections::HashMap;
@@ -29,23 +32,32 @@ pub struct SearchResponse<T: Default = serde_json::Value> {
     pub aggregations: Option<HashMap<String, AggregationResponse>>,
 
     /// Suggestion results (if suggestions were requested)
+    ///
+    /// Suggestions do not depend on the type of the hits: completion suggesters
+    /// may suggest documents of another type, whose sources are deserialized
+    /// with [`CompletionSuggestOption::source`].
     #[serde(default)]
     pub suggest: Option<HashMap<String, Vec<Suggestion>>>,
 
     /// Profile information (if profiling was requested)
     #[serde(default)]
-    pub profile: Option<serde_json::Value>,
+    pub profile: Option<SearchProfile>,
 
     /// Scroll ID (if scroll was requested)
     #[serde(rename = "_scroll_id", default)]
     pub scroll_id: Option<String>,
+
+    /// Point-in-time ID to use for the next page (if a point in time was searched)
+    #[serde(default)]
+    pub pit_id: Option<String>,
 }
 
 /// Information about search hits
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SearchHits<T: Default = serde_json::Value> {
-    /// Total number of matching hits
+    /// Total number of matching hits, at least 0 when `track_total_hits` is disabled
+    #[serde(default = "TotalHits::untracked")]
     pub total: TotalHits,
 
     /// Maximum score among the hits
@@ -67,6 +79,16 @@ pub struct TotalHits {
     pub relation: TotalHitsRelation,
 }
 
+impl TotalHits {
+    /// Total reported when hits are not tracked, which is only known to be at least 0
+    pub fn untracked() -> Self {
+        Self {
+            value: 0,
+            relation: TotalHitsRelation::GreaterThanOrEqual,
+        }
+    }
+}
+
 /// Relation of the reported total hits to the actual total
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -197,7 +219,23 @@ pub struct SearchHit<T = serde_json::Value> {
     #[serde(rename = "_source", default)]
     pub source: Option<T>,
 
-    /// Requested fields
+    /// Position of a nested object within its document (nested inner hits)
+    #[serde(rename = "_nested", default)]
+    pub nested: Option<NestedIdentity>,
+
+    /// Document version (if `version` was requested)
+    #[serde(rename = "_version", default)]
+    pub version: Option<i64>,
+
+    /// Sequence number (if `seq_no_primary_term` was requested)
+    #[serde(rename = "_seq_no", default)]
+    pub seq_no: Option<i64>,
+
+    /// Primary term (if `seq_no_primary_term` was requested)
+    #[serde(rename = "_primary_term", default)]
+    pub primary_term: Option<i64>,
+
+    /// Requested fields, docvalue fields, script fields and the collapse key
     #[serde(default)]
     pub fields: Option<HashMap<String, Vec<serde_json::Value>>>,
 
@@ -218,6 +256,108 @@ impl<T> SearchHit<T> {
     pub fn source_ref_required(&self) -> &T {
         self.source.as_ref().expect("source is required")
     }
+
+    /// Values of a requested field, deserialized into `V`
+    ///
+    /// Returns `None` when the hit has no value for the field.
+    pub fn field<V: DeserializeOwned>(&self, name: &str) -> crate::Result<Option<Vec<V>>> {
+        self.fields
+            .as_ref()
+            .and_then(|fields| fields.get(name))
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|value| V::deserialize(value).map_err(Into::into))
+                    .collect()
+            })
+            .transpose()
+    }
+
+    /// First value of a requested field, deserialized into `V`
+    pub fn field_value<V: DeserializeOwned>(&self, name: &str) -> crate::Result<Option<V>> {
+        match self.fields.as_ref().and_then(|fields| fields.get(name)) {
+            Some(values) => values
+                .first()
+                .map(|value| V::deserialize(value).map_err(Into::into))
+                .transpose(),
+            None => Ok(None),
+        }
+    }
+
+    /// Highlighted fragments of a field
+    ///
+    /// Returns `None` when the field has no highlighted fragments.
+    pub fn highlight(&self, field: &str) -> Option<&[String]> {
+        self.highlight
+            .as_ref()
+            .and_then(|highlight| highlight.get(field))
+            .map(Vec::as_slice)
+    }
+
+    /// Inner hits returned under `name`, with their sources deserialized into `U`
+    ///
+    /// Returns `None` when the hit has no inner hits with that name.
+    pub fn inner_hits<U>(&self, name: &str) -> crate::Result<Option<SearchHits<U>>>
+    where
+        U: DeserializeOwned + Default,
+    {
+        match self.inner_hits.as_ref().and_then(|inner| inner.get(name)) {
+            Some(result) => Ok(Some(serde_json::from_value(serde_json::to_value(
+                &result.hits,
+            )?)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl SearchHit<serde_json::Value> {
+    /// Decode the source with `D`, which picks the document type from the hit's index
+    ///
+    /// Hits without a source are kept without one.
+    pub fn decode<D: SearchHitDecoder>(self) -> crate::Result<SearchHit<D>> {
+        let source = match self.source {
+            Some(source) => Some(D::decode_hit(&self.index, source)?),
+            None => None,
+        };
+        Ok(SearchHit {
+            index: self.index,
+            id: self.id,
+            score: self.score,
+            source,
+            nested: self.nested,
+            version: self.version,
+            seq_no: self.seq_no,
+            primary_term: self.primary_term,
+            fields: self.fields,
+            highlight: self.highlight,
+            inner_hits: self.inner_hits,
+            sort: self.sort,
+        })
+    }
+}
+
+impl SearchHits<serde_json::Value> {
+    /// Decode the sources of all hits with `D`, which picks each document type from the hit's index
+    ///
+    /// Fails on the first hit that `D` cannot decode.
+    pub fn decode<D: SearchHitDecoder>(self) -> crate::Result<Vec<SearchHit<D>>> {
+        self.hits.into_iter().map(SearchHit::decode).collect()
+    }
+}
+
+/// Position of a nested object within its document
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NestedIdentity {
+    /// Path of the nested field
+    pub field: String,
+
+    /// Position of the object in the nested field
+    pub offset: u32,
+
+    /// Position within the object for multi-level nested fields
+    #[serde(rename = "_nested", default)]
+    pub nested: Option<Box<NestedIdentity>>,
 }
 
 /// Inner hits result
@@ -228,7 +368,7 @@ pub struct InnerHitsResult {
     pub hits: SearchHits,
 }
 
-/// Suggestion result
+/// Suggestion result for one token or the whole text of a suggester
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Suggestion {
@@ -245,21 +385,110 @@ pub struct Suggestion {
     pub options: Vec<SuggestionOption>,
 }
 
-/// Option within a suggestion
+/// Option within a suggestion, depending on the suggester that returned it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum SuggestionOption {
+    /// Document suggested by a completion suggester
+    Completion(CompletionSuggestOption),
+    /// Term suggested by a term suggester
+    Term(TermSuggestOption),
+    /// Phrase suggested by a phrase suggester
+    Phrase(PhraseSuggestOption),
+}
+
+impl SuggestionOption {
+    /// Suggested text
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Completion(option) => &option.text,
+            Self::Term(option) => &option.text,
+            Self::Phrase(option) => &option.text,
+        }
+    }
+
+    /// Score of the suggested text
+    pub fn score(&self) -> f64 {
+        match self {
+            Self::Completion(option) => option.score,
+            Self::Term(option) => option.score,
+            Self::Phrase(option) => option.score,
+        }
+    }
+}
+
+/// Option of a term suggester
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TermSuggestOption {
+    /// Suggested term
+    pub text: String,
+
+    /// Score of the suggested term
+    pub score: f64,
+
+    /// Document frequency of the suggested term
+    pub freq: u64,
+}
+
+/// Option of a phrase suggester
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct SuggestionOption {
-    /// Text of the suggestion option
+pub struct PhraseSuggestOption {
+    /// Suggested phrase
     pub text: String,
 
-    /// Score of the suggestion option
+    /// Score of the suggested phrase
     pub score: f64,
 
-    /// Whether this is a collated result
+    /// Suggested phrase with the corrected terms highlighted
+    #[serde(default)]
+    pub highlighted: Option<String>,
+
+    /// Whether the collate query matched any document (if `prune` is enabled)
     #[serde(default)]
     pub collate_match: Option<bool>,
 }
 
+/// Option of a completion suggester with the suggested document
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompletionSuggestOption {
+    /// Suggested completion
+    pub text: String,
+
+    /// Index of the suggested document
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// ID of the suggested document
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Weight of the suggested completion
+    #[serde(rename = "_score")]
+    pub score: f64,
+
+    /// Source of the suggested document
+    #[serde(rename = "_source")]
+    pub source: Option<serde_json::Value>,
+
+    /// Contexts of the suggested completion, by context name
+    #[serde(default)]
+    pub contexts: Option<HashMap<String, Vec<String>>>,
+}
+
+impl CompletionSuggestOption {
+    /// Source of the suggested document, deserialized into `U`
+    ///
+    /// Returns `None` when the option has no source.
+    pub fn source<U: DeserializeOwned>(&self) -> crate::Result<Option<U>> {
+        self.source
+            .as_ref()
+            .map(|source| U::deserialize(source).map_err(Into::into))
+            .transpose()
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SortTerm(#[serde_as(as = "KeyValueMap<_>")] pub Vec<SortEntry>);
@@ -401,180 +630,1316 @@ pub struct ScriptField {
     pub ignore_failure: Option<bool>,
 }
 
-/// Search-after parameters for pagination
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(transparent)]
-pub struct SearchAfter(pub Vec<serde_json::Value>);
+/// Whether and how precisely to count the hits matching a search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TrackTotalHits {
+    /// Count all hits exactly, or skip counting them
+    Enabled(bool),
 
-/// Point in time ID for search
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(transparent)]
-pub struct PointInTimeId(pub String);
+    /// Count hits exactly up to the given number
+    UpTo(u64),
+}
 
-/// Response from a scroll request
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ScrollResponse<T>
-where
-    T: Default,
-{
-    /// Scroll ID for subsequent requests
-    #[serde(rename = "_scroll_id")]
-    pub scroll_id: String,
+impl From<bool> for TrackTotalHits {
+    fn from(enabled: bool) -> Self {
+        TrackTotalHits::Enabled(enabled)
+    }
+}
 
-    /// Document hits
-    pub hits: Hits<T>,
+impl From<u64> for TrackTotalHits {
+    fn from(limit: u64) -> Self {
+        TrackTotalHits::UpTo(limit)
+    }
+}
 
-    /// Total time spent on the request in milliseconds
-    pub took: u64,
+/// Field to retrieve with `fields` or `docvalue_fields`, with an optional format
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldAndFormat {
+    /// Name or wildcard pattern of the field
+    pub field: String,
 
-    /// Whether the request timed out
-    pub timed_out: bool,
+    /// Format of the values, such as a date format or `epoch_millis`
+    pub format: Option<String>,
 
-    /// Shard information
-    #[serde(rename = "_shards")]
-    pub shards: ShardInfo,
+    /// Whether to return unmapped fields matching the pattern
+    pub include_unmapped: Option<bool>,
 }
 
-/// Hits contains the search results
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Hits<T>
-where
-    T: Default,
-{
-    /// Total hits information
-    pub total: TotalHits,
+impl FieldAndFormat {
+    /// Retrieve a field with the given format
+    pub fn with_format(field: impl Into<String>, format: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            format: Some(format.into()),
+            include_unmapped: None,
+        }
+    }
+}
 
-    /// Maximum score across all hits
-    #[serde(default)]
-    pub max_score: Option<f64>,
+impl From<&str> for FieldAndFormat {
+    fn from(field: &str) -> Self {
+        Self::from(field.to_string())
+    }
+}
 
-    /// Array of search hits
-    pub hits: Vec<SearchHit<T>>,
+impl From<String> for FieldAndFormat {
+    fn from(field: String) -> Self {
+        Self {
+            field,
+            format: None,
+            include_unmapped: None,
+        }
+    }
 }
 
-/// Information about shards involved in a search operation
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ShardInfo {
-    /// Total number of shards
-    pub total: u32,
+/// Options of the inner hits of a collapsed group or a nested, parent or child query
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    default,
+    build_fn(error = "crate::Error")
+)]
+pub struct InnerHits {
+    /// Name of the inner hits in the response
+    pub name: Option<String>,
 
-    /// Number of successful shards
-    pub successful: u32,
+    /// Offset of the first inner hit to return
+    pub from: Option<i64>,
 
-    /// Number of skipped shards
-    #[serde(default)]
-    pub skipped: Option<u32>,
+    /// Maximum number of inner hits to return
+    pub size: Option<i64>,
 
-    /// Number of failed shards
-    pub failed: u32,
+    /// Sorting of the inner hits
+    pub sort: Option<Vec<SortTerm>>,
 
-    /// Details about failures in shards, if any
-    #[serde(default)]
-    pub failures: Option<Vec<ShardFailure>>,
-}
+    /// Source filtering of the inner hits
+    #[serde(rename = "_source")]
+    pub source: Option<SourceFilter>,
 
-/// Details about a shard failure
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ShardFailure {
-    /// Shard index
-    pub shard: u32,
+    /// Highlighting of the inner hits
+    pub highlight: Option<HighlightOptions>,
 
-    /// Index name
-    pub index: String,
+    /// Whether to explain the scoring of the inner hits
+    pub explain: Option<bool>,
 
-    /// Node ID
-    pub node: String,
+    /// Script fields to compute for the inner hits
+    pub script_fields: Option<HashMap<String, ScriptField>>,
 
-    /// Reason for the failure
-    pub reason: serde_json::Value,
-}
+    /// Doc-value fields to return for the inner hits
+    pub docvalue_fields: Option<Vec<FieldAndFormat>>,
 
-/// Response from a clear_scroll request
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ClearScrollResponse {
-    /// Whether the request was successful
-    pub succeeded: bool,
+    /// Whether to include the version of the inner hits
+    pub version: Option<bool>,
 
-    /// Number of scroll contexts cleared
-    pub num_freed: u64,
-}
+    /// Whether to include the sequence number and primary term of the inner hits
+    pub seq_no_primary_term: Option<bool>,
 
-/// Item for multi-search request
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MSearchItem {
-    /// Header information (index, search_type, etc.)
-    pub header: MSearchHeader,
+    /// Collapsing of the inner hits on a field
+    pub collapse: Option<Box<Collapse>>,
+}
 
-    /// Search request body
-    pub body: serde_json::Value,
+impl InnerHits {
+    pub fn builder() -> InnerHitsBuilder {
+        InnerHitsBuilder::default()
+    }
 }
 
-/// Header for an msearch request item
+/// Collapsing of the hits to the top hit of each value of a field
 #[serde_with::skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, Builder)]
-#[builder(pattern = "mutable", setter(into, strip_option), default)]
-pub struct MSearchHeader {
-    /// Index to search in
-    pub index: Option<String>,
-
-    /// Search type
-    pub search_type: Option<SearchType>,
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Collapse {
+    /// Single-valued keyword or numeric field to collapse on
+    pub field: String,
 
-    /// Preference for shards
-    pub preference: Option<String>,
+    /// Inner hits returned with the top hit of each group
+    pub inner_hits: Option<Vec<InnerHits>>,
 
-    /// Routing value
-    pub routing: Option<String>,
+    /// Maximum number of concurrent searches for the inner hits of groups
+    pub max_concurrent_group_searches: Option<u32>,
 }
 
-impl MSearchHeader {
-    pub fn builder() -> MSearchHeaderBuilder {
-        MSearchHeaderBuilder::default()
+impl Collapse {
+    /// Collapse the hits on a field
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            inner_hits: None,
+            max_concurrent_group_searches: None,
+        }
     }
-}
 
-/// Type of search execution
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum SearchType {
-    /// Query then fetch (default)
-    QueryThenFetch,
-    /// Distributed frequency for DFS query then fetch
-    DfsQueryThenFetch,
+    /// Add inner hits to return with the top hit of each group
+    pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+        self.inner_hits
+            .get_or_insert_with(Vec::new)
+            .push(inner_hits);
+        self
+    }
+
+    /// Limit the number of concurrent searches for the inner hits of groups
+    pub fn max_concurrent_group_searches(mut self, searches: u32) -> Self {
+        self.max_concurrent_group_searches = Some(searches);
+        self
+    }
 }
 
-/// Multi-search response
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MSearchResponse<T: Default = serde_json::Value> {
-    /// Information about the overall response
-    pub took: i64,
+/// Rescoring of the top hits of each shard
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Rescore {
+    /// Number of top hits of each shard to rescore
+    pub window_size: Option<u32>,
 
-    /// Responses for each search request
-    pub responses: Vec<SearchResponse<T>>,
+    /// Query rescorer
+    pub query: RescoreQuery,
 }
 
-/// Point-in-time response
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PointInTimeResponse {
-    /// Point-in-time ID
-    pub id: String,
+impl Rescore {
+    /// Rescore the top hits with a query
+    pub fn query(rescore_query: impl Into<Query>) -> Self {
+        Self {
+            window_size: None,
+            query: RescoreQuery {
+                rescore_query: rescore_query.into(),
+                query_weight: None,
+                rescore_query_weight: None,
+                score_mode: None,
+            },
+        }
+    }
+
+    /// Set the number of top hits of each shard to rescore
+    pub fn window_size(mut self, window_size: u32) -> Self {
+        self.window_size = Some(window_size);
+        self
+    }
+
+    /// Set the weights of the original and rescore query scores
+    pub fn weights(mut self, query_weight: f64, rescore_query_weight: f64) -> Self {
+        self.query.query_weight = Some(query_weight);
+        self.query.rescore_query_weight = Some(rescore_query_weight);
+        self
+    }
+
+    /// Set how the original and rescore query scores are combined
+    pub fn score_mode(mut self, score_mode: RescoreScoreMode) -> Self {
+        self.query.score_mode = Some(score_mode);
+        self
+    }
 }
 
-/// Delete point-in-time response
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeletePointInTimeResponse {
-    /// Whether the delete was successful
-    pub succeeded: bool,
+/// Query rescorer options
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RescoreQuery {
+    /// Query scoring the top hits again
+    pub rescore_query: Query,
 
-    /// Number of search contexts that were deleted
-    pub num_freed: u64,
+    /// Weight of the original query score
+    pub query_weight: Option<f64>,
+
+    /// Weight of the rescore query score
+    pub rescore_query_weight: Option<f64>,
+
+    /// How the scores are combined
+    pub score_mode: Option<RescoreScoreMode>,
 }
 
-#[cfg(test)]
-mod tests {
-    #[cfg(test)]
-    mod search_tests {
-        use crate::types::common::ShardStatistics;
+/// How the original and rescore query scores are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RescoreScoreMode {
+    /// Sum of the scores (default)
+    Total,
+    /// Product of the scores
+    Multiply,
+    /// Average of the scores
+    Avg,
+    /// Highest of the scores
+    Max,
+    /// Lowest of the scores
+    Min,
+}
+
+/// Boost of the scores of hits from an index, serialized as `{"<index>": <boost>}`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "HashMap<String, f64>", into = "HashMap<String, f64>")]
+pub struct IndexBoost {
+    /// Index name, alias or wildcard pattern
+    pub index: String,
+
+    /// Multiplier of the scores
+    pub boost: f64,
+}
+
+impl IndexBoost {
+    /// Multiply the scores of hits from an index by `boost`
+    pub fn new(index: impl Into<String>, boost: f64) -> Self {
+        Self {
+            index: index.into(),
+            boost,
+        }
+    }
+}
+
+impl From<HashMap<String, f64>> for IndexBoost {
+    fn from(map: HashMap<String, f64>) -> Self {
+        let (index, boost) = map.into_iter().next().unwrap_or_default();
+        Self { index, boost }
+    }
+}
+
+impl From<IndexBoost> for HashMap<String, f64> {
+    fn from(boost: IndexBoost) -> Self {
+        HashMap::from([(boost.index, boost.boost)])
+    }
+}
+
+/// Suggesters of a search by name, with an optional text shared by all of them
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Suggest {
+    /// Text suggested for by the suggesters that have no text of their own
+    pub text: Option<String>,
+
+    /// Suggesters by name
+    #[serde(flatten)]
+    pub suggesters: HashMap<String, Suggester>,
+}
+
+impl Suggest {
+    /// Create an empty suggest section
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the text shared by the suggesters
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Add a suggester under a name
+    pub fn suggester(mut self, name: impl Into<String>, suggester: impl Into<Suggester>) -> Self {
+        self.suggesters.insert(name.into(), suggester.into());
+        self
+    }
+}
+
+/// Suggester with the text, prefix or regular expression to suggest for
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Suggester {
+    /// Text to suggest for
+    pub text: Option<String>,
+
+    /// Prefix to complete (completion suggester)
+    pub prefix: Option<String>,
+
+    /// Regular expression to complete (completion suggester)
+    pub regex: Option<String>,
+
+    /// Suggester type and options
+    #[serde(flatten)]
+    pub kind: SuggesterKind,
+}
+
+impl Suggester {
+    /// Suggest terms for each token of a text
+    pub fn term(text: impl Into<String>, options: TermSuggester) -> Self {
+        Self::from(options).text(text)
+    }
+
+    /// Suggest corrections of a whole phrase
+    pub fn phrase(text: impl Into<String>, options: PhraseSuggester) -> Self {
+        Self::from(options).text(text)
+    }
+
+    /// Suggest completions starting with a prefix
+    pub fn completion(prefix: impl Into<String>, options: CompletionSuggester) -> Self {
+        let mut suggester = Self::from(options);
+        suggester.prefix = Some(prefix.into());
+        suggester
+    }
+
+    /// Suggest completions matching a regular expression
+    pub fn completion_regex(regex: impl Into<String>, options: CompletionSuggester) -> Self {
+        let mut suggester = Self::from(options);
+        suggester.regex = Some(regex.into());
+        suggester
+    }
+
+    /// Set the text to suggest for
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+}
+
+impl From<SuggesterKind> for Suggester {
+    fn from(kind: SuggesterKind) -> Self {
+        Self {
+            text: None,
+            prefix: None,
+            regex: None,
+            kind,
+        }
+    }
+}
+
+impl From<TermSuggester> for Suggester {
+    fn from(options: TermSuggester) -> Self {
+        Self::from(SuggesterKind::Term(options))
+    }
+}
+
+impl From<PhraseSuggester> for Suggester {
+    fn from(options: PhraseSuggester) -> Self {
+        Self::from(SuggesterKind::Phrase(options))
+    }
+}
+
+impl From<CompletionSuggester> for Suggester {
+    fn from(options: CompletionSuggester) -> Self {
+        Self::from(SuggesterKind::Completion(options))
+    }
+}
+
+/// Type of a suggester with its options
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggesterKind {
+    /// Term suggester
+    Term(TermSuggester),
+    /// Phrase suggester
+    Phrase(PhraseSuggester),
+    /// Completion suggester
+    Completion(CompletionSuggester),
+}
+
+/// Term suggester, suggesting terms within an edit distance of each token
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+pub struct TermSuggester {
+    /// Field to take the suggested terms from
+    pub field: String,
+
+    /// Analyzer of the suggested text
+    #[builder(default)]
+    pub analyzer: Option<String>,
+
+    /// Maximum number of options per token
+    #[builder(default)]
+    pub size: Option<u32>,
+
+    /// Maximum number of options taken from each shard
+    #[builder(default)]
+    pub shard_size: Option<u32>,
+
+    /// Order of the options of each token
+    #[builder(default)]
+    pub sort: Option<SuggestSort>,
+
+    /// Which tokens to suggest for
+    #[builder(default)]
+    pub suggest_mode: Option<SuggestMode>,
+
+    /// Maximum edit distance of the suggested terms, 1 or 2
+    #[builder(default)]
+    pub max_edits: Option<u32>,
+
+    /// Number of leading characters that must match
+    #[builder(default)]
+    pub prefix_length: Option<u32>,
+
+    /// Minimum length of a suggested term
+    #[builder(default)]
+    pub min_word_length: Option<u32>,
+
+    /// Factor of `size` for the number of candidates inspected on each shard
+    #[builder(default)]
+    pub max_inspections: Option<u32>,
+
+    /// Minimum number or ratio of documents containing a suggested term
+    #[builder(default)]
+    pub min_doc_freq: Option<f64>,
+
+    /// Maximum number or ratio of documents containing a token to suggest for it
+    #[builder(default)]
+    pub max_term_freq: Option<f64>,
+
+    /// How the similarity of terms is measured
+    #[builder(default)]
+    pub string_distance: Option<StringDistance>,
+}
+
+impl TermSuggester {
+    pub fn builder() -> TermSuggesterBuilder {
+        TermSuggesterBuilder::default()
+    }
+}
+
+/// Order of the options of a term suggestion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuggestSort {
+    /// By score, then document frequency (default)
+    Score,
+    /// By document frequency, then score
+    Frequency,
+}
+
+/// Which tokens a term suggester suggests for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuggestMode {
+    /// Tokens missing from the index (default)
+    Missing,
+    /// Tokens with more frequent terms
+    Popular,
+    /// All tokens
+    Always,
+}
+
+/// Similarity measure of the suggested terms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StringDistance {
+    /// Damerau-Levenshtein distance (default)
+    Internal,
+    /// Damerau-Levenshtein algorithm
+    DamerauLevenshtein,
+    /// Levenshtein edit distance
+    Levenshtein,
+    /// Jaro-Winkler algorithm
+    JaroWinkler,
+    /// Character n-gram distance
+    Ngram,
+}
+
+/// Phrase suggester, suggesting corrections of a whole phrase with an n-gram language model
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+pub struct PhraseSuggester {
+    /// Field to take the n-grams of the language model from
+    pub field: String,
+
+    /// Maximum size of the n-grams of the field
+    #[builder(default)]
+    pub gram_size: Option<u32>,
+
+    /// Probability of a term being misspelled even if it exists in the index
+    #[builder(default)]
+    pub real_word_error_likelihood: Option<f64>,
+
+    /// Factor of the input phrase score a suggestion must exceed
+    #[builder(default)]
+    pub confidence: Option<f64>,
+
+    /// Maximum number or ratio of misspelled terms
+    #[builder(default)]
+    pub max_errors: Option<f64>,
+
+    /// Separator of the terms of the bigram field
+    #[builder(default)]
+    pub separator: Option<String>,
+
+    /// Maximum number of suggested phrases
+    #[builder(default)]
+    pub size: Option<u32>,
+
+    /// Analyzer of the suggested text
+    #[builder(default)]
+    pub analyzer: Option<String>,
+
+    /// Maximum number of suggested phrases taken from each shard
+    #[builder(default)]
+    pub shard_size: Option<u32>,
+
+    /// Tags around the corrected terms of the suggested phrases
+    #[builder(default)]
+    pub highlight: Option<PhraseHighlight>,
+
+    /// Query checking each suggested phrase against the index
+    #[builder(default)]
+    pub collate: Option<PhraseCollate>,
+
+    /// Smoothing model balancing frequent and infrequent n-grams
+    #[builder(default)]
+    pub smoothing: Option<SmoothingModel>,
+
+    /// Generators of the candidate terms of each token
+    #[builder(default)]
+    pub direct_generator: Option<Vec<DirectGenerator>>,
+}
+
+impl PhraseSuggester {
+    pub fn builder() -> PhraseSuggesterBuilder {
+        PhraseSuggesterBuilder::default()
+    }
+}
+
+/// Tags around the corrected terms of suggested phrases
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PhraseHighlight {
+    /// Tag before each corrected term
+    pub pre_tag: String,
+
+    /// Tag after each corrected term
+    pub post_tag: String,
+}
+
+impl PhraseHighlight {
+    /// Surround the corrected terms with the given tags
+    pub fn new(pre_tag: impl Into<String>, post_tag: impl Into<String>) -> Self {
+        Self {
+            pre_tag: pre_tag.into(),
+            post_tag: post_tag.into(),
+        }
+    }
+}
+
+/// Query template run for each suggested phrase, available as `{{suggestion}}`
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PhraseCollate {
+    /// Mustache query template
+    pub query: CollateQuery,
+
+    /// Parameters of the query template
+    pub params: Option<HashMap<String, serde_json::Value>>,
+
+    /// Whether to keep the phrases that match no document, with `collate_match` set to false
+    pub prune: Option<bool>,
+}
+
+impl PhraseCollate {
+    /// Check the suggested phrases with a query template
+    pub fn new(source: impl Into<serde_json::Value>) -> Self {
+        Self {
+            query: CollateQuery {
+                source: source.into(),
+            },
+            params: None,
+            prune: None,
+        }
+    }
+
+    /// Add a parameter of the query template
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.params
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Keep the phrases that match no document
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.prune = Some(prune);
+        self
+    }
+}
+
+/// Query template of a phrase collate
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CollateQuery {
+    /// Template source, as a query object or a string
+    pub source: serde_json::Value,
+}
+
+/// Smoothing model of a phrase suggester
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SmoothingModel {
+    /// Back off to lower-order n-grams with a discount (default)
+    StupidBackoff {
+        /// Factor of the lower-order n-gram scores
+        discount: f64,
+    },
+    /// Additive smoothing
+    Laplace {
+        /// Constant added to the n-gram counts
+        alpha: f64,
+    },
+    /// Weighted average of the trigram, bigram and unigram scores
+    LinearInterpolation {
+        /// Weight of the trigrams
+        trigram_lambda: f64,
+        /// Weight of the bigrams
+        bigram_lambda: f64,
+        /// Weight of the unigrams
+        unigram_lambda: f64,
+    },
+}
+
+/// Generator of the candidate terms of a phrase suggester
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+pub struct DirectGenerator {
+    /// Field to take the candidate terms from
+    pub field: String,
+
+    /// Maximum number of candidates per token
+    #[builder(default)]
+    pub size: Option<u32>,
+
+    /// Which tokens to generate candidates for
+    #[builder(default)]
+    pub suggest_mode: Option<SuggestMode>,
+
+    /// Maximum edit distance of the candidates, 1 or 2
+    #[builder(default)]
+    pub max_edits: Option<u32>,
+
+    /// Number of leading characters that must match
+    #[builder(default)]
+    pub prefix_length: Option<u32>,
+
+    /// Minimum length of a candidate
+    #[builder(default)]
+    pub min_word_length: Option<u32>,
+
+    /// Factor of `size` for the number of candidates inspected on each shard
+    #[builder(default)]
+    pub max_inspections: Option<u32>,
+
+    /// Minimum number or ratio of documents containing a candidate
+    #[builder(default)]
+    pub min_doc_freq: Option<f64>,
+
+    /// Maximum number or ratio of documents containing a token to generate for it
+    #[builder(default)]
+    pub max_term_freq: Option<f64>,
+
+    /// Analyzer applied to each token before generating candidates
+    #[builder(default)]
+    pub pre_filter: Option<String>,
+
+    /// Analyzer applied to each candidate
+    #[builder(default)]
+    pub post_filter: Option<String>,
+}
+
+impl DirectGenerator {
+    pub fn builder() -> DirectGeneratorBuilder {
+        DirectGeneratorBuilder::default()
+    }
+}
+
+/// Completion suggester, suggesting documents from a `completion` field as the user types
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+pub struct CompletionSuggester {
+    /// Completion field to suggest from
+    pub field: String,
+
+    /// Maximum number of suggested documents
+    #[builder(default)]
+    pub size: Option<u32>,
+
+    /// Whether to drop options with the same text
+    #[builder(default)]
+    pub skip_duplicates: Option<bool>,
+
+    /// Fuzzy matching of the prefix
+    #[builder(default)]
+    pub fuzzy: Option<CompletionFuzzy>,
+
+    /// Options of the regular expression
+    #[builder(default)]
+    pub regex: Option<CompletionRegex>,
+
+    /// Category or geo contexts filtering and boosting the suggestions, by context name
+    #[builder(default)]
+    pub contexts: Option<HashMap<String, Vec<CompletionContext>>>,
+}
+
+impl CompletionSuggester {
+    pub fn builder() -> CompletionSuggesterBuilder {
+        CompletionSuggesterBuilder::default()
+    }
+}
+
+impl CompletionSuggesterBuilder {
+    /// Add a context to filter and boost the suggestions with
+    pub fn context(&mut self, name: impl Into<String>, context: CompletionContext) -> &mut Self {
+        self.contexts
+            .get_or_insert_with(|| Some(HashMap::new()))
+            .get_or_insert_with(HashMap::new)
+            .entry(name.into())
+            .or_default()
+            .push(context);
+        self
+    }
+}
+
+/// Fuzzy matching of a completion prefix
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(
+    pattern = "mutable",
+    setter(into, strip_option),
+    default,
+    build_fn(error = "crate::Error")
+)]
+pub struct CompletionFuzzy {
+    /// Maximum edit distance
+    pub fuzziness: Option<Fuzziness>,
+
+    /// Whether a transposition counts as one edit
+    pub transpositions: Option<bool>,
+
+    /// Minimum length of the prefix before fuzzy matching applies
+    pub min_length: Option<u32>,
+
+    /// Number of leading characters that must match
+    pub prefix_length: Option<u32>,
+
+    /// Whether edits are measured in Unicode code points rather than bytes
+    pub unicode_aware: Option<bool>,
+}
+
+impl CompletionFuzzy {
+    pub fn builder() -> CompletionFuzzyBuilder {
+        CompletionFuzzyBuilder::default()
+    }
+}
+
+/// Options of a completion regular expression
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompletionRegex {
+    /// Enabled operators, such as `ALL` or `INTERSECTION|COMPLEMENT`
+    pub flags: Option<String>,
+
+    /// Maximum number of automaton states
+    pub max_determinized_states: Option<u32>,
+}
+
+/// Category or geo context of a completion suggester
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompletionContext {
+    /// Category value, or `{"lat": .., "lon": ..}` point of a geo context
+    pub context: serde_json::Value,
+
+    /// Factor of the scores of the suggestions in the context
+    pub boost: Option<f64>,
+
+    /// Whether a category value is a prefix
+    pub prefix: Option<bool>,
+
+    /// Geohash precision of a geo context
+    pub precision: Option<serde_json::Value>,
+
+    /// Geohash precisions of the neighbouring cells to include in a geo context
+    pub neighbours: Option<Vec<serde_json::Value>>,
+}
+
+impl CompletionContext {
+    /// Category context
+    pub fn category(value: impl Into<String>) -> Self {
+        Self::with_context(serde_json::Value::String(value.into()))
+    }
+
+    /// Geo context around a point
+    pub fn geo(lat: f64, lon: f64) -> Self {
+        Self::with_context(serde_json::json!({"lat": lat, "lon": lon}))
+    }
+
+    fn with_context(context: serde_json::Value) -> Self {
+        Self {
+            context,
+            boost: None,
+            prefix: None,
+            precision: None,
+            neighbours: None,
+        }
+    }
+
+    /// Boost the suggestions in the context
+    pub fn boost(mut self, boost: f64) -> Self {
+        self.boost = Some(boost);
+        self
+    }
+
+    /// Match category values starting with the context value
+    pub fn prefix(mut self, prefix: bool) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+
+    /// Set the geohash precision, as a level or a distance such as `1km`
+    pub fn precision(mut self, precision: impl Into<serde_json::Value>) -> Self {
+        self.precision = Some(precision.into());
+        self
+    }
+}
+
+/// Search-after parameters for pagination
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct SearchAfter(pub Vec<serde_json::Value>);
+
+/// Point in time ID for search
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct PointInTimeId(pub String);
+
+/// Point in time searched instead of an index
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PointInTime {
+    /// Point-in-time ID
+    pub id: String,
+
+    /// How much longer to keep the point in time alive
+    pub keep_alive: Option<String>,
+}
+
+impl PointInTime {
+    /// Create a point in time reference from its ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            keep_alive: None,
+        }
+    }
+
+    /// Extend the lifetime of the point in time by the given duration
+    pub fn keep_alive(mut self, keep_alive: impl Into<String>) -> Self {
+        self.keep_alive = Some(keep_alive.into());
+        self
+    }
+}
+
+/// Slice of a scroll or point-in-time search, to read it in parallel
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Slice {
+    /// Index of the slice, from 0 to `max - 1`
+    pub id: u32,
+
+    /// Number of slices the search is split into
+    pub max: u32,
+
+    /// Field whose values split the documents, `_id` by default
+    pub field: Option<String>,
+}
+
+impl Slice {
+    /// Create the slice `id` of a search split into `max` slices
+    pub fn new(id: u32, max: u32) -> Self {
+        Self {
+            id,
+            max,
+            field: None,
+        }
+    }
+
+    /// Split the documents on the values of a numeric doc-values field
+    pub fn field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+}
+
+/// Response from a scroll request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrollResponse<T>
+where
+    T: Default,
+{
+    /// Scroll ID for subsequent requests
+    #[serde(rename = "_scroll_id")]
+    pub scroll_id: String,
+
+    /// Document hits
+    pub hits: Hits<T>,
+
+    /// Total time spent on the request in milliseconds
+    pub took: u64,
+
+    /// Whether the request timed out
+    pub timed_out: bool,
+
+    /// Shard information
+    #[serde(rename = "_shards")]
+    pub shards: ShardInfo,
+}
+
+/// Hits contains the search results
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Hits<T>
+where
+    T: Default,
+{
+    /// Total hits information
+    pub total: TotalHits,
+
+    /// Maximum score across all hits
+    #[serde(default)]
+    pub max_score: Option<f64>,
+
+    /// Array of search hits
+    pub hits: Vec<SearchHit<T>>,
+}
+
+/// Information about shards involved in a search operation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShardInfo {
+    /// Total number of shards
+    pub total: u32,
+
+    /// Number of successful shards
+    pub successful: u32,
+
+    /// Number of skipped shards
+    #[serde(default)]
+    pub skipped: Option<u32>,
+
+    /// Number of failed shards
+    pub failed: u32,
+
+    /// Details about failures in shards, if any
+    #[serde(default)]
+    pub failures: Option<Vec<ShardFailure>>,
+}
+
+/// Details about a shard failure
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShardFailure {
+    /// Shard index
+    pub shard: u32,
+
+    /// Index name
+    pub index: String,
+
+    /// Node ID
+    pub node: String,
+
+    /// Reason for the failure
+    pub reason: serde_json::Value,
+}
+
+/// Response from a clear_scroll request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClearScrollResponse {
+    /// Whether the request was successful
+    pub succeeded: bool,
+
+    /// Number of scroll contexts cleared
+    pub num_freed: u64,
+}
+
+/// Item for multi-search request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MSearchItem {
+    /// Header information (index, search_type, etc.)
+    pub header: MSearchHeader,
+
+    /// Search request body
+    pub body: serde_json::Value,
+}
+
+/// Header for an msearch request item
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "mutable", setter(into, strip_option), default)]
+pub struct MSearchHeader {
+    /// Index to search in
+    pub index: Option<String>,
+
+    /// Search type
+    pub search_type: Option<SearchType>,
+
+    /// Preference for shards
+    pub preference: Option<String>,
+
+    /// Routing value
+    pub routing: Option<String>,
+}
+
+impl MSearchHeader {
+    pub fn builder() -> MSearchHeaderBuilder {
+        MSearchHeaderBuilder::default()
+    }
+}
+
+/// Type of search execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchType {
+    /// Query then fetch (default)
+    QueryThenFetch,
+    /// Distributed frequency for DFS query then fetch
+    DfsQueryThenFetch,
+}
+
+impl SearchType {
+    /// Name of the search type in the `search_type` parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::QueryThenFetch => "query_then_fetch",
+            SearchType::DfsQueryThenFetch => "dfs_query_then_fetch",
+        }
+    }
+}
+
+/// Multi-search response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MSearchResponse<T: Default = serde_json::Value> {
+    /// Information about the overall response
+    pub took: i64,
+
+    /// Responses for each search request
+    pub responses: Vec<SearchResponse<T>>,
+}
+
+/// Stored or inline search template with the parameters to render it with
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchTemplate {
+    /// ID of a stored template
+    pub id: Option<String>,
+
+    /// Inline mustache template, as a search body object or a string
+    pub source: Option<serde_json::Value>,
+
+    /// Values of the template parameters
+    pub params: Option<HashMap<String, serde_json::Value>>,
+
+    /// Whether to explain the scoring of the hits
+    pub explain: Option<bool>,
+
+    /// Whether to profile the execution of the search
+    pub profile: Option<bool>,
+}
+
+impl SearchTemplate {
+    /// Render the stored template with the given ID
+    pub fn stored(id: impl Into<String>) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Render an inline template
+    pub fn inline(source: impl Into<serde_json::Value>) -> Self {
+        Self {
+            source: Some(source.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the value of a template parameter
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.params
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Check that the template is either stored or inline
+    pub(crate) fn validate(&self) -> crate::Result<()> {
+        match (&self.id, &self.source) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err(crate::Error::InvalidArgument(
+                "Exactly one of template 'id' or 'source' must be specified".to_string(),
+            )),
+        }
+    }
+}
+
+/// Item for multi-search template request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MSearchTemplateItem {
+    /// Header information (index, search_type, etc.)
+    pub header: MSearchHeader,
+
+    /// Template rendered into the search request body
+    pub body: SearchTemplate,
+}
+
+/// Search template stored with the `_scripts` API
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoredSearchTemplate {
+    /// Language of the template, `mustache`
+    pub lang: String,
+
+    /// Template source, returned as a string
+    pub source: serde_json::Value,
+
+    /// Options of the template, such as its content type
+    #[serde(default)]
+    pub options: Option<HashMap<String, String>>,
+}
+
+/// Response from a put stored search template operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PutSearchTemplateResponse {
+    /// Whether the operation was acknowledged
+    pub acknowledged: bool,
+}
+
+/// Response from a get stored search template operation
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSearchTemplateResponse {
+    /// Template ID
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Whether the template exists
+    pub found: bool,
+
+    /// Stored template (if found)
+    #[serde(default)]
+    pub script: Option<StoredSearchTemplate>,
+}
+
+/// Response from a delete stored search template operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteSearchTemplateResponse {
+    /// Whether the operation was acknowledged
+    pub acknowledged: bool,
+}
+
+/// Response from a render search template operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderSearchTemplateResponse {
+    /// Search request body generated by the template
+    pub template_output: serde_json::Value,
+}
+
+/// Point-in-time response
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointInTimeResponse {
+    /// Point-in-time ID
+    pub pit_id: String,
+
+    /// Information about the shards the point in time was created on
+    #[serde(default)]
+    pub _shards: Option<ShardStatistics>,
+
+    /// Creation time of the point in time in milliseconds since the epoch
+    #[serde(default)]
+    pub creation_time: Option<i64>,
+}
+
+/// Delete point-in-time response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletePointInTimeResponse {
+    /// Outcome for each deleted point in time
+    pub pits: Vec<DeletedPointInTime>,
+}
+
+/// Outcome of deleting a single point in time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedPointInTime {
+    /// Point-in-time ID
+    pub pit_id: String,
+
+    /// Whether the delete was successful
+    pub successful: bool,
+}
+
+/// Explain response describing how a document scored against a query
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExplainResponse<T = serde_json::Value> {
+    /// Index where the document is stored
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// Document ID
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Whether the document matches the query
+    pub matched: bool,
+
+    /// Score explanation, absent when the document does not exist
+    #[serde(default)]
+    pub explanation: Option<Explanation>,
+
+    /// Document retrieved alongside the explanation when `_source` is requested
+    #[serde(default)]
+    pub get: Option<ExplainDocument<T>>,
+}
+
+/// Node of a score explanation tree
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Explanation {
+    /// Score contributed by this node
+    pub value: f64,
+
+    /// Human readable description of how the value was computed
+    pub description: String,
+
+    /// Explanations of the values this node was computed from
+    #[serde(default)]
+    pub details: Vec<Explanation>,
+}
+
+/// Document returned by the explain API
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExplainDocument<T = serde_json::Value> {
+    /// Whether the document was found
+    pub found: bool,
+
+    /// Document source data
+    #[serde(rename = "_source", default)]
+    pub source: Option<T>,
+
+    /// Requested stored fields
+    #[serde(default)]
+    pub fields: Option<HashMap<String, Vec<serde_json::Value>>>,
+}
+
+/// Count response
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CountResponse {
+    /// Number of documents matching the query
+    pub count: u64,
+
+    /// Information about the shards involved in the count
+    pub _shards: ShardStatistics,
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(test)]
+    mod search_tests {
+        use crate::types::common::ShardStatistics;
         use crate::types::search::*;
         use crate::Error;
         use serde_json::{json, Value};
@@ -633,6 +1998,10 @@ mod tests {
                             "field1": "value1",
                             "field2": 42
                         })),
+                        nested: None,
+                        version: None,
+                        seq_no: None,
+                        primary_term: None,
                         fields: None,
                         highlight: None,
                         inner_hits: None,
@@ -643,6 +2012,7 @@ mod tests {
                 suggest: None,
                 profile: None,
                 scroll_id: None,
+                pit_id: None,
             };
 
             let expected_json = r#"{
@@ -754,6 +2124,10 @@ mod tests {
                     "title": "Test Document",
                     "content": "This is a test document"
                 })),
+                nested: None,
+                version: None,
+                seq_no: None,
+                primary_term: None,
                 fields: None,
                 highlight: Some(HashMap::from([(
                     "content".to_string(),
@@ -779,5 +2153,372 @@ mod tests {
 
             test_serde_roundtrip(&hit, expected_json)
         }
+
+        #[test]
+        fn test_explain_response() -> Result<(), Error> {
+            let explain_response: ExplainResponse = ExplainResponse {
+                index: "test-index".to_string(),
+                id: "1".to_string(),
+                matched: true,
+                explanation: Some(Explanation {
+                    value: 1.6,
+                    description: "weight(title:search in 0)".to_string(),
+                    details: vec![Explanation {
+                        value: 2.2,
+                        description: "boost".to_string(),
+                        details: vec![],
+                    }],
+                }),
+                get: Some(ExplainDocument {
+                    found: true,
+                    source: Some(json!({"title": "search"})),
+                    fields: None,
+                }),
+            };
+
+            let expected_json = r#"{
+                    "_index": "test-index",
+                    "_id": "1",
+                    "matched": true,
+                    "explanation": {
+                        "value": 1.6,
+                        "description": "weight(title:search in 0)",
+                        "details": [
+                            {
+                                "value": 2.2,
+                                "description": "boost",
+                                "details": []
+                            }
+                        ]
+                    },
+                    "get": {
+                        "found": true,
+                        "_source": {"title": "search"}
+                    }
+                }"#;
+
+            test_serde_roundtrip(&explain_response, expected_json)
+        }
+
+        #[test]
+        fn test_count_response() -> Result<(), Error> {
+            let count_response = CountResponse {
+                count: 17,
+                _shards: ShardStatistics {
+                    total: 2,
+                    successful: 2,
+                    failed: 0,
+                    failures: vec![],
+                },
+            };
+
+            let expected_json = r#"{
+                    "count": 17,
+                    "_shards": {
+                        "total": 2,
+                        "successful": 2,
+                        "failed": 0,
+                        "failures": []
+                    }
+                }"#;
+
+            test_serde_roundtrip(&count_response, expected_json)
+        }
+
+        #[test]
+        fn test_search_body_types() -> Result<(), Error> {
+            test_serde_roundtrip(&TrackTotalHits::from(false), "false")?;
+            test_serde_roundtrip(&TrackTotalHits::from(10_000u64), "10000")?;
+            test_serde_roundtrip(
+                &vec![IndexBoost::new("logs-2024", 2.0)],
+                r#"[{"logs-2024": 2.0}]"#,
+            )?;
+            test_serde_roundtrip(
+                &vec![
+                    FieldAndFormat::from("title"),
+                    FieldAndFormat::with_format("created_at", "epoch_millis"),
+                ],
+                r#"[{"field": "title"}, {"field": "created_at", "format": "epoch_millis"}]"#,
+            )?;
+
+            let collapse = Collapse::new("user.id")
+                .inner_hits(
+                    InnerHits::builder()
+                        .name("latest")
+                        .size(3i64)
+                        .sort(vec![SortTerm::from((
+                            "date",
+                            SortOptions::builder().order(SortOrder::Desc).build()?,
+                        ))])
+                        .build()?,
+                )
+                .max_concurrent_group_searches(4);
+            test_serde_roundtrip(
+                &collapse,
+                r#"{
+                    "field": "user.id",
+                    "inner_hits": [{"name": "latest", "size": 3, "sort": [{"date": {"order": "desc"}}]}],
+                    "max_concurrent_group_searches": 4
+                }"#,
+            )?;
+
+            let rescore = Rescore::query(crate::types::query::Query::match_all())
+                .window_size(50)
+                .weights(0.7, 1.2)
+                .score_mode(RescoreScoreMode::Multiply);
+            test_serde_roundtrip(
+                &rescore,
+                r#"{
+                    "window_size": 50,
+                    "query": {
+                        "rescore_query": {"match_all": {}},
+                        "query_weight": 0.7,
+                        "rescore_query_weight": 1.2,
+                        "score_mode": "multiply"
+                    }
+                }"#,
+            )
+        }
+
+        #[test]
+        fn test_untracked_total_hits() -> Result<(), Error> {
+            let hits: SearchHits = serde_json::from_value(json!({"max_score": null, "hits": []}))?;
+            assert_eq!(hits.total, TotalHits::untracked());
+            assert_eq!(hits.total.relation, TotalHitsRelation::GreaterThanOrEqual);
+            Ok(())
+        }
+
+        #[test]
+        fn test_search_hit_fields() -> Result<(), Error> {
+            let hit: SearchHit = serde_json::from_value(json!({
+                "_index": "logs",
+                "_id": "1",
+                "_version": 3,
+                "_seq_no": 12,
+                "_primary_term": 1,
+                "fields": {
+                    "created_at": ["2024-01-01T00:00:00.000Z"],
+                    "rating": [4.5, 3.0]
+                }
+            }))?;
+            assert_eq!(hit.version, Some(3));
+            assert_eq!(hit.seq_no, Some(12));
+            assert_eq!(hit.primary_term, Some(1));
+
+            assert_eq!(hit.field::<f64>("rating")?, Some(vec![4.5, 3.0]));
+            assert_eq!(
+                hit.field_value::<String>("created_at")?.as_deref(),
+                Some("2024-01-01T00:00:00.000Z")
+            );
+            assert_eq!(hit.field_value::<f64>("missing")?, None);
+            assert!(hit.field::<u64>("created_at").is_err());
+            Ok(())
+        }
+
+        #[test]
+        fn test_search_hit_inner_hits_and_highlight() -> Result<(), Error> {
+            #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+            struct Variant {
+                sku: String,
+                color: String,
+            }
+
+            let hit: SearchHit = serde_json::from_value(json!({
+                "_index": "products",
+                "_id": "1",
+                "_score": 1.2,
+                "highlight": {"name": ["<em>Lamp</em> shade"]},
+                "inner_hits": {
+                    "red_variants": {
+                        "hits": {
+                            "total": {"value": 1, "relation": "eq"},
+                            "max_score": 0.9,
+                            "hits": [{
+                                "_index": "products",
+                                "_id": "1",
+                                "_nested": {"field": "variants", "offset": 2},
+                                "_score": 0.9,
+                                "_source": {"sku": "L-R", "color": "red"},
+                                "highlight": {"variants.color": ["<em>red</em>"]}
+                            }]
+                        }
+                    }
+                }
+            }))?;
+
+            assert_eq!(
+                hit.highlight("name"),
+                Some(&["<em>Lamp</em> shade".to_string()][..])
+            );
+            assert_eq!(hit.highlight("description"), None);
+
+            let variants = hit.inner_hits::<Variant>("red_variants")?.unwrap();
+            assert_eq!(variants.total.value, 1);
+            let variant = &variants.hits[0];
+            assert_eq!(
+                variant.source,
+                Some(Variant {
+                    sku: "L-R".to_string(),
+                    color: "red".to_string()
+                })
+            );
+            assert_eq!(variant.nested.as_ref().unwrap().offset, 2);
+            assert_eq!(
+                variant.highlight("variants.color").unwrap()[0],
+                "<em>red</em>"
+            );
+
+            assert!(hit.inner_hits::<Variant>("blue_variants")?.is_none());
+            assert!(hit.inner_hits::<u32>("red_variants").is_err());
+            Ok(())
+        }
+
+        #[test]
+        fn test_suggest_request() -> Result<(), Error> {
+            let suggest = Suggest::new()
+                .text("noble prize")
+                .suggester(
+                    "spelling",
+                    TermSuggester::builder()
+                        .field("title")
+                        .suggest_mode(SuggestMode::Popular)
+                        .sort(SuggestSort::Frequency)
+                        .build()?,
+                )
+                .suggester(
+                    "phrase",
+                    Suggester::phrase(
+                        "noble prize",
+                        PhraseSuggester::builder()
+                            .field("title.trigram")
+                            .highlight(PhraseHighlight::new("<em>", "</em>"))
+                            .smoothing(SmoothingModel::Laplace { alpha: 0.7 })
+                            .collate(
+                                PhraseCollate::new(
+                                    json!({"match": {"{{field}}": "{{suggestion}}"}}),
+                                )
+                                .param("field", "title")
+                                .prune(true),
+                            )
+                            .build()?,
+                    ),
+                )
+                .suggester(
+                    "autocomplete",
+                    Suggester::completion(
+                        "nir",
+                        CompletionSuggester::builder()
+                            .field("suggest")
+                            .skip_duplicates(true)
+                            .fuzzy(
+                                CompletionFuzzy::builder()
+                                    .fuzziness(crate::types::query::Fuzziness::Distance(1))
+                                    .build()?,
+                            )
+                            .context("genre", CompletionContext::category("rock").boost(2.0))
+                            .context("genre", CompletionContext::category("gr").prefix(true))
+                            .build()?,
+                    ),
+                );
+            test_serde_roundtrip(
+                &suggest,
+                r#"{
+                    "text": "noble prize",
+                    "spelling": {
+                        "term": {"field": "title", "suggest_mode": "popular", "sort": "frequency"}
+                    },
+                    "phrase": {
+                        "text": "noble prize",
+                        "phrase": {
+                            "field": "title.trigram",
+                            "highlight": {"pre_tag": "<em>", "post_tag": "</em>"},
+                            "smoothing": {"laplace": {"alpha": 0.7}},
+                            "collate": {
+                                "query": {"source": {"match": {"{{field}}": "{{suggestion}}"}}},
+                                "params": {"field": "title"},
+                                "prune": true
+                            }
+                        }
+                    },
+                    "autocomplete": {
+                        "prefix": "nir",
+                        "completion": {
+                            "field": "suggest",
+                            "skip_duplicates": true,
+                            "fuzzy": {"fuzziness": 1},
+                            "contexts": {
+                                "genre": [{"context": "rock", "boost": 2.0}, {"context": "gr", "prefix": true}]
+                            }
+                        }
+                    }
+                }"#,
+            )
+        }
+
+        #[test]
+        fn test_suggest_response() -> Result<(), Error> {
+            #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+            struct Song {
+                title: String,
+            }
+
+            let suggest: HashMap<String, Vec<Suggestion>> = serde_json::from_value(json!({
+                "spelling": [{
+                    "text": "noble", "offset": 0, "length": 5,
+                    "options": [{"text": "nobel", "score": 0.8, "freq": 4}]
+                }],
+                "phrase": [{
+                    "text": "noble prize", "offset": 0, "length": 11,
+                    "options": [{
+                        "text": "nobel prize",
+                        "highlighted": "<em>nobel</em> prize",
+                        "score": 0.4,
+                        "collate_match": true
+                    }]
+                }],
+                "autocomplete": [{
+                    "text": "nir", "offset": 0, "length": 3,
+                    "options": [{
+                        "text": "Nirvana",
+                        "_index": "music",
+                        "_id": "1",
+                        "_score": 34.0,
+                        "_source": {"title": "Nevermind"},
+                        "contexts": {"genre": ["rock"]}
+                    }]
+                }]
+            }))?;
+
+            let term = &suggest["spelling"][0].options[0];
+            assert!(matches!(term, SuggestionOption::Term(option) if option.freq == 4));
+            assert_eq!(term.text(), "nobel");
+
+            match &suggest["phrase"][0].options[0] {
+                SuggestionOption::Phrase(option) => {
+                    assert_eq!(option.highlighted.as_deref(), Some("<em>nobel</em> prize"));
+                    assert_eq!(option.collate_match, Some(true));
+                }
+                other => panic!("expected a phrase option, got {:?}", other),
+            }
+
+            let completion = &suggest["autocomplete"][0].options[0];
+            assert_eq!(completion.score(), 34.0);
+            match completion {
+                SuggestionOption::Completion(option) => {
+                    assert_eq!(option.id, "1");
+                    assert_eq!(
+                        option.source::<Song>()?,
+                        Some(Song {
+                            title: "Nevermind".to_string()
+                        })
+                    );
+                    assert_eq!(option.contexts.as_ref().unwrap()["genre"], vec!["rock"]);
+                    // A source of another type is reported rather than matched as a term option
+                    assert!(option.source::<Vec<String>>().is_err());
+                }
+                other => panic!("expected a completion option, got {:?}", other),
+            }
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,285 @@
+//! Tests for the asynchronous search namespace
+
+pub mod fixture;
+
+use crate::fixture::{stub_server_targets, OpenSearchFixture};
+use anyhow::Result;
+use opensearch_api::async_search::AsyncSearchState;
+use opensearch_api::types::common::RefreshPolicy;
+use opensearch_api::types::query::{MatchAllQuery, TermQuery};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Test document structure for asynchronous search tests
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+struct Product {
+    name: String,
+    price: u32,
+}
+
+/// Asynchronous search response in the given state with the given product names
+fn async_response(state: &str, names: &[&str]) -> Value {
+    let hits: Vec<Value> = names
+        .iter()
+        .enumerate()
+        .map(|(position, name)| {
+            json!({
+                "_index": "products",
+                "_id": position.to_string(),
+                "_score": 1.0,
+                "_source": {"name": name, "price": 10}
+            })
+        })
+        .collect();
+    json!({
+        "id": "FklfVlU4eFdIUTh1Q1hyM3ZnT19fUVEUd29KLWZYUUI",
+        "state": state,
+        "start_time_in_millis": 1729240000000u64,
+        "expiration_time_in_millis": 1729326400000u64,
+        "response": {
+            "took": 1,
+            "timed_out": false,
+            "_shards": {"total": 1, "successful": 1, "failed": 0},
+            "hits": {"total": {"value": hits.len(), "relation": "eq"}, "max_score": 1.0, "hits": hits}
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_async_search_requests() -> Result<()> {
+    let (client, requests, targets) = stub_server_targets(vec![
+        async_response("RUNNING", &["lamp"]),
+        async_response("SUCCEEDED", &["lamp", "desk"]),
+        json!({"acknowledged": true}),
+    ])
+    .await?;
+
+    let search = client
+        .search::<Product>("products")
+        .query(TermQuery::builder().field("name", "lamp").build()?)
+        .size(10u32)
+        .build()?;
+    let submitted = client
+        .async_search()
+        .submit(search)
+        .wait_for_completion_timeout("1ms")
+        .keep_on_completion(true)
+        .keep_alive("1d")
+        .build()?
+        .send()
+        .await?;
+    assert_eq!(submitted.state, AsyncSearchState::Running);
+    assert!(submitted.state.is_running());
+    let partial = submitted.response.unwrap();
+    assert_eq!(partial.hits.hits[0].source.as_ref().unwrap().name, "lamp");
+
+    let fetched = client
+        .async_search()
+        .get::<Product>(&submitted.id)
+        .keep_alive("2d")
+        .build()?
+        .send()
+        .await?;
+    assert_eq!(fetched.state, AsyncSearchState::Succeeded);
+    assert_eq!(fetched.response.unwrap().hits.hits.len(), 2);
+
+    let deleted = client
+        .async_search()
+        .delete(&submitted.id)
+        .build()?
+        .send()
+        .await?;
+    assert!(deleted.acknowledged);
+
+    assert_eq!(
+        targets.lock().unwrap().clone(),
+        vec![
+            "POST /_plugins/_asynchronous_search?index=products&size=10&wait_for_completion_timeout=1ms&keep_on_completion=true&keep_alive=1d",
+            "GET /_plugins/_asynchronous_search/FklfVlU4eFdIUTh1Q1hyM3ZnT19fUVEUd29KLWZYUUI?keep_alive=2d",
+            "DELETE /_plugins/_asynchronous_search/FklfVlU4eFdIUTh1Q1hyM3ZnT19fUVEUd29KLWZYUUI",
+        ]
+    );
+    let body: Value = serde_json::from_str(&requests.lock().unwrap()[0])?;
+    assert_eq!(body["query"], json!({"term": {"name": {"value": "lamp"}}}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_async_search_polls_until_completion() -> Result<()> {
+    let (client, _requests, targets) = stub_server_targets(vec![
+        async_response("INIT", &[]),
+        async_response("RUNNING", &["lamp"]),
+        async_response("PERSIST_SUCCEEDED", &["lamp", "desk"]),
+    ])
+    .await?;
+
+    let search = client
+        .search::<Product>("products")
+        .query(MatchAllQuery::builder().build()?)
+        .build()?;
+    let response = client
+        .async_search()
+        .submit(search)
+        .keep_on_completion(true)
+        .build()?
+        .send_and_wait(Duration::from_millis(10), Duration::from_secs(5))
+        .await?;
+    assert_eq!(response.state, AsyncSearchState::PersistSucceeded);
+    assert_eq!(response.response.unwrap().hits.hits.len(), 2);
+
+    let targets = targets.lock().unwrap().clone();
+    assert_eq!(targets.len(), 3);
+    assert!(targets[1].starts_with("GET /_plugins/_asynchronous_search/"));
+    assert_eq!(targets[1], targets[2]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_async_search_wait_times_out() -> Result<()> {
+    let mut responses = vec![async_response("RUNNING", &[]); 4];
+    responses.push(json!({"acknowledged": true}));
+    let (client, _requests, targets) = stub_server_targets(responses).await?;
+
+    // A search stuck in RUNNING fails once the timeout elapses, and is deleted
+    let search = client
+        .search::<Product>("products")
+        .query(MatchAllQuery::builder().build()?)
+        .build()?;
+    let result = client
+        .async_search()
+        .submit(search)
+        .build()?
+        .send_and_wait(Duration::from_millis(10), Duration::from_millis(25))
+        .await;
+    let err = result.unwrap_err();
+    assert!(err.to_string().contains("still running"));
+
+    let targets = targets.lock().unwrap().clone();
+    assert!(targets.len() <= 5);
+    assert_eq!(
+        targets.last().unwrap(),
+        "DELETE /_plugins/_asynchronous_search/FklfVlU4eFdIUTh1Q1hyM3ZnT19fUVEUd29KLWZYUUI"
+    );
+
+    // Waiting on an ID alone gives up without deleting the search
+    let (client, _requests, targets) =
+        stub_server_targets(vec![async_response("RUNNING", &[]); 4]).await?;
+    let result = client
+        .async_search()
+        .wait_for_completion::<Product>(
+            "FklfVlU4eFdIUTh1Q1hyM3ZnT19fUVEUd29KLWZYUUI",
+            Duration::from_millis(10),
+            Duration::from_millis(15),
+        )
+        .await;
+    assert!(result.is_err());
+    assert!(targets
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|target| target.starts_with("GET ")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_async_search_stats() -> Result<()> {
+    let counters = |submitted: u64, running: u64| {
+        json!({
+            "asynchronous_search_stats": {
+                "submitted": submitted,
+                "initialized": submitted,
+                "search_failed": 0,
+                "search_completed": submitted - running,
+                "rejected": 0,
+                "persist_failed": 0,
+                "cancelled": 0,
+                "running_current": running,
+                "persisted": 1
+            }
+        })
+    };
+    let (client, _requests, targets) = stub_server_targets(vec![json!({
+        "_nodes": {"total": 2, "successful": 2, "failed": 0},
+        "cluster_name": "docker-cluster",
+        "nodes": {"node-1": counters(4, 1), "node-2": counters(2, 0)}
+    })])
+    .await?;
+
+    let stats = client.async_search().stats().await?;
+    assert_eq!(stats.cluster_name, "docker-cluster");
+    assert_eq!(stats.nodes["node-2"].asynchronous_search_stats.submitted, 2);
+    let total = stats.total();
+    assert_eq!(total.submitted, 6);
+    assert_eq!(total.search_completed, 5);
+    assert_eq!(total.running_current, 1);
+    assert_eq!(total.persisted, 2);
+    assert_eq!(
+        targets.lock().unwrap()[0],
+        "GET /_plugins/_asynchronous_search/stats"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_async_search_against_cluster() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("async-search");
+
+    let mut bulk = fixture.client.bulk();
+    for (position, (name, price)) in [("lamp", 25), ("desk", 120), ("chair", 80)]
+        .into_iter()
+        .enumerate()
+    {
+        let product = Product {
+            name: name.to_string(),
+            price,
+        };
+        bulk = bulk.index(&index_name, Some(position.to_string()), &product);
+    }
+    bulk.refresh(RefreshPolicy::True).send().await?;
+
+    let search = fixture
+        .client
+        .search::<Product>(&index_name)
+        .query(MatchAllQuery::builder().build()?)
+        .build()?;
+    let response = fixture
+        .client
+        .async_search()
+        .submit(search)
+        .wait_for_completion_timeout("0ms")
+        .keep_on_completion(true)
+        .build()?
+        .send_and_wait(Duration::from_millis(100), Duration::from_secs(30))
+        .await?;
+    assert!(!response.state.is_running());
+    assert_eq!(response.response.as_ref().unwrap().hits.total.value, 3);
+
+    let stored = fixture
+        .client
+        .async_search()
+        .get::<Product>(&response.id)
+        .build()?
+        .send()
+        .await?;
+    assert_eq!(stored.response.unwrap().hits.hits.len(), 3);
+
+    let stats = fixture.client.async_search().stats().await?;
+    assert!(stats.total().submitted >= 1);
+
+    let deleted = fixture
+        .client
+        .async_search()
+        .delete(&response.id)
+        .build()?
+        .send()
+        .await?;
+    assert!(deleted.acknowledged);
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+//! Tests for splitting bulk requests by byte size
+
+pub mod fixture;
+
+use crate::fixture::{stub_server, OpenSearchFixture};
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use opensearch_api::bulk::{BulkOperation, BulkRetryPolicy, BulkStreamConfig};
+use opensearch_api::types::common::RefreshPolicy;
+use opensearch_api::Error;
+use serde_json::{json, Value};
+
+fn document(i: usize) -> Value {
+    json!({"body": "x".repeat(200), "position": i})
+}
+
+fn created(id: &str) -> Value {
+    json!({"index": {"_index": "docs", "_id": id, "status": 201, "result": "created"}})
+}
+
+#[tokio::test]
+async fn test_bulk_chunks_by_bytes() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        json!({"took": 1, "errors": false, "items": [created("doc-1"), created("doc-2")]}),
+        json!({"took": 2, "errors": true, "items": [
+            created("doc-3"),
+            {"index": {"_index": "docs", "_id": "doc-4", "status": 400,
+                "error": {"type": "mapper_parsing_exception", "reason": "failed to parse"}}}
+        ]}),
+        json!({"took": 3, "errors": false, "items": [created("doc-5")]}),
+    ])
+    .await?;
+
+    // Every operation has the same size, so two of them fit in a request
+    let entry_size = client
+        .bulk()
+        .index("docs", Some("doc-1"), &document(1))
+        .to_ndjson()?
+        .len();
+    let mut bulk = client.bulk().max_request_bytes(entry_size * 2 + 1);
+    for i in 1..=5 {
+        bulk = bulk.index("docs", Some(format!("doc-{}", i)), &document(i));
+    }
+    let response = bulk.send().await?;
+
+    let requests = requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 3);
+    assert!(requests
+        .iter()
+        .all(|request| request.len() <= entry_size * 2 + 1));
+    assert_eq!(requests[2].lines().count(), 2);
+
+    // The responses are merged in the order of the operations
+    assert_eq!(response.took, 6);
+    assert!(response.errors);
+    assert_eq!(
+        response.succeeded_ids().collect::<Vec<_>>(),
+        vec!["doc-1", "doc-2", "doc-3", "doc-5"]
+    );
+    assert_eq!(response.failed_items().count(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_stream_chunks_by_bytes() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        json!({"took": 1, "errors": false, "items": [created("doc-1"), created("doc-2")]}),
+        json!({"took": 1, "errors": false, "items": [created("doc-3")]}),
+    ])
+    .await?;
+
+    let entry_size = client
+        .bulk()
+        .index("docs", Some("doc-1"), &document(1))
+        .to_ndjson()?
+        .len();
+    let operations = stream::iter(1..=3).map(|i| {
+        BulkOperation::index(
+            Some("docs".to_string()),
+            Some(format!("doc-{}", i)),
+            document(i),
+        )
+    });
+
+    // The chunk of three operations is sent in two requests
+    let results: Vec<_> = client
+        .bulk()
+        .max_request_bytes(entry_size * 2)
+        .from_stream(operations, BulkStreamConfig::default())
+        .collect()
+        .await;
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].as_ref().unwrap().items.len(), 2);
+    assert_eq!(results[1].as_ref().unwrap().items.len(), 1);
+
+    let requests = requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 2);
+    assert!(requests
+        .iter()
+        .all(|request| request.len() <= entry_size * 2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_retry_chunks_by_bytes() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        json!({"took": 1, "errors": false, "items": [created("doc-1")]}),
+        json!({"took": 1, "errors": false, "items": [created("doc-2")]}),
+    ])
+    .await?;
+
+    let entry_size = client
+        .bulk()
+        .index("docs", Some("doc-1"), &document(1))
+        .to_ndjson()?
+        .len();
+    let response = client
+        .bulk()
+        .max_request_bytes(entry_size)
+        .index("docs", Some("doc-1"), &document(1))
+        .index("docs", Some("doc-2"), &document(2))
+        .send_with_retry(BulkRetryPolicy::default())
+        .await?;
+
+    assert_eq!(requests.lock().unwrap().len(), 2);
+    assert_eq!(response.took, 2);
+    assert_eq!(response.items.len(), 2);
+    assert_eq!(response.items[1].response().unwrap().id, "doc-2");
+    assert!(!response.has_failures());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_operation_over_limit() -> Result<()> {
+    let (client, requests) = stub_server(Vec::new()).await?;
+
+    // Nothing is sent when an operation can never fit in a request
+    let result = client
+        .bulk()
+        .max_request_bytes(100)
+        .index("docs", Some("small"), &json!({}))
+        .index("docs", Some("large"), &document(1))
+        .send()
+        .await;
+    match result {
+        Err(Error::Validation(message)) => {
+            assert!(message.starts_with("bulk operation 1 is"), "{}", message)
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+    assert!(requests.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_chunks_against_cluster() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("bulk-chunks");
+
+    let mut bulk = fixture.client.bulk().max_request_bytes(4096);
+    for i in 0..100 {
+        bulk = bulk.index(&index_name, Some(format!("doc-{}", i)), &document(i));
+    }
+    let response = bulk.refresh(RefreshPolicy::True).send().await?;
+
+    assert!(!response.errors);
+    assert_eq!(response.items.len(), 100);
+    assert_eq!(response.items[99].response().unwrap().id, "doc-99");
+
+    let count = fixture.client.count(&index_name).build()?.send().await?;
+    assert_eq!(count.count, 100);
+
+    Ok(())
+}
@@ -0,0 +1,199 @@
+//! Tests for client-side ID generation of bulk operations
+
+pub mod fixture;
+
+use crate::fixture::{stub_server, OpenSearchFixture, TestDocument};
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use opensearch_api::bulk::{BulkRetryPolicy, BulkStreamConfig, IdStrategy};
+use opensearch_api::types::common::RefreshPolicy;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// IDs of the action lines of a bulk request body
+fn request_ids(body: &str) -> Result<Vec<String>> {
+    let mut ids = Vec::new();
+    for (number, line) in body.lines().enumerate() {
+        if number % 2 == 0 {
+            let action: Value = serde_json::from_str(line)?;
+            ids.push(
+                action["index"]["_id"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+        }
+    }
+    Ok(ids)
+}
+
+fn item(id: &str, status: u16) -> Value {
+    let mut response = json!({"_index": "products", "_id": id, "status": status});
+    if status == 429 {
+        response["error"] =
+            json!({"type": "es_rejected_execution_exception", "reason": "rejected execution"});
+    } else {
+        response["result"] = json!("created");
+    }
+    json!({ "index": response })
+}
+
+#[tokio::test]
+async fn test_bulk_retry_keeps_generated_ids() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        json!({"took": 1, "errors": true, "items": [item("a", 201), item("b", 429)]}),
+        json!({"took": 1, "errors": false, "items": [item("b", 201)]}),
+    ])
+    .await?;
+
+    let response = client
+        .bulk()
+        .id_strategy(IdStrategy::UuidV4)
+        .index("products", None::<String>, &TestDocument::new_sample(1))
+        .index("products", Some("explicit"), &TestDocument::new_sample(2))
+        .send_with_retry(
+            BulkRetryPolicy::builder()
+                .initial_backoff(Duration::from_millis(1))
+                .build()?,
+        )
+        .await?;
+    assert!(!response.has_failures());
+
+    // The rejected operation is sent again with the ID of its first attempt
+    let requests = requests.lock().unwrap().clone();
+    let first = request_ids(&requests[0])?;
+    let second = request_ids(&requests[1])?;
+    assert_eq!(first.len(), 2);
+    assert!(uuid::Uuid::parse_str(&first[0]).is_ok());
+    assert_eq!(first[1], "explicit");
+    assert_eq!(second, vec!["explicit".to_string()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_cloned_request_keeps_generated_ids() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        json!({"took": 1, "errors": false, "items": [item("a", 201)]}),
+        json!({"took": 1, "errors": false, "items": [item("a", 201)]}),
+    ])
+    .await?;
+
+    // IDs are generated when operations are added, so every send reuses them
+    let bulk = client
+        .bulk()
+        .index("products", None::<String>, &TestDocument::new_sample(1))
+        .id_strategy(IdStrategy::UuidV7);
+    bulk.clone().send().await?;
+    bulk.send().await?;
+
+    let requests = requests.lock().unwrap().clone();
+    let first = request_ids(&requests[0])?;
+    assert!(uuid::Uuid::parse_str(&first[0]).is_ok());
+    assert_eq!(request_ids(&requests[1])?, first);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_id_strategies() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        json!({"took": 1, "errors": false, "items": []}),
+        json!({"took": 1, "errors": false, "items": []}),
+        json!({"took": 1, "errors": false, "items": []}),
+    ])
+    .await?;
+
+    // The same document always hashes to the same ID
+    client
+        .bulk()
+        .id_strategy(IdStrategy::ContentHash)
+        .index("products", None::<String>, &TestDocument::new_sample(1))
+        .index("products", None::<String>, &TestDocument::new_sample(1))
+        .index("products", None::<String>, &TestDocument::new_sample(2))
+        .send()
+        .await?;
+
+    client
+        .bulk()
+        .id_strategy(IdStrategy::custom(|document: &TestDocument| {
+            format!("doc-{}", document.views)
+        }))
+        .create("products", None::<String>, &TestDocument::new_sample(3))
+        .send()
+        .await?;
+
+    // Without a strategy OpenSearch assigns the ID
+    client
+        .bulk()
+        .index("products", None::<String>, &TestDocument::new_sample(4))
+        .send()
+        .await?;
+
+    let requests = requests.lock().unwrap().clone();
+    let hashed = request_ids(&requests[0])?;
+    assert_eq!(hashed[0], hashed[1]);
+    assert_ne!(hashed[0], hashed[2]);
+    assert_eq!(hashed[0].len(), 64);
+
+    let custom: Value = serde_json::from_str(requests[1].lines().next().unwrap())?;
+    assert_eq!(custom["create"]["_id"], "doc-3");
+
+    assert_eq!(request_ids(&requests[2])?, vec![String::new()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_document_stream_generated_ids() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("bulk-id-strategy");
+
+    let documents = stream::iter((0..20).map(TestDocument::new_sample));
+    let results: Vec<_> = fixture
+        .client
+        .bulk()
+        .id_strategy(IdStrategy::UuidV7)
+        .from_document_stream(
+            &index_name,
+            documents,
+            |_: &TestDocument| None,
+            BulkStreamConfig::builder().chunk_size(5usize).build()?,
+        )
+        .collect()
+        .await;
+
+    // Time-ordered IDs sort in the order the documents were sent
+    let mut ids = Vec::new();
+    for result in results {
+        let response = result?;
+        assert!(!response.has_failures());
+        ids.extend(
+            response
+                .items
+                .iter()
+                .map(|item| item.response().unwrap().id.clone()),
+        );
+    }
+    let mut sorted = ids.clone();
+    sorted.sort();
+    assert_eq!(ids, sorted);
+
+    // Indexing the same documents by content twice does not duplicate them
+    for _ in 0..2 {
+        let mut bulk = fixture.client.bulk().id_strategy(IdStrategy::ContentHash);
+        for i in 0..5 {
+            bulk = bulk.index(
+                &index_name,
+                None::<String>,
+                &TestDocument::new_sample(100 + i),
+            );
+        }
+        let response = bulk.refresh(RefreshPolicy::True).send().await?;
+        assert!(!response.errors);
+    }
+    let count = fixture.client.count(&index_name).build()?.send().await?;
+    assert_eq!(count.count, 25);
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! Tests for the auto-flushing bulk indexer
+
+pub mod fixture;
+
+use crate::fixture::{unreachable_client, OpenSearchFixture, TestDocument};
+use anyhow::Result;
+use opensearch_api::bulk::BulkOperation;
+use opensearch_api::bulk_indexer::BulkIndexerConfig;
+use opensearch_api::Error;
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_bulk_indexer_concurrent_producers() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("bulk-indexer");
+
+    let indexer = fixture.client.bulk_indexer(
+        BulkIndexerConfig::builder()
+            .flush_documents(100usize)
+            .concurrency(2usize)
+            .build()?,
+    )?;
+
+    // Several tasks share clones of the same indexer
+    let mut producers = Vec::new();
+    for producer in 0..4 {
+        let indexer = indexer.clone();
+        let index_name = index_name.clone();
+        producers.push(tokio::spawn(async move {
+            for i in 0..250 {
+                let id = producer * 250 + i;
+                indexer
+                    .add(BulkOperation::index(
+                        Some(index_name.clone()),
+                        Some(format!("doc-{}", id)),
+                        TestDocument::new_sample(id),
+                    ))
+                    .await?;
+            }
+            Ok::<_, Error>(())
+        }));
+    }
+    for producer in producers {
+        producer.await??;
+    }
+
+    let stats = indexer.close().await?;
+    assert_eq!(stats.added, 1000);
+    assert_eq!(stats.indexed, 1000);
+    assert_eq!(stats.failed, 0);
+    assert!(stats.requests >= 10);
+    assert!(stats.bytes > 0);
+
+    fixture
+        .client
+        .indices()
+        .refresh(&index_name)
+        .build()?
+        .send()
+        .await?;
+    let count = fixture.client.count(&index_name).build()?.send().await?;
+    assert_eq!(count.count, 1000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_indexer_flush_interval() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("bulk-indexer-interval");
+
+    let indexer = fixture.client.bulk_indexer(
+        BulkIndexerConfig::builder()
+            .flush_documents(1000usize)
+            .flush_interval(Duration::from_millis(200))
+            .build()?,
+    )?;
+
+    for i in 0..5 {
+        indexer
+            .add(BulkOperation::index(
+                Some(index_name.clone()),
+                None,
+                TestDocument::new_sample(i),
+            ))
+            .await?;
+    }
+
+    // The interval flushes the partial batch without an explicit flush
+    sleep(Duration::from_secs(2)).await;
+    let stats = indexer.stats();
+    assert_eq!(stats.requests, 1);
+    assert_eq!(stats.indexed, 5);
+
+    indexer.close().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_indexer_request_failures() -> Result<()> {
+    let indexer = unreachable_client()?.bulk_indexer(
+        BulkIndexerConfig::builder()
+            .flush_documents(10usize)
+            .flush_bytes(1024usize)
+            .build()?,
+    )?;
+    let other_handle = indexer.clone();
+
+    for i in 0..25 {
+        indexer
+            .add(BulkOperation::index(
+                Some("unreachable".to_string()),
+                None,
+                TestDocument::new_sample(i),
+            ))
+            .await?;
+    }
+
+    // Every operation of a failed request is counted as failed
+    let stats = indexer.close().await?;
+    assert_eq!(stats.added, 25);
+    assert_eq!(stats.indexed, 0);
+    assert_eq!(stats.failed, 25);
+    assert_eq!(stats.requests, 3);
+
+    // Remaining handles are rejected once the indexer is closed
+    let result = other_handle
+        .add(BulkOperation::<TestDocument>::delete(
+            Some("unreachable".to_string()),
+            "1".to_string(),
+        ))
+        .await;
+    assert!(matches!(result, Err(Error::BulkIndexerClosed)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_indexer_invalid_config() -> Result<()> {
+    let result = unreachable_client()?
+        .bulk_indexer(BulkIndexerConfig::builder().concurrency(0usize).build()?);
+    assert!(matches!(result, Err(Error::InvalidConfiguration(_))));
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//! Tests for NDJSON import and export of bulk payloads
+
+pub mod fixture;
+
+use crate::fixture::{unreachable_client, OpenSearchFixture, TestDocument};
+use anyhow::Result;
+use opensearch_api::bulk::BulkOperation;
+use opensearch_api::types::common::RefreshPolicy;
+use opensearch_api::Error;
+use std::fs::File;
+use std::io::BufReader;
+
+#[tokio::test]
+async fn test_bulk_ndjson_roundtrip() -> Result<()> {
+    let bulk = unreachable_client()?
+        .bulk::<TestDocument>()
+        .index("docs", Some("1"), &TestDocument::new_sample(1))
+        .create("docs", None::<String>, &TestDocument::new_sample(2))
+        .update("docs", "3", &TestDocument::new_sample(3))
+        .delete::<TestDocument>("docs", "4");
+
+    let payload = bulk.to_ndjson()?;
+    assert_eq!(payload.lines().count(), 7);
+    assert!(payload.ends_with('\n'));
+
+    let path = std::env::temp_dir().join(format!("bulk-{}.ndjson", uuid::Uuid::new_v4()));
+    bulk.write_ndjson(File::create(&path)?)?;
+    assert_eq!(std::fs::read_to_string(&path)?, payload);
+
+    // Parsing yields typed operations that serialize to the same payload
+    let operations =
+        BulkOperation::<TestDocument>::read_ndjson(BufReader::new(File::open(&path)?))?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(operations.len(), 4);
+    match &operations[0] {
+        BulkOperation::Index { metadata, document } => {
+            assert_eq!(metadata.index.as_deref(), Some("docs"));
+            assert_eq!(metadata.id.as_deref(), Some("1"));
+            assert_eq!(document, &TestDocument::new_sample(1));
+        }
+        other => panic!("expected an index operation, got {:?}", other),
+    }
+    assert!(matches!(
+        &operations[1],
+        BulkOperation::Create { metadata, .. } if metadata.id.is_none()
+    ));
+    assert!(matches!(
+        &operations[2],
+        BulkOperation::Update { document, .. } if document.doc == Some(TestDocument::new_sample(3))
+    ));
+    assert!(matches!(
+        &operations[3],
+        BulkOperation::Delete { metadata } if metadata.id == "4"
+    ));
+
+    let reparsed = unreachable_client()?
+        .bulk::<TestDocument>()
+        .operations(operations)
+        .to_ndjson()?;
+    assert_eq!(reparsed, payload);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_ndjson_parse_errors() -> Result<()> {
+    // Blank lines are skipped
+    let operations = BulkOperation::<TestDocument>::parse_ndjson(
+        "\n{\"delete\":{\"_index\":\"docs\",\"_id\":\"1\"}}\n\n",
+    )?;
+    assert_eq!(operations.len(), 1);
+
+    let cases = [
+        (
+            "{\"index\":{}}\n",
+            "missing document for index action on line 1",
+        ),
+        ("{\"delete\":{}}\n", "invalid delete metadata on line 1"),
+        (
+            "{\"upsert\":{}}\n",
+            "unknown bulk action 'upsert' on line 1",
+        ),
+        ("{}\n", "empty bulk action on line 1"),
+        ("not json\n", "invalid bulk action on line 1"),
+        (
+            "{\"delete\":{\"_id\":\"1\"}}\n\n{\"index\":{}}\n{\"title\":1}\n",
+            "invalid document after line 3",
+        ),
+    ];
+    for (payload, expected) in cases {
+        match BulkOperation::<TestDocument>::parse_ndjson(payload) {
+            Err(Error::Validation(message)) => assert!(
+                message.starts_with(expected),
+                "unexpected message '{}' for {:?}",
+                message,
+                payload
+            ),
+            other => panic!(
+                "expected a validation error for {:?}, got {:?}",
+                payload, other
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_ndjson_load_into_cluster() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("bulk-ndjson");
+
+    let mut payload = String::new();
+    for i in 0..5 {
+        payload.push_str(&format!(
+            "{{\"index\":{{\"_index\":\"{}\",\"_id\":\"doc-{}\"}}}}\n",
+            index_name, i
+        ));
+        payload.push_str(&serde_json::to_string(&TestDocument::new_sample(i))?);
+        payload.push('\n');
+    }
+
+    let operations = BulkOperation::<TestDocument>::parse_ndjson(&payload)?;
+    let response = fixture
+        .client
+        .bulk()
+        .operations(operations)
+        .refresh(RefreshPolicy::True)
+        .send()
+        .await?;
+    assert!(!response.errors);
+
+    let count = fixture.client.count(&index_name).build()?.send().await?;
+    assert_eq!(count.count, 5);
+
+    Ok(())
+}
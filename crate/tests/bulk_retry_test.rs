@@ -0,0 +1,168 @@
+//! Tests for retrying rejected bulk operations
+
+pub mod fixture;
+
+use crate::fixture::{stub_server, OpenSearchFixture, TestDocument};
+use anyhow::Result;
+use opensearch_api::bulk::BulkRetryPolicy;
+use opensearch_api::types::common::RefreshPolicy;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+fn item(id: &str, status: u16, error: Option<(&str, &str)>) -> Value {
+    let mut response = json!({"_index": "retry", "_id": id, "status": status});
+    if let Some((error_type, reason)) = error {
+        response["error"] = json!({"type": error_type, "reason": reason});
+    } else {
+        response["result"] = json!("created");
+    }
+    json!({ "index": response })
+}
+
+#[tokio::test]
+async fn test_bulk_retry_rejected_items() -> Result<()> {
+    let rejected = Some(("es_rejected_execution_exception", "rejected execution"));
+    let (client, requests) = stub_server(vec![
+        json!({"took": 3, "errors": true, "items": [
+            item("doc-1", 201, None),
+            item("doc-2", 429, rejected),
+            item("doc-3", 400, Some(("mapper_parsing_exception", "failed to parse"))),
+            item("doc-4", 429, rejected),
+        ]}),
+        json!({"took": 2, "errors": true, "items": [
+            item("doc-2", 201, None),
+            item("doc-4", 429, rejected),
+        ]}),
+        json!({"took": 1, "errors": false, "items": [
+            item("doc-4", 201, None),
+        ]}),
+    ])
+    .await?;
+
+    let mut bulk = client.bulk();
+    for i in 1..=4 {
+        bulk = bulk.index(
+            "retry",
+            Some(format!("doc-{}", i)),
+            &TestDocument::new_sample(i),
+        );
+    }
+    let response = bulk
+        .send_with_retry(
+            BulkRetryPolicy::builder()
+                .initial_backoff(Duration::from_millis(10))
+                .build()?,
+        )
+        .await?;
+
+    // Only the rejected operations are sent again
+    let requests = requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[1].lines().count(), 4);
+    assert!(requests[1].contains("\"doc-2\"") && requests[1].contains("\"doc-4\""));
+    assert_eq!(requests[2].lines().count(), 2);
+    assert!(requests[2].contains("\"doc-4\""));
+
+    assert_eq!(response.took, 6);
+    assert_eq!(response.retried, 3);
+    assert_eq!(response.items.len(), 4);
+    assert!(response
+        .items
+        .iter()
+        .enumerate()
+        .all(|(i, item)| item.response().unwrap().id == format!("doc-{}", i + 1)));
+
+    // The mapping error is permanent and keeps its document
+    assert!(response.has_failures());
+    assert_eq!(response.failures.len(), 1);
+    let failure = &response.failures[0];
+    assert_eq!(failure.status, 400);
+    assert_eq!(failure.attempts, 1);
+    assert_eq!(
+        failure.error.as_ref().unwrap().error_type,
+        "mapper_parsing_exception"
+    );
+    assert_eq!(failure.document(), Some(&TestDocument::new_sample(3)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_retry_exhausted() -> Result<()> {
+    let rejected = Some(("es_rejected_execution_exception", "rejected execution"));
+    let (client, requests) = stub_server(vec![
+        json!({"took": 1, "errors": true, "items": [item("doc-1", 429, rejected)]}),
+        json!({"took": 1, "errors": true, "items": [item("doc-1", 429, rejected)]}),
+    ])
+    .await?;
+
+    let response = client
+        .bulk()
+        .index("retry", Some("doc-1"), &TestDocument::new_sample(1))
+        .send_with_retry(
+            BulkRetryPolicy::builder()
+                .max_retries(1u32)
+                .initial_backoff(Duration::from_millis(10))
+                .build()?,
+        )
+        .await?;
+
+    // The operation is reported as failed once the policy runs out of retries
+    assert_eq!(requests.lock().unwrap().len(), 2);
+    assert_eq!(response.retried, 1);
+    assert_eq!(response.failures.len(), 1);
+    assert_eq!(response.failures[0].status, 429);
+    assert_eq!(response.failures[0].attempts, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_retry_policy_backoff() -> Result<()> {
+    let policy = BulkRetryPolicy::builder()
+        .initial_backoff(Duration::from_millis(100))
+        .max_backoff(Duration::from_millis(500))
+        .build()?;
+
+    assert_eq!(policy.backoff(0), Duration::from_millis(100));
+    assert_eq!(policy.backoff(1), Duration::from_millis(200));
+    assert_eq!(policy.backoff(2), Duration::from_millis(400));
+    assert_eq!(policy.backoff(3), Duration::from_millis(500));
+    assert_eq!(policy.backoff(40), Duration::from_millis(500));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_retry_permanent_conflict() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("bulk-retry");
+
+    fixture
+        .client
+        .bulk()
+        .index(&index_name, Some("doc-1"), &TestDocument::new_sample(1))
+        .refresh(RefreshPolicy::True)
+        .send()
+        .await?;
+
+    // Creating an existing document is a conflict that is never retried
+    let response = fixture
+        .client
+        .bulk()
+        .create(&index_name, Some("doc-1"), &TestDocument::new_sample(10))
+        .create(&index_name, Some("doc-2"), &TestDocument::new_sample(2))
+        .send_with_retry(BulkRetryPolicy::default())
+        .await?;
+
+    assert_eq!(response.retried, 0);
+    assert_eq!(response.items.len(), 2);
+    assert_eq!(response.failures.len(), 1);
+    let failure = &response.failures[0];
+    assert_eq!(failure.status, 409);
+    assert_eq!(failure.attempts, 1);
+    assert_eq!(failure.document(), Some(&TestDocument::new_sample(10)));
+    assert!(!response.items[1].is_failed());
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+//! Tests for stream-based bulk ingestion
+
+pub mod fixture;
+
+use crate::fixture::{stub_server, unreachable_client, OpenSearchFixture, TestDocument};
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use opensearch_api::bulk::{BulkOperation, BulkStreamConfig};
+use opensearch_api::dead_letter::MemoryDeadLetterSink;
+use serde_json::json;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_bulk_from_stream() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("bulk-stream");
+
+    let operations = stream::iter(0..95).map(|i| {
+        BulkOperation::index(
+            Some(index_name.clone()),
+            Some(format!("doc-{}", i)),
+            TestDocument::new_sample(i),
+        )
+    });
+    let config = BulkStreamConfig::builder()
+        .chunk_size(20usize)
+        .concurrency(3usize)
+        .build()?;
+
+    // One result per chunk, in the order of the chunks
+    let results: Vec<_> = fixture
+        .client
+        .bulk()
+        .from_stream(operations, config)
+        .collect()
+        .await;
+    assert_eq!(results.len(), 5);
+    for (chunk, result) in results.into_iter().enumerate() {
+        let response = result?;
+        assert!(!response.has_failures());
+        let expected = if chunk == 4 { 15 } else { 20 };
+        assert_eq!(response.items.len(), expected);
+        let first = response.items[0].response().unwrap();
+        assert_eq!(first.id, format!("doc-{}", chunk * 20));
+    }
+
+    fixture
+        .client
+        .indices()
+        .refresh(&index_name)
+        .build()?
+        .send()
+        .await?;
+    let count = fixture.client.count(&index_name).build()?.send().await?;
+    assert_eq!(count.count, 95);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_from_document_stream() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("bulk-document-stream");
+
+    let documents = stream::iter((0..10).map(TestDocument::new_sample));
+    let results: Vec<_> = fixture
+        .client
+        .bulk()
+        .from_document_stream(
+            &index_name,
+            documents,
+            |document: &TestDocument| Some(format!("doc-{}", document.views)),
+            BulkStreamConfig::default(),
+        )
+        .collect()
+        .await;
+    assert_eq!(results.len(), 1);
+
+    let response = results.into_iter().next().unwrap()?;
+    assert_eq!(response.items.len(), 10);
+    assert_eq!(response.items[3].response().unwrap().id, "doc-3");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_from_stream_request_errors() -> Result<()> {
+    let operations = stream::iter(0..25).map(|i| {
+        BulkOperation::index(
+            Some("unreachable".to_string()),
+            None,
+            TestDocument::new_sample(i),
+        )
+    });
+    let config = BulkStreamConfig::builder()
+        .chunk_size(10usize)
+        .concurrency(2usize)
+        .build()?;
+
+    // A failed chunk is reported without ending the stream
+    let results: Vec<_> = unreachable_client()?
+        .bulk()
+        .index("unreachable", Some("first"), &TestDocument::new_sample(100))
+        .from_stream(operations, config)
+        .collect()
+        .await;
+    assert_eq!(results.len(), 3);
+
+    // The operations of failed chunks are handed back
+    let sink = MemoryDeadLetterSink::new();
+    let mut unsent = Vec::new();
+    for result in results {
+        let err = result.expect_err("the cluster is unreachable");
+        err.write_dead_letters(&sink).await?;
+        unsent.extend(err.operations);
+    }
+    assert_eq!(unsent.len(), 26);
+    assert_eq!(sink.letters().len(), 26);
+    assert!(sink
+        .letters()
+        .iter()
+        .all(|letter| letter.status == 0 && letter.error_type == "request_error"));
+    assert_eq!(sink.letters()[0].id.as_deref(), Some("first"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_from_stream_flushes_partial_chunks() -> Result<()> {
+    let item =
+        json!({"index": {"_index": "events", "_id": "1", "status": 201, "result": "created"}});
+    let (client, requests) = stub_server(vec![
+        json!({"took": 1, "errors": false, "items": [item, item, item]}),
+    ])
+    .await?;
+
+    // A source that stays open after a few operations, like a message queue
+    let operations = stream::iter(0..3)
+        .map(|i| {
+            BulkOperation::index(
+                Some("events".to_string()),
+                None,
+                TestDocument::new_sample(i),
+            )
+        })
+        .chain(stream::pending());
+    let config = BulkStreamConfig::builder()
+        .chunk_size(100usize)
+        .flush_interval(Duration::from_millis(50))
+        .build()?;
+
+    let mut results = Box::pin(client.bulk().from_stream(operations, config));
+    let response = tokio::time::timeout(Duration::from_secs(5), results.next())
+        .await?
+        .expect("the partial chunk is sent")?;
+    assert_eq!(response.items.len(), 3);
+    assert_eq!(requests.lock().unwrap()[0].lines().count(), 6);
+
+    Ok(())
+}
@@ -4,8 +4,10 @@ pub mod fixture;
 
 use crate::fixture::OpenSearchFixture;
 use anyhow::Result;
+use opensearch_api::bulk::{BulkNamespace, BulkOperation};
 use opensearch_api::types::common::RefreshPolicy;
 use opensearch_api::types::script::{InlineScript, Script};
+use opensearch_api::types::search::SourceFilter;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::collections::HashMap;
@@ -676,3 +678,127 @@ async fn test_bulk_with_error_handling() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_documents_bulk_typed_response() -> Result<()> {
+    let fixture = setup_fixture().await?;
+    let index_name = fixture.namespaced_index("documents-bulk");
+
+    let operations = vec![
+        BulkOperation::index(
+            Some(index_name.clone()),
+            Some("doc-1".to_string()),
+            TestDocument::new_sample(1),
+        ),
+        BulkOperation::create(
+            Some(index_name.clone()),
+            Some("doc-1".to_string()),
+            TestDocument::new_sample(1),
+        ),
+        BulkOperation::index(
+            Some(index_name.clone()),
+            Some("doc-2".to_string()),
+            TestDocument::new_sample(2),
+        ),
+    ];
+
+    // The documents namespace returns the same typed response as client.bulk()
+    let response = fixture
+        .client
+        .documents()
+        .bulk()
+        .operations(operations.clone())
+        .refresh("true")
+        .send()
+        .await?;
+
+    assert!(response.errors);
+    assert_eq!(
+        response.succeeded_ids().collect::<Vec<_>>(),
+        vec!["doc-1", "doc-2"]
+    );
+    assert_eq!(response.failed_items().count(), 1);
+    assert_eq!(response.by_status()[&409].len(), 1);
+    for (operation, item) in response.iter_with_ops(&operations) {
+        assert_eq!(Some(operation.action()), item.action());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_upsert_with_partial_document() -> Result<()> {
+    let fixture = setup_fixture().await?;
+    let index_name = fixture.namespaced_index("bulk-upsert");
+
+    // The patch only holds the changed field, the upsert is the full document
+    let patch = json!({"views": 50});
+    let response = BulkNamespace::<Value, TestDocument>::new(fixture.client.clone())
+        .upsert(&index_name, "doc-1", &patch, &TestDocument::new_sample(1))
+        .update_with_document(&index_name, "doc-2", |doc| {
+            doc.doc(patch.clone())
+                .upsert(TestDocument::new_sample(2))
+                .source(SourceFilter::Enabled(true))
+        })
+        .refresh(RefreshPolicy::True)
+        .send()
+        .await?;
+    assert!(!response.errors);
+    assert_eq!(
+        response.items[0].response().unwrap().result.as_deref(),
+        Some("created")
+    );
+    let inserted = response.items[1]
+        .response()
+        .unwrap()
+        .source::<TestDocument>()?
+        .unwrap();
+    assert_eq!(inserted, TestDocument::new_sample(2));
+
+    // A second pass applies the patch, retrying on version conflicts
+    let response = fixture
+        .client
+        .bulk()
+        .update_with_metadata(&index_name, "doc-1", &patch, |metadata| {
+            metadata.retry_on_conflict(3)
+        })
+        .update_with_document(&index_name, "doc-2", |doc| {
+            doc.doc(patch.clone())
+                .detect_noop(true)
+                .source(SourceFilter::Enabled(true))
+        })
+        .send()
+        .await?;
+    assert!(!response.errors);
+    assert_eq!(
+        response.items[0].response().unwrap().result.as_deref(),
+        Some("updated")
+    );
+    let updated = response.items[1].response().unwrap();
+    assert_eq!(updated.result.as_deref(), Some("noop"));
+    assert_eq!(updated.source::<TestDocument>()?.unwrap().views, 50);
+
+    // A scripted upsert runs the script against an empty document
+    let script = Script::Inline(InlineScript {
+        source: "ctx._source.views = (ctx._source.views ?: 0) + 1".to_string(),
+        lang: Some("painless".to_string()),
+        params: None,
+        options: None,
+    });
+    let response = fixture
+        .client
+        .bulk::<Value>()
+        .update_with_document(&index_name, "doc-3", |doc| {
+            doc.script(script)
+                .scripted_upsert(true)
+                .upsert(json!({}))
+                .source(SourceFilter::Enabled(true))
+        })
+        .send()
+        .await?;
+    assert!(!response.errors);
+    let source = response.items[0].response().unwrap().source::<Value>()?;
+    assert_eq!(source, Some(json!({"views": 1})));
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+//! Tests for dead-letter sinks of failed bulk operations
+
+pub mod fixture;
+
+use crate::fixture::{unreachable_client, OpenSearchFixture};
+use anyhow::Result;
+use opensearch_api::bulk::{BulkOperation, BulkRetryPolicy};
+use opensearch_api::bulk_indexer::BulkIndexerConfig;
+use opensearch_api::dead_letter::{
+    DeadLetter, DeadLetterSink, FileDeadLetterSink, IndexDeadLetterSink, MemoryDeadLetterSink,
+};
+use opensearch_api::types::common::RefreshPolicy;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_dead_letter_roundtrip() -> Result<()> {
+    let letter = DeadLetter::new(
+        "{\"index\":{\"_index\":\"logs\",\"_id\":\"1\"}}\n{\"count\":\"many\"}\n",
+        400,
+        "mapper_parsing_exception",
+        "failed to parse field [count]",
+    );
+    assert_eq!(letter.index.as_deref(), Some("logs"));
+    assert_eq!(letter.id.as_deref(), Some("1"));
+    assert!(letter.timestamp > 0);
+
+    // The original operation is rebuilt from the payload
+    let operation = letter.to_operation()?;
+    match &operation {
+        BulkOperation::Index { metadata, document } => {
+            assert_eq!(metadata.index.as_deref(), Some("logs"));
+            assert_eq!(metadata.id.as_deref(), Some("1"));
+            assert_eq!(document, &json!({"count": "many"}));
+        }
+        other => panic!("expected an index operation, got {:?}", other),
+    }
+
+    let update = DeadLetter::new(
+        "{\"update\":{\"_index\":\"logs\",\"_id\":\"2\"}}\n{\"doc\":{\"count\":1},\"doc_as_upsert\":true}\n",
+        409,
+        "version_conflict_engine_exception",
+        "version conflict",
+    );
+    assert!(matches!(
+        update.to_operation()?,
+        BulkOperation::Update { document, .. } if document.doc_as_upsert == Some(true)
+    ));
+
+    let delete = DeadLetter::new("{\"delete\":{\"_id\":\"3\"}}\n", 404, "", "");
+    assert!(delete.index.is_none());
+    assert!(matches!(
+        delete.to_operation()?,
+        BulkOperation::Delete { .. }
+    ));
+
+    let invalid = DeadLetter::new("{\"index\":{\"_id\":\"4\"}}\n", 400, "", "");
+    assert!(invalid.to_operation().is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_dead_letter_sink() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("dead-letters-{}.ndjson", uuid::Uuid::new_v4()));
+    let sink = FileDeadLetterSink::new(&path);
+    assert!(sink.read().await?.is_empty());
+
+    let first = DeadLetter::new(
+        "{\"delete\":{\"_id\":\"1\"}}\n",
+        404,
+        "not_found",
+        "missing",
+    );
+    let second = DeadLetter::new(
+        "{\"index\":{\"_id\":\"2\"}}\n{\"field\":true}\n",
+        400,
+        "mapper_parsing_exception",
+        "failed to parse",
+    );
+    sink.write(vec![first.clone()]).await?;
+    sink.write(vec![second.clone()]).await?;
+
+    // Every record is appended as its own line
+    let content = std::fs::read_to_string(&path)?;
+    assert_eq!(content.lines().count(), 2);
+    assert_eq!(sink.read().await?, vec![first, second]);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_indexer_dead_letters() -> Result<()> {
+    let sink = MemoryDeadLetterSink::new();
+    let indexer = unreachable_client()?.bulk_indexer(
+        BulkIndexerConfig::builder()
+            .flush_documents(2usize)
+            .dead_letter(sink.clone())
+            .build()?,
+    )?;
+
+    for i in 0..3 {
+        indexer
+            .add(BulkOperation::index(
+                Some("unreachable".to_string()),
+                Some(format!("doc-{}", i)),
+                json!({"value": i}),
+            ))
+            .await?;
+    }
+    let stats = indexer.close().await?;
+    assert_eq!(stats.failed, 3);
+
+    // Operations of failed requests are kept with their original payload
+    let mut letters = sink.take();
+    letters.sort_by(|a, b| a.id.cmp(&b.id));
+    assert_eq!(letters.len(), 3);
+    assert!(sink.letters().is_empty());
+    for (i, letter) in letters.iter().enumerate() {
+        assert_eq!(letter.status, 0);
+        assert_eq!(letter.error_type, "request_error");
+        assert_eq!(letter.index.as_deref(), Some("unreachable"));
+        assert_eq!(letter.id, Some(format!("doc-{}", i)));
+        assert!(matches!(
+            letter.to_operation()?,
+            BulkOperation::Index { document, .. } if document == json!({"value": i})
+        ));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dead_letter_index_and_replay() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("dead-letter-docs");
+    let dlq_name = fixture.namespaced_index("dead-letter-queue");
+
+    fixture
+        .client
+        .indices()
+        .create(&index_name)
+        .mappings(json!({"properties": {"count": {"type": "integer"}}}))
+        .build()?
+        .send()
+        .await?;
+
+    let response = fixture
+        .client
+        .bulk()
+        .index(&index_name, Some("good"), &json!({"count": 1}))
+        .index(&index_name, Some("bad"), &json!({"count": "many"}))
+        .send_with_retry(BulkRetryPolicy::default())
+        .await?;
+    assert_eq!(response.failures.len(), 1);
+
+    // Failures are stored in the dead-letter index
+    let sink = IndexDeadLetterSink::new(fixture.client.clone(), &dlq_name);
+    assert_eq!(response.write_dead_letters(&sink).await?, 1);
+    fixture
+        .client
+        .indices()
+        .refresh(&dlq_name)
+        .build()?
+        .send()
+        .await?;
+    let count = fixture.client.count(&dlq_name).build()?.send().await?;
+    assert_eq!(count.count, 1);
+
+    let memory = MemoryDeadLetterSink::new();
+    response.write_dead_letters(&memory).await?;
+    let letters = memory.letters();
+    assert_eq!(letters[0].error_type, "mapper_parsing_exception");
+    assert_eq!(letters[0].id.as_deref(), Some("bad"));
+
+    // Replaying fails the same way until the mapping allows the document
+    let replayed = fixture
+        .client
+        .bulk()
+        .replay(&letters)?
+        .refresh(RefreshPolicy::True)
+        .send()
+        .await?;
+    assert!(replayed.errors);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! Tests for the Document trait derive macro
+
+use opensearch_api::Document;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Document)]
+#[opensearch(index = "products")]
+struct Product {
+    #[opensearch(id)]
+    sku: String,
+    #[opensearch(routing)]
+    tenant: String,
+    #[opensearch(version)]
+    revision: u32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Document)]
+#[opensearch(index = "events")]
+struct Event {
+    #[opensearch(id)]
+    event_id: Option<u64>,
+    #[opensearch(version)]
+    sequence: Option<i64>,
+    message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Document)]
+#[opensearch(index = "snapshots")]
+struct Snapshot {
+    #[opensearch(version)]
+    generation: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Document)]
+#[opensearch(index = "logs")]
+struct LogLine<T> {
+    line: T,
+}
+
+#[test]
+fn test_derive_document_fields() {
+    let product = Product {
+        sku: "sku-1".to_string(),
+        tenant: "acme".to_string(),
+        revision: 3,
+        name: "Widget".to_string(),
+    };
+
+    assert_eq!(Product::index_name(), "products");
+    assert_eq!(product.id(), Some("sku-1".to_string()));
+    assert_eq!(product.routing(), Some("acme".to_string()));
+    assert_eq!(product.version(), Some(3));
+}
+
+#[test]
+fn test_derive_document_optional_fields() {
+    let mut event = Event {
+        event_id: Some(42),
+        sequence: Some(7),
+        message: "started".to_string(),
+    };
+
+    assert_eq!(Event::index_name(), "events");
+    assert_eq!(event.id(), Some("42".to_string()));
+    assert_eq!(event.routing(), None);
+    assert_eq!(event.version(), Some(7));
+
+    event.event_id = None;
+    event.sequence = None;
+    assert_eq!(event.id(), None);
+    assert_eq!(event.version(), None);
+}
+
+#[test]
+fn test_derive_document_unsigned_version() {
+    let mut snapshot = Snapshot { generation: 12 };
+    assert_eq!(snapshot.version(), Some(12));
+
+    // Versions outside the i64 range are not wrapped into negative numbers
+    snapshot.generation = i64::MAX as u64 + 1;
+    assert_eq!(snapshot.version(), None);
+}
+
+#[test]
+fn test_derive_document_generic_without_id() {
+    let line = LogLine {
+        line: "hello".to_string(),
+    };
+
+    assert_eq!(LogLine::<String>::index_name(), "logs");
+    assert_eq!(line.id(), None);
+    assert_eq!(line.routing(), None);
+    assert_eq!(line.version(), None);
+}
@@ -5,8 +5,9 @@ pub mod fixture;
 use crate::fixture::OpenSearchFixture;
 use anyhow::Result;
 use opensearch_api::types::document::{
-    DeleteOptions, IndexOptions, UpdateOptions, WaitForActiveShards,
+    DeleteOptions, IndexOptions, TermVectorsFilter, UpdateOptions, WaitForActiveShards,
 };
+use opensearch_api::{Document, Error};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::time::Duration;
@@ -766,3 +767,227 @@ async fn test_document_operations_with_routing() -> Result<()> {
 
     Ok(())
 }
+
+/// Document type that carries its own index, ID and routing
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Document)]
+#[opensearch(index = "derived-docs")]
+struct DerivedDocument {
+    #[opensearch(id)]
+    doc_id: String,
+    #[opensearch(routing)]
+    tenant: String,
+    title: String,
+}
+
+#[tokio::test]
+async fn test_save_and_get_derived_document() -> Result<()> {
+    let fixture = setup_fixture().await?;
+    let doc = DerivedDocument {
+        doc_id: format!("derived-{}", fixture.id),
+        tenant: "tenant-a".to_string(),
+        title: "Derived Document".to_string(),
+    };
+
+    // Index and ID are inferred from the document
+    let response = fixture
+        .client
+        .documents()
+        .save(&doc)
+        .refresh("true")
+        .send()
+        .await?;
+
+    assert_eq!(response.index, DerivedDocument::index_name());
+    assert_eq!(response.id, doc.doc_id);
+
+    let get_response = fixture
+        .client
+        .documents()
+        .get_doc::<DerivedDocument>(&doc.doc_id)
+        .routing(&doc.tenant)
+        .send()
+        .await?
+        .expect("Derived document should exist");
+
+    assert_eq!(get_response.index, DerivedDocument::index_name());
+    assert_eq!(get_response.source, Some(doc));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_termvectors() -> Result<()> {
+    let fixture = setup_fixture().await?;
+    let index_name = fixture.namespaced_index("termvectors");
+    let doc = TestDocument::new_sample();
+
+    for doc_id in ["tv-1", "tv-2"] {
+        fixture
+            .client
+            .documents()
+            .index(&index_name)
+            .document(&doc)
+            .id(doc_id)
+            .refresh("true")
+            .send()
+            .await?;
+    }
+
+    // Stored document with term statistics and token positions
+    let response = fixture
+        .client
+        .documents()
+        .termvectors(&index_name, "tv-1")
+        .fields(vec!["content"])
+        .positions(true)
+        .offsets(true)
+        .term_statistics(true)
+        .field_statistics(true)
+        .send()
+        .await?;
+
+    assert!(response.found);
+    let content = &response.term_vectors["content"];
+    assert_eq!(content.field_statistics.as_ref().unwrap().doc_count, 2);
+    let term = &content.terms["opensearch"];
+    assert_eq!(term.term_freq, 1);
+    assert_eq!(term.doc_freq, Some(2));
+    assert!(term.tokens.as_ref().unwrap()[0].start_offset.is_some());
+
+    // Artificial document filtered down to a single term
+    let response = fixture
+        .client
+        .documents()
+        .termvectors_doc(&index_name, json!({ "content": "search search engine" }))
+        .fields(vec!["content"])
+        .filter(
+            TermVectorsFilter::builder()
+                .max_num_terms(1u32)
+                .min_term_freq(2u32)
+                .build()?,
+        )
+        .send()
+        .await?;
+
+    let content = &response.term_vectors["content"];
+    assert_eq!(content.terms.len(), 1);
+    assert_eq!(content.terms["search"].term_freq, 2);
+
+    // Multiple documents in one request
+    let response = fixture
+        .client
+        .documents()
+        .mtermvectors()
+        .index(&index_name)
+        .ids(vec!["tv-1".to_string(), "tv-2".to_string()])
+        .fields(vec!["content"])
+        .send()
+        .await?;
+
+    assert_eq!(response.docs.len(), 2);
+    assert!(response.docs.iter().all(|doc| doc.found));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_source_and_source_exists() -> Result<()> {
+    let fixture = setup_fixture().await?;
+    let index_name = fixture.namespaced_index("docs");
+    let doc = TestDocument::new_sample();
+    let doc_id = "test-doc-source";
+
+    // Missing documents have no source
+    let missing = fixture
+        .client
+        .documents()
+        .get_source::<TestDocument>(&index_name, doc_id)
+        .send()
+        .await?;
+    assert_eq!(missing, None);
+
+    fixture
+        .client
+        .documents()
+        .index(&index_name)
+        .document(&doc)
+        .id(doc_id)
+        .refresh("true")
+        .send()
+        .await?;
+
+    let source = fixture
+        .client
+        .documents()
+        .get_source::<TestDocument>(&index_name, doc_id)
+        .send()
+        .await?;
+    assert_eq!(source, Some(doc));
+
+    // Source filtering returns only the requested fields
+    let partial = fixture
+        .client
+        .documents()
+        .get_source::<Value>(&index_name, doc_id)
+        .source_includes(vec!["title"])
+        .send()
+        .await?
+        .expect("Document source should exist");
+    assert_eq!(partial, json!({ "title": "Test Document" }));
+
+    assert!(
+        fixture
+            .client
+            .documents()
+            .source_exists(&index_name, doc_id)
+            .send()
+            .await?
+    );
+    assert!(
+        !fixture
+            .client
+            .documents()
+            .source_exists(&index_name, "missing-doc")
+            .send()
+            .await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_document() -> Result<()> {
+    let fixture = setup_fixture().await?;
+    let index_name = fixture.namespaced_index("docs");
+    let doc = TestDocument::new_sample();
+    let doc_id = "test-doc-create";
+
+    let response = fixture
+        .client
+        .documents()
+        .create(&index_name, doc_id)
+        .document(&doc)
+        .refresh("true")
+        .send()
+        .await?;
+    assert_eq!(response.id, doc_id);
+    assert_eq!(response.result, "created");
+
+    // Creating the same ID again is a typed conflict
+    let conflict = fixture
+        .client
+        .documents()
+        .create(&index_name, doc_id)
+        .document(&doc)
+        .send()
+        .await;
+    match conflict {
+        Err(Error::DocumentAlreadyExists(id, index)) => {
+            assert_eq!(id, doc_id);
+            assert_eq!(index, index_name);
+        }
+        other => panic!("Expected DocumentAlreadyExists, got {:?}", other),
+    }
+
+    Ok(())
+}
@@ -1,11 +1,15 @@
 use opensearch_api::{Client, ClientConfig};
 use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use testcontainers::core::wait::HttpWaitStrategy;
 use testcontainers::core::{ContainerPort, WaitFor};
 use testcontainers::runners::AsyncRunner;
 use testcontainers::{ContainerAsync, Image, ImageExt, ReuseDirective};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
 use uuid::Uuid;
 
 /// Default OpenSearch port for tests
@@ -152,6 +156,166 @@ impl OpenSearchFixture {
     }
 }
 
+/// Minimal HTTP server answering every request with the next canned JSON response
+///
+/// Returns a client pointing at the server and the bodies of the requests received.
+pub async fn stub_server(
+    responses: Vec<serde_json::Value>,
+) -> anyhow::Result<(Client, Arc<Mutex<Vec<String>>>)> {
+    let (url, requests) = stub_server_url(responses).await?;
+    let config = ClientConfig::builder()
+        .base_url(url)
+        .timeout_secs(5u64)
+        .build()?;
+    Ok((Client::builder().config(config).build()?, requests))
+}
+
+/// Same as [`stub_server`], returning the URL of the server instead of a client
+pub async fn stub_server_url(
+    responses: Vec<serde_json::Value>,
+) -> anyhow::Result<(String, Arc<Mutex<Vec<String>>>)> {
+    let (url, requests, _targets) = serve(responses).await?;
+    Ok((url, requests))
+}
+
+/// Same as [`stub_server`], also returning the method and target of the requests received,
+/// such as `POST /logs/_search?size=10`
+pub async fn stub_server_targets(
+    responses: Vec<serde_json::Value>,
+) -> anyhow::Result<(Client, Arc<Mutex<Vec<String>>>, Arc<Mutex<Vec<String>>>)> {
+    let (url, requests, targets) = serve(responses).await?;
+    let config = ClientConfig::builder()
+        .base_url(url)
+        .timeout_secs(5u64)
+        .build()?;
+    Ok((Client::builder().config(config).build()?, requests, targets))
+}
+
+type Received = Arc<Mutex<Vec<String>>>;
+
+async fn serve(responses: Vec<serde_json::Value>) -> anyhow::Result<(String, Received, Received)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let address = listener.local_addr()?;
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let targets = Arc::new(Mutex::new(Vec::new()));
+
+    let received = requests.clone();
+    let received_targets = targets.clone();
+    tokio::spawn(async move {
+        for response in responses {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                return;
+            };
+
+            // Read the headers, then as many body bytes as announced
+            let mut request = Vec::new();
+            let mut chunk = [0u8; 4096];
+            let body_start = loop {
+                let read = stream.read(&mut chunk).await.unwrap_or(0);
+                if read == 0 {
+                    return;
+                }
+                request.extend_from_slice(&chunk[..read]);
+                if let Some(end) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+                    break end + 4;
+                }
+            };
+            let headers = String::from_utf8_lossy(&request[..body_start]).to_lowercase();
+            let target = String::from_utf8_lossy(&request[..body_start])
+                .lines()
+                .next()
+                .and_then(|line| line.rsplit_once(' '))
+                .map(|(target, _version)| target.to_string())
+                .unwrap_or_default();
+            received_targets.lock().unwrap().push(target);
+            let length = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            while request.len() < body_start + length {
+                let read = stream.read(&mut chunk).await.unwrap_or(0);
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&chunk[..read]);
+            }
+            received
+                .lock()
+                .unwrap()
+                .push(String::from_utf8_lossy(&request[body_start..]).to_string());
+
+            let body = response.to_string();
+            let reply = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(reply.as_bytes()).await;
+            let _ = stream.shutdown().await;
+        }
+    });
+
+    Ok((format!("http://{}", address), requests, targets))
+}
+
+/// Client pointing at a port nobody listens on
+pub fn unreachable_client() -> anyhow::Result<Client> {
+    let config = ClientConfig::builder()
+        .base_url("http://127.0.0.1:9")
+        .timeout_secs(5u64)
+        .build()?;
+    Ok(Client::builder().config(config).build()?)
+}
+
+/// Test document shared by the bulk tests
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TestDocument {
+    pub title: String,
+    pub views: u32,
+}
+
+impl TestDocument {
+    pub fn new_sample(index: usize) -> Self {
+        Self {
+            title: format!("Test Document {}", index),
+            views: index as u32,
+        }
+    }
+}
+
+/// Document of the search pages built by [`search_page`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PositionDocument {
+    pub position: u32,
+}
+
+/// Search response page with the given scroll or point-in-time ID and document positions
+///
+/// `id_field` is `_scroll_id` or `pit_id`. Hits are sorted on
+/// `[position, "doc-<position>"]`.
+pub fn search_page(id_field: &str, id: &str, positions: &[u32]) -> serde_json::Value {
+    let hits: Vec<serde_json::Value> = positions
+        .iter()
+        .map(|position| {
+            serde_json::json!({
+                "_index": "logs",
+                "_id": format!("doc-{}", position),
+                "_score": null,
+                "_source": {"position": position},
+                "sort": [position, format!("doc-{}", position)]
+            })
+        })
+        .collect();
+    serde_json::json!({
+        id_field: id,
+        "took": 1,
+        "timed_out": false,
+        "_shards": {"total": 1, "successful": 1, "failed": 0},
+        "hits": {"total": {"value": positions.len(), "relation": "eq"}, "max_score": null, "hits": hits}
+    })
+}
+
 #[cfg(test)]
 mod tests {
     // use super::*;
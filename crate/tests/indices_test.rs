@@ -5,6 +5,7 @@ pub mod fixture;
 use crate::fixture::OpenSearchFixture;
 use anyhow::Result;
 use opensearch_api::indices::{AddAliasAction, AliasAction, IndexSettings, RemoveAliasAction, UpdateIndexSettings};
+use opensearch_api::types::query::{MatchAllQuery, Query};
 use serde_json::json;
 use std::collections::HashMap;
 
@@ -1025,3 +1026,58 @@ async fn test_combined_operations() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_validate_query() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("validate_query_test");
+
+    create_test_index(&fixture, &index_name).await?;
+
+    // A well-formed query is valid
+    let valid_response = fixture
+        .client
+        .indices()
+        .validate_query(&index_name)
+        .query(MatchAllQuery::builder().build()?)
+        .rewrite(true)
+        .build()?
+        .send()
+        .await?;
+
+    assert!(valid_response.valid);
+    assert!(!valid_response.explanations.is_empty());
+    assert!(valid_response.explanations[0].explanation.is_some());
+
+    // An unknown generic query is rejected with an explanation
+    let invalid_query = Query::Generic(HashMap::from([(
+        "not_a_query".to_string(),
+        json!({ "field": "value" }),
+    )]));
+    let invalid_response = fixture
+        .client
+        .indices()
+        .validate_query(&index_name)
+        .query(invalid_query)
+        .explain(true)
+        .build()?
+        .send()
+        .await?;
+
+    assert!(!invalid_response.valid);
+    assert!(invalid_response
+        .explanations
+        .iter()
+        .any(|explanation| explanation.error.is_some()));
+
+    // Clean up
+    fixture
+        .client
+        .indices()
+        .delete(&index_name)
+        .build()?
+        .send()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,272 @@
+//! Tests for the opensearch-load binary
+#![cfg(feature = "loader")]
+
+pub mod fixture;
+
+use crate::fixture::{stub_server_url, OpenSearchFixture, DEFAULT_PASSWORD, DEFAULT_USERNAME};
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use std::process::Output;
+use tokio::process::Command;
+
+/// Write a file with the given content to the temporary directory
+fn temp_file(extension: &str, content: &str) -> Result<PathBuf> {
+    let path = std::env::temp_dir().join(format!(
+        "opensearch-load-{}.{}",
+        uuid::Uuid::new_v4(),
+        extension
+    ));
+    std::fs::write(&path, content)?;
+    Ok(path)
+}
+
+async fn run_loader(url: &str, args: &[&str]) -> Result<Output> {
+    Ok(Command::new(env!("CARGO_BIN_EXE_opensearch-load"))
+        .args(args)
+        .args(["--url", url, "--quiet"])
+        .env_remove("OPENSEARCH_CONFIG")
+        .output()
+        .await?)
+}
+
+fn created(id: &str) -> Value {
+    json!({"index": {"_index": "products", "_id": id, "status": 201, "result": "created"}})
+}
+
+#[tokio::test]
+async fn test_load_csv() -> Result<()> {
+    let path = temp_file(
+        "csv",
+        "sku,name,price,available\nA-1,Lamp,12.5,true\nA-2,Desk,120,false\n3,,7,true\n",
+    )?;
+    let (url, requests) = stub_server_url(vec![
+        json!({"took": 1, "errors": false, "items": [created("A-1"), created("A-2")]}),
+        json!({"took": 1, "errors": false, "items": [created("3")]}),
+    ])
+    .await?;
+
+    let output = run_loader(
+        &url,
+        &[
+            path.to_str().unwrap(),
+            "--index",
+            "products",
+            "--id-field",
+            "sku",
+            "--batch-size",
+            "2",
+            "--concurrency",
+            "1",
+            "--infer-types",
+        ],
+    )
+    .await?;
+    std::fs::remove_file(&path)?;
+    assert!(output.status.success(), "{:?}", output);
+
+    // Fields are typed except the ID field, and empty fields are left out
+    let requests = requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 2);
+    let lines: Vec<Value> = requests
+        .iter()
+        .flat_map(|request| request.lines())
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+    assert_eq!(
+        lines[0],
+        json!({"index": {"_index": "products", "_id": "A-1"}})
+    );
+    assert_eq!(
+        lines[1],
+        json!({"sku": "A-1", "name": "Lamp", "price": 12.5, "available": true})
+    );
+    assert_eq!(
+        lines[4],
+        json!({"index": {"_index": "products", "_id": "3"}})
+    );
+    assert_eq!(lines[5], json!({"sku": "3", "price": 7, "available": true}));
+
+    let report = String::from_utf8(output.stdout)?;
+    assert!(report.contains("documents: 3"), "{}", report);
+    assert!(report.contains("indexed:   3"), "{}", report);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_csv_keeps_strings() -> Result<()> {
+    let path = temp_file("csv", "sku,zip,ratio,active\n00123,02139,1e5,true\n")?;
+    let (url, requests) = stub_server_url(vec![
+        json!({"took": 1, "errors": false, "items": [created("00123")]}),
+    ])
+    .await?;
+
+    let output = run_loader(
+        &url,
+        &[
+            path.to_str().unwrap(),
+            "--index",
+            "products",
+            "--id-field",
+            "sku",
+        ],
+    )
+    .await?;
+    std::fs::remove_file(&path)?;
+    assert!(output.status.success(), "{:?}", output);
+
+    // Without --infer-types every field is sent as written
+    let request = requests.lock().unwrap()[0].clone();
+    let lines: Vec<Value> = request
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+    assert_eq!(
+        lines[0],
+        json!({"index": {"_index": "products", "_id": "00123"}})
+    );
+    assert_eq!(
+        lines[1],
+        json!({"sku": "00123", "zip": "02139", "ratio": "1e5", "active": "true"})
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_max_request_bytes() -> Result<()> {
+    let path = temp_file(
+        "ndjson",
+        "{\"sku\":\"C-1\"}\n{\"sku\":\"C-2\"}\n{\"sku\":\"C-3\"}\n",
+    )?;
+    let (url, requests) = stub_server_url(vec![
+        json!({"took": 1, "errors": false, "items": [created("C-1")]}),
+        json!({"took": 1, "errors": false, "items": [created("C-2")]}),
+        json!({"took": 1, "errors": false, "items": [created("C-3")]}),
+    ])
+    .await?;
+
+    // Each operation is about 50 bytes, so the batch is sent one by one
+    let output = run_loader(
+        &url,
+        &[
+            path.to_str().unwrap(),
+            "--index",
+            "products",
+            "--concurrency",
+            "1",
+            "--max-request-bytes",
+            "60",
+        ],
+    )
+    .await?;
+    std::fs::remove_file(&path)?;
+    assert!(output.status.success(), "{:?}", output);
+
+    let requests = requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 3);
+    assert!(requests.iter().all(|request| request.lines().count() == 2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_reports_failures() -> Result<()> {
+    let path = temp_file("ndjson", "{\"sku\":\"B-1\"}\n\n{\"sku\":\"B-2\"}\n")?;
+    let (url, _requests) = stub_server_url(vec![json!({"took": 1, "errors": true, "items": [
+        created("B-1"),
+        {"index": {"_index": "products", "_id": "B-2", "status": 400,
+            "error": {"type": "mapper_parsing_exception", "reason": "failed to parse"}}}
+    ]})])
+    .await?;
+
+    let output = run_loader(&url, &[path.to_str().unwrap(), "--index", "products"]).await?;
+    std::fs::remove_file(&path)?;
+
+    // Failed documents are reported and give a distinct exit code
+    assert_eq!(output.status.code(), Some(2));
+    let report = String::from_utf8(output.stdout)?;
+    assert!(report.contains("failed:    1"), "{}", report);
+    assert!(report.contains("mapper_parsing_exception: 1"), "{}", report);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_invalid_file() -> Result<()> {
+    let path = temp_file("ndjson", "{\"sku\":\"C-1\"}\nnot json\n")?;
+    let (url, _requests) = stub_server_url(vec![
+        json!({"took": 1, "errors": false, "items": [created("C-1")]}),
+    ])
+    .await?;
+
+    let output = run_loader(&url, &[path.to_str().unwrap(), "--index", "products"]).await?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(output.status.code(), Some(1));
+    let error = String::from_utf8(output.stderr)?;
+    assert!(error.contains("invalid JSON on line 2"), "{}", error);
+
+    let path = temp_file("txt", "")?;
+    let output = run_loader(&url, &[path.to_str().unwrap(), "--index", "products"]).await?;
+    std::fs::remove_file(&path)?;
+    let error = String::from_utf8(output.stderr)?;
+    assert!(error.contains("pass --format"), "{}", error);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_json_into_cluster() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("loader");
+
+    let documents: Vec<Value> = (0..50)
+        .map(|i| json!({"sku": format!("D-{}", i), "price": i}))
+        .collect();
+    let path = temp_file("json", &serde_json::to_string(&documents)?)?;
+
+    // The connection is read from the same configuration file as the client
+    let config = temp_file(
+        "yaml",
+        &format!(
+            "base_url: {}\nusername: {}\npassword: \"{}\"\nverify_ssl: false\n",
+            fixture.get_base_url().await?,
+            DEFAULT_USERNAME,
+            DEFAULT_PASSWORD
+        ),
+    )?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_opensearch-load"))
+        .args([
+            path.to_str().unwrap(),
+            "--index",
+            &index_name,
+            "--create-index",
+            "--generate-ids",
+            "hash",
+            "--batch-size",
+            "10",
+            "--config",
+            config.to_str().unwrap(),
+            "--quiet",
+        ])
+        .output()
+        .await?;
+    std::fs::remove_file(&path)?;
+    std::fs::remove_file(&config)?;
+    assert!(output.status.success(), "{:?}", output);
+
+    fixture
+        .client
+        .indices()
+        .refresh(&index_name)
+        .build()?
+        .send()
+        .await?;
+    let count = fixture.client.count(&index_name).build()?.send().await?;
+    assert_eq!(count.count, 50);
+
+    Ok(())
+}
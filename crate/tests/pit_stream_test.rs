@@ -0,0 +1,200 @@
+//! Tests for point-in-time searches and streaming hits with `search_after`
+
+pub mod fixture;
+
+use crate::fixture::{search_page, stub_server, OpenSearchFixture, PositionDocument};
+use anyhow::Result;
+use futures::{StreamExt, TryStreamExt};
+use opensearch_api::types::common::RefreshPolicy;
+use opensearch_api::types::query::Query;
+use opensearch_api::types::search::{PointInTime, SortOptions, SortOrder, SortTerm};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+fn opened(pit_id: &str) -> Value {
+    json!({
+        "pit_id": pit_id,
+        "_shards": {"total": 1, "successful": 1, "failed": 0},
+        "creation_time": 1700000000000u64
+    })
+}
+
+fn deleted(pit_id: &str) -> Value {
+    json!({"pits": [{"pit_id": pit_id, "successful": true}]})
+}
+
+#[tokio::test]
+async fn test_pit_stream_pages_with_search_after() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        opened("pit-1"),
+        search_page("pit_id", "pit-2", &[0, 1]),
+        search_page("pit_id", "pit-2", &[2, 3]),
+        search_page("pit_id", "pit-3", &[4]),
+        deleted("pit-3"),
+    ])
+    .await?;
+
+    let by_position = SortOptions::builder().order(SortOrder::Desc).build()?;
+    let hits: Vec<_> = client
+        .search::<PositionDocument>("logs")
+        .sort(vec![SortTerm::from(("position", by_position))])
+        .size(2)
+        .pit_stream("1m")
+        .try_collect()
+        .await?;
+    let positions: Vec<u32> = hits
+        .iter()
+        .map(|hit| hit.source.as_ref().unwrap().position)
+        .collect();
+    assert_eq!(positions, vec![0, 1, 2, 3, 4]);
+
+    // The point in time is opened without a body and kept alive by every search
+    let requests = requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 5);
+    assert!(requests[0].is_empty());
+    let searches: Vec<Value> = requests[1..4]
+        .iter()
+        .map(|body| serde_json::from_str(body))
+        .collect::<Result<_, _>>()?;
+    assert_eq!(
+        searches[0]["pit"],
+        json!({"id": "pit-1", "keep_alive": "1m"})
+    );
+    assert_eq!(
+        searches[0]["sort"],
+        json!([{"position": {"order": "desc"}}, {"_id": {"order": "asc"}}])
+    );
+    assert!(searches[0].get("search_after").is_none());
+
+    // Each page continues after the last hit with the latest point-in-time ID
+    assert_eq!(searches[1]["pit"]["id"], "pit-2");
+    assert_eq!(searches[1]["search_after"], json!([1, "doc-1"]));
+    assert_eq!(searches[2]["search_after"], json!([3, "doc-3"]));
+
+    let delete: Value = serde_json::from_str(&requests[4])?;
+    assert_eq!(delete, json!({"pit_id": ["pit-3"]}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pit_stream_deletes_on_drop() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        opened("pit-1"),
+        search_page("pit_id", "pit-1", &[0, 1]),
+        deleted("pit-1"),
+    ])
+    .await?;
+
+    let hits: Vec<_> = client
+        .search::<PositionDocument>("logs")
+        .size(2)
+        .pit_stream("30s")
+        .take(1)
+        .collect()
+        .await;
+    assert_eq!(hits.len(), 1);
+
+    for _ in 0..50 {
+        if requests.lock().unwrap().len() == 3 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let last: Value = serde_json::from_str(&requests.lock().unwrap()[2])?;
+    assert_eq!(last, json!({"pit_id": ["pit-1"]}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pit_stream_errors() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        opened("pit-1"),
+        json!({"unexpected": true}),
+        deleted("pit-1"),
+    ])
+    .await?;
+
+    // A failed page ends the stream with the error and deletes the point in time
+    let results: Vec<_> = client
+        .search::<PositionDocument>("logs")
+        .pit_stream("1m")
+        .collect()
+        .await;
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+
+    let last: Value = serde_json::from_str(&requests.lock().unwrap()[2])?;
+    assert_eq!(last, json!({"pit_id": ["pit-1"]}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pit_search_against_cluster() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("pit-stream");
+
+    let mut bulk = fixture.client.bulk();
+    for position in 0..45 {
+        bulk = bulk.index(
+            &index_name,
+            Some(format!("doc-{}", position)),
+            &PositionDocument { position },
+        );
+    }
+    bulk.refresh(RefreshPolicy::True).send().await?;
+
+    // Documents indexed after the point in time was opened are not visible
+    let pit = fixture
+        .client
+        .create_pit(&index_name, "1m")
+        .build()?
+        .send()
+        .await?;
+    fixture
+        .client
+        .bulk()
+        .index(
+            &index_name,
+            Some("doc-100"),
+            &PositionDocument { position: 100 },
+        )
+        .refresh(RefreshPolicy::True)
+        .send()
+        .await?;
+    let response = fixture
+        .client
+        .search::<PositionDocument>(&index_name)
+        .pit(PointInTime::new(&pit.pit_id).keep_alive("1m"))
+        .size(0)
+        .build()?
+        .send()
+        .await?;
+    assert_eq!(response.hits.total.value, 45);
+    let deleted = fixture
+        .client
+        .delete_pit(pit.pit_id)
+        .build()?
+        .send()
+        .await?;
+    assert!(deleted.pits.iter().all(|pit| pit.successful));
+
+    let by_position = SortOptions::builder().order(SortOrder::Asc).build()?;
+    let positions: Vec<u32> = fixture
+        .client
+        .search::<PositionDocument>(&index_name)
+        .query(Query::match_all())
+        .sort(vec![SortTerm::from(("position", by_position))])
+        .size(10)
+        .pit_stream("1m")
+        .map_ok(|hit| hit.source.unwrap().position)
+        .try_collect()
+        .await?;
+    let mut expected: Vec<u32> = (0..45).collect();
+    expected.push(100);
+    assert_eq!(positions, expected);
+
+    Ok(())
+}
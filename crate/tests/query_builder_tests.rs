@@ -1,5 +1,6 @@
 use anyhow::Error;
 use opensearch_api::types::query::*;
+use opensearch_api::types::search::{HighlightOptions, InnerHits, SourceFilter};
 use serde_json::json;
 
 #[test]
@@ -695,6 +696,93 @@ fn test_nested_query_builder() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn test_nested_query_inner_hits() -> Result<(), Error> {
+    let nested_query = NestedQuery::builder()
+        .path("variants")
+        .query(Box::new(Query::from(
+            TermQuery::builder()
+                .field("variants.color", "red")
+                .build()?,
+        )))
+        .inner_hits(
+            InnerHits::builder()
+                .name("red_variants")
+                .size(3i64)
+                .source(SourceFilter::Include(vec!["variants.sku".to_string()]))
+                .highlight(serde_json::from_value::<HighlightOptions>(
+                    json!({"fields": {"variants.color": {}}}),
+                )?)
+                .build()?,
+        )
+        .build()?;
+
+    let json = Query::from(nested_query).json()?;
+    let expected = json!({
+        "nested": {
+            "path": "variants",
+            "query": {"term": {"variants.color": {"value": "red"}}},
+            "inner_hits": {
+                "name": "red_variants",
+                "size": 3,
+                "_source": ["variants.sku"],
+                "highlight": {"fields": {"variants.color": {}}}
+            }
+        }
+    });
+
+    assert_eq!(json, expected);
+    Ok(())
+}
+
+#[test]
+fn test_join_queries_inner_hits() -> Result<(), Error> {
+    let has_child = HasChildQuery {
+        has_child: HasChildQueryParams {
+            type_: "answer".to_string(),
+            query: Box::new(Query::match_all().into()),
+            score_mode: None,
+            min_children: None,
+            max_children: None,
+            ignore_unmapped: None,
+            boost: None,
+            inner_hits: Some(InnerHits::builder().size(2i64).build()?),
+        },
+    };
+    let has_parent = HasParentQuery {
+        has_parent: HasParentQueryParams {
+            parent_type: "question".to_string(),
+            query: Box::new(Query::match_all().into()),
+            score: None,
+            ignore_unmapped: None,
+            boost: None,
+            inner_hits: Some(InnerHits::builder().name("question").build()?),
+        },
+    };
+
+    assert_eq!(
+        Query::from(has_child).json()?,
+        json!({
+            "has_child": {
+                "type": "answer",
+                "query": {"match_all": {}},
+                "inner_hits": {"size": 2}
+            }
+        })
+    );
+    assert_eq!(
+        Query::from(has_parent).json()?,
+        json!({
+            "has_parent": {
+                "parent_type": "question",
+                "query": {"match_all": {}},
+                "inner_hits": {"name": "question"}
+            }
+        })
+    );
+    Ok(())
+}
+
 #[test]
 fn test_regexp_query_builder() -> Result<(), Error> {
     let query = RegexpQuery::builder()
@@ -0,0 +1,149 @@
+//! Tests for streaming search hits with the scroll API
+
+pub mod fixture;
+
+use crate::fixture::{search_page, stub_server, OpenSearchFixture, PositionDocument};
+use anyhow::Result;
+use futures::{StreamExt, TryStreamExt};
+use opensearch_api::types::common::RefreshPolicy;
+use opensearch_api::types::query::Query;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+fn cleared() -> Value {
+    json!({"succeeded": true, "num_freed": 1})
+}
+
+#[tokio::test]
+async fn test_scroll_stream_follows_scroll_ids() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        search_page("_scroll_id", "scroll-1", &[0, 1]),
+        search_page("_scroll_id", "scroll-2", &[2, 3]),
+        search_page("_scroll_id", "scroll-3", &[4]),
+        search_page("_scroll_id", "scroll-3", &[]),
+        cleared(),
+    ])
+    .await?;
+
+    let hits: Vec<_> = client
+        .search::<PositionDocument>("logs")
+        .size(2)
+        .scroll_stream("1m")
+        .try_collect()
+        .await?;
+    let positions: Vec<u32> = hits
+        .iter()
+        .map(|hit| hit.source.as_ref().unwrap().position)
+        .collect();
+    assert_eq!(positions, vec![0, 1, 2, 3, 4]);
+
+    // Every scroll request uses the latest scroll id, then the context is cleared
+    let requests: Vec<Value> = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|body| serde_json::from_str(body))
+        .collect::<Result<_, _>>()?;
+    assert_eq!(requests.len(), 5);
+    assert_eq!(
+        requests[1],
+        json!({"scroll_id": "scroll-1", "scroll": "1m"})
+    );
+    assert_eq!(
+        requests[2],
+        json!({"scroll_id": "scroll-2", "scroll": "1m"})
+    );
+    assert_eq!(
+        requests[3],
+        json!({"scroll_id": "scroll-3", "scroll": "1m"})
+    );
+    assert_eq!(requests[4], json!({"scroll_id": ["scroll-3"]}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scroll_stream_clears_on_drop() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        search_page("_scroll_id", "scroll-1", &[0, 1]),
+        search_page("_scroll_id", "scroll-2", &[2, 3]),
+        cleared(),
+    ])
+    .await?;
+
+    // Stopping after three hits leaves the second page partly read
+    let hits: Vec<_> = client
+        .search::<PositionDocument>("logs")
+        .size(2)
+        .scroll_stream("30s")
+        .take(3)
+        .collect()
+        .await;
+    assert_eq!(hits.len(), 3);
+
+    for _ in 0..50 {
+        if requests.lock().unwrap().len() == 3 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let last: Value = serde_json::from_str(&requests.lock().unwrap()[2])?;
+    assert_eq!(last, json!({"scroll_id": ["scroll-2"]}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scroll_stream_errors() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        search_page("_scroll_id", "scroll-1", &[0]),
+        json!({"unexpected": true}),
+        cleared(),
+    ])
+    .await?;
+
+    // A failed page ends the stream with the error and clears the context
+    let results: Vec<_> = client
+        .search::<PositionDocument>("logs")
+        .scroll_stream("1m")
+        .collect()
+        .await;
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+
+    let last: Value = serde_json::from_str(&requests.lock().unwrap()[2])?;
+    assert_eq!(last, json!({"scroll_id": ["scroll-1"]}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scroll_stream_against_cluster() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("scroll-stream");
+
+    let mut bulk = fixture.client.bulk();
+    for position in 0..55 {
+        bulk = bulk.index(
+            &index_name,
+            Some(format!("doc-{}", position)),
+            &PositionDocument { position },
+        );
+    }
+    bulk.refresh(RefreshPolicy::True).send().await?;
+
+    let mut positions: Vec<u32> = fixture
+        .client
+        .search::<PositionDocument>(&index_name)
+        .query(Query::match_all())
+        .size(10)
+        .scroll_stream("1m")
+        .map_ok(|hit| hit.source.unwrap().position)
+        .try_collect()
+        .await?;
+    positions.sort();
+    assert_eq!(positions, (0..55).collect::<Vec<_>>());
+
+    Ok(())
+}
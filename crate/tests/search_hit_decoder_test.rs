@@ -0,0 +1,265 @@
+//! Tests for decoding hits of multi-index searches into per-index document types
+
+pub mod fixture;
+
+use crate::fixture::{stub_server, OpenSearchFixture};
+use anyhow::Result;
+use opensearch_api::types::common::RefreshPolicy;
+use opensearch_api::types::query::MatchAllQuery;
+use opensearch_api::{matches_index_pattern, Document, Error, SearchHitDecoder};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct LogLine {
+    level: String,
+    message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct AuditEvent {
+    user: String,
+    action: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Document)]
+#[opensearch(index = "users")]
+struct User {
+    #[opensearch(id)]
+    name: String,
+}
+
+#[derive(Debug, PartialEq, SearchHitDecoder)]
+enum Federated {
+    #[opensearch(index = "logs-*")]
+    #[opensearch(index = "app-logs-*")]
+    Log(LogLine),
+    #[opensearch(index = "audit-*")]
+    Audit(AuditEvent),
+    User(User),
+    #[opensearch(other)]
+    Unknown(Value),
+}
+
+#[derive(Debug, PartialEq, SearchHitDecoder)]
+enum Strict {
+    #[opensearch(index = "audit-*")]
+    Audit(AuditEvent),
+}
+
+#[derive(Debug, PartialEq, SearchHitDecoder)]
+#[opensearch(discriminator = "kind")]
+enum Event {
+    #[opensearch(value = "log")]
+    Log(LogLine),
+    #[opensearch(value = "audit")]
+    Audit(AuditEvent),
+}
+
+#[derive(Debug, PartialEq, SearchHitDecoder)]
+enum Wrapped<T> {
+    #[opensearch(index = "logs-*")]
+    Log(T),
+}
+
+#[test]
+fn test_matches_index_pattern() {
+    assert!(matches_index_pattern("logs", "logs"));
+    assert!(!matches_index_pattern("logs", "logs-1"));
+    assert!(matches_index_pattern("*", "anything"));
+    assert!(matches_index_pattern("logs-*-eu", "logs-2024-eu"));
+    assert!(!matches_index_pattern("logs-*-eu", "logs-2024-us"));
+    assert!(matches_index_pattern("metrics, logs-*", "logs-1"));
+    assert!(matches_index_pattern("logs-*", "remote:logs-1"));
+    assert!(matches_index_pattern("remote:logs-*", "remote:logs-1"));
+    assert!(!matches_index_pattern("other:logs-*", "remote:logs-1"));
+}
+
+#[test]
+fn test_decode_by_index() -> Result<()> {
+    let log = json!({"level": "info", "message": "started"});
+    assert_eq!(
+        Federated::decode_hit("logs-2024.06.01", log.clone())?,
+        Federated::Log(serde_json::from_value(log.clone())?)
+    );
+    assert!(matches!(
+        Federated::decode_hit("app-logs-1", log.clone())?,
+        Federated::Log(_)
+    ));
+    assert!(matches!(
+        Federated::decode_hit("audit-1", json!({"user": "ada", "action": "login"}))?,
+        Federated::Audit(AuditEvent { ref user, .. }) if user == "ada"
+    ));
+
+    // Variants without patterns match the index of their document type
+    assert_eq!(
+        Federated::decode_hit("users", json!({"name": "ada"}))?,
+        Federated::User(User {
+            name: "ada".to_string()
+        })
+    );
+    assert_eq!(
+        Federated::decode_hit("metrics-1", json!({"cpu": 0.5}))?,
+        Federated::Unknown(json!({"cpu": 0.5}))
+    );
+
+    // Without an `other` variant, unmatched and malformed hits are errors
+    assert!(matches!(
+        Strict::decode_hit("logs-1", log.clone()),
+        Err(Error::Search(message)) if message.contains("logs-1")
+    ));
+    assert!(matches!(
+        Strict::decode_hit("audit-1", log),
+        Err(Error::SerializationError(_))
+    ));
+
+    assert_eq!(
+        Wrapped::<u32>::decode_hit("logs-1", json!(7))?,
+        Wrapped::Log(7)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_by_discriminator() -> Result<()> {
+    assert!(matches!(
+        Event::decode_hit(
+            "events",
+            json!({"kind": "log", "level": "warn", "message": "slow"})
+        )?,
+        Event::Log(LogLine { ref level, .. }) if level == "warn"
+    ));
+    assert!(matches!(
+        Event::decode_hit(
+            "events",
+            json!({"kind": "audit", "user": "ada", "action": "logout"})
+        )?,
+        Event::Audit(_)
+    ));
+    assert!(matches!(
+        Event::decode_hit("events", json!({"kind": "metric"})),
+        Err(Error::Search(_))
+    ));
+    assert!(matches!(
+        Event::decode_hit("events", json!({"message": "no kind"})),
+        Err(Error::Search(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decode_multi_index_search() -> Result<()> {
+    let (client, _requests) = stub_server(vec![json!({
+        "took": 1,
+        "timed_out": false,
+        "_shards": {"total": 2, "successful": 2, "failed": 0},
+        "hits": {
+            "total": {"value": 3, "relation": "eq"},
+            "max_score": 1.0,
+            "hits": [
+                {
+                    "_index": "logs-1",
+                    "_id": "1",
+                    "_score": 1.0,
+                    "_source": {"level": "error", "message": "disk full"}
+                },
+                {
+                    "_index": "audit-1",
+                    "_id": "2",
+                    "_score": 1.0,
+                    "_source": {"user": "ada", "action": "login"},
+                    "sort": [2]
+                },
+                {"_index": "audit-1", "_id": "3", "_score": 1.0}
+            ]
+        }
+    })])
+    .await?;
+
+    let response = client
+        .search::<Value>("logs-*,audit-*")
+        .query(MatchAllQuery::builder().build()?)
+        .build()?
+        .send()
+        .await?;
+    let hits = response.hits.decode::<Federated>()?;
+
+    assert_eq!(hits.len(), 3);
+    assert!(matches!(hits[0].source, Some(Federated::Log(_))));
+    assert!(matches!(hits[1].source, Some(Federated::Audit(_))));
+    assert_eq!(hits[1].id, "2");
+    assert_eq!(hits[1].sort, Some(vec![json!(2)]));
+    assert_eq!(hits[2].source, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decode_multi_index_search_against_cluster() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let logs = fixture.namespaced_index("logs");
+    let audit = fixture.namespaced_index("audit");
+
+    fixture
+        .client
+        .bulk()
+        .index(
+            &logs,
+            Some("1".to_string()),
+            &LogLine {
+                level: "info".to_string(),
+                message: "started".to_string(),
+            },
+        )
+        .refresh(RefreshPolicy::True)
+        .send()
+        .await?;
+    fixture
+        .client
+        .bulk()
+        .index(
+            &audit,
+            Some("2".to_string()),
+            &AuditEvent {
+                user: "ada".to_string(),
+                action: "login".to_string(),
+            },
+        )
+        .refresh(RefreshPolicy::True)
+        .send()
+        .await?;
+
+    #[derive(Debug, SearchHitDecoder)]
+    enum Hit {
+        #[opensearch(index = "logs-*")]
+        Log(LogLine),
+        #[opensearch(index = "audit-*")]
+        Audit(AuditEvent),
+    }
+
+    let response = fixture
+        .client
+        .search::<Value>(format!("{},{}", logs, audit))
+        .query(MatchAllQuery::builder().build()?)
+        .build()?
+        .send()
+        .await?;
+    let hits = response.hits.decode::<Hit>()?;
+    assert_eq!(hits.len(), 2);
+    for hit in hits {
+        match hit.source.unwrap() {
+            Hit::Log(line) => {
+                assert_eq!(hit.index, logs);
+                assert_eq!(line.message, "started");
+            }
+            Hit::Audit(event) => {
+                assert_eq!(hit.index, audit);
+                assert_eq!(event.user, "ada");
+            }
+        }
+    }
+
+    Ok(())
+}
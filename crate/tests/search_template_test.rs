@@ -0,0 +1,347 @@
+//! Tests for stored and inline search templates
+
+pub mod fixture;
+
+use crate::fixture::{stub_server_targets, OpenSearchFixture};
+use anyhow::Result;
+use opensearch_api::types::common::RefreshPolicy;
+use opensearch_api::types::search::{MSearchHeader, MSearchTemplateItem, SearchTemplate};
+use opensearch_api::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// Test document structure for search template tests
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+struct Product {
+    name: String,
+    price: u32,
+}
+
+/// Search response with the given product names
+fn page(names: &[&str]) -> Value {
+    let hits: Vec<Value> = names
+        .iter()
+        .enumerate()
+        .map(|(position, name)| {
+            json!({
+                "_index": "products",
+                "_id": position.to_string(),
+                "_score": 1.0,
+                "_source": {"name": name, "price": 10}
+            })
+        })
+        .collect();
+    json!({
+        "took": 1,
+        "timed_out": false,
+        "_shards": {"total": 1, "successful": 1, "failed": 0},
+        "hits": {"total": {"value": hits.len(), "relation": "eq"}, "max_score": 1.0, "hits": hits}
+    })
+}
+
+#[tokio::test]
+async fn test_search_template_requests() -> Result<()> {
+    let (client, requests, targets) =
+        stub_server_targets(vec![page(&["lamp"]), page(&["desk", "chair"])]).await?;
+
+    // Stored template with parameters and query-string options
+    let response = client
+        .search_template::<Product>("products")
+        .id("by-name")
+        .param("name", "lamp")
+        .param("size", 5)
+        .preference("_local")
+        .build()?
+        .send()
+        .await?;
+    assert_eq!(response.hits.hits[0].source.as_ref().unwrap().name, "lamp");
+
+    // Inline template
+    let response = client
+        .search_template::<Product>("products")
+        .source(json!({"query": {"range": {"price": {"lte": "{{max}}"}}}}))
+        .param("max", 20)
+        .explain(true)
+        .build()?
+        .send()
+        .await?;
+    assert_eq!(response.hits.hits.len(), 2);
+
+    let targets = targets.lock().unwrap().clone();
+    assert_eq!(
+        targets,
+        vec![
+            "POST /products/_search/template?preference=_local",
+            "POST /products/_search/template",
+        ]
+    );
+    let requests = requests.lock().unwrap().clone();
+    let stored: Value = serde_json::from_str(&requests[0])?;
+    assert_eq!(
+        stored,
+        json!({"id": "by-name", "params": {"name": "lamp", "size": 5}})
+    );
+    let inline: Value = serde_json::from_str(&requests[1])?;
+    assert_eq!(
+        inline,
+        json!({
+            "source": {"query": {"range": {"price": {"lte": "{{max}}"}}}},
+            "params": {"max": 20},
+            "explain": true
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_template_requires_id_or_source() -> Result<()> {
+    let (client, requests, _targets) = stub_server_targets(vec![]).await?;
+
+    let missing = client
+        .search_template::<Product>("products")
+        .param("name", "lamp")
+        .build()?
+        .send()
+        .await;
+    assert!(matches!(missing, Err(Error::InvalidArgument(_))));
+
+    let both = client
+        .render_search_template()
+        .id("by-name")
+        .source("{}")
+        .build()?
+        .send()
+        .await;
+    assert!(matches!(both, Err(Error::InvalidArgument(_))));
+
+    let unset = client
+        .msearch_template::<Product>()
+        .build()?
+        .add_template(MSearchTemplateItem {
+            header: MSearchHeader::default(),
+            body: SearchTemplate::default(),
+        })
+        .send()
+        .await;
+    assert!(matches!(unset, Err(Error::InvalidArgument(_))));
+
+    // Nothing is sent for an invalid template
+    assert!(requests.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_msearch_template_request() -> Result<()> {
+    let (client, requests, targets) = stub_server_targets(vec![json!({
+        "took": 2,
+        "responses": [page(&["lamp"]), page(&[])]
+    })])
+    .await?;
+
+    let response = client
+        .msearch_template::<Product>()
+        .build()?
+        .add_template(MSearchTemplateItem {
+            header: MSearchHeader::builder().index("products").build()?,
+            body: SearchTemplate::stored("by-name").param("name", "lamp"),
+        })
+        .add_template(MSearchTemplateItem {
+            header: MSearchHeader::builder().index("archive").build()?,
+            body: SearchTemplate::inline(json!({"query": {"match_none": {}}})),
+        })
+        .send()
+        .await?;
+    assert_eq!(response.responses.len(), 2);
+    assert_eq!(response.responses[0].hits.hits.len(), 1);
+
+    // Header and template lines alternate as newline-delimited JSON
+    assert_eq!(targets.lock().unwrap()[0], "POST /_msearch/template");
+    let body = requests.lock().unwrap()[0].clone();
+    let lines: Vec<Value> = body
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+    assert_eq!(
+        lines,
+        vec![
+            json!({"index": "products"}),
+            json!({"id": "by-name", "params": {"name": "lamp"}}),
+            json!({"index": "archive"}),
+            json!({"source": {"query": {"match_none": {}}}}),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stored_template_requests() -> Result<()> {
+    let (client, requests, targets) = stub_server_targets(vec![
+        json!({"acknowledged": true}),
+        json!({
+            "_id": "by-name",
+            "found": true,
+            "script": {
+                "lang": "mustache",
+                "source": "{\"query\":{\"match\":{\"name\":\"{{name}}\"}}}",
+                "options": {"content_type": "application/json"}
+            }
+        }),
+        json!({"template_output": {"query": {"match": {"name": "lamp"}}}}),
+        json!({"acknowledged": true}),
+    ])
+    .await?;
+
+    let put = client
+        .put_search_template("by-name", json!({"query": {"match": {"name": "{{name}}"}}}))
+        .build()?
+        .send()
+        .await?;
+    assert!(put.acknowledged);
+
+    let stored = client
+        .get_search_template("by-name")
+        .build()?
+        .send()
+        .await?;
+    assert!(stored.found);
+    assert_eq!(stored.script.unwrap().lang, "mustache");
+
+    let rendered = client
+        .render_search_template()
+        .id("by-name")
+        .param("name", "lamp")
+        .build()?
+        .send()
+        .await?;
+    assert_eq!(
+        rendered.template_output,
+        json!({"query": {"match": {"name": "lamp"}}})
+    );
+
+    let deleted = client
+        .delete_search_template("by-name")
+        .build()?
+        .send()
+        .await?;
+    assert!(deleted.acknowledged);
+
+    assert_eq!(
+        targets.lock().unwrap().clone(),
+        vec![
+            "PUT /_scripts/by-name",
+            "GET /_scripts/by-name",
+            "POST /_render/template",
+            "DELETE /_scripts/by-name",
+        ]
+    );
+    let requests = requests.lock().unwrap().clone();
+    let put: Value = serde_json::from_str(&requests[0])?;
+    assert_eq!(
+        put,
+        json!({"script": {"lang": "mustache", "source": {"query": {"match": {"name": "{{name}}"}}}}})
+    );
+    let render: Value = serde_json::from_str(&requests[2])?;
+    assert_eq!(render, json!({"id": "by-name", "params": {"name": "lamp"}}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_templates_against_cluster() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("search-template");
+    let template_id = fixture.namespaced_index("products-by-price");
+
+    let mut bulk = fixture.client.bulk();
+    for (position, (name, price)) in [("lamp", 25), ("desk", 120), ("chair", 80)]
+        .into_iter()
+        .enumerate()
+    {
+        let product = Product {
+            name: name.to_string(),
+            price,
+        };
+        bulk = bulk.index(&index_name, Some(position.to_string()), &product);
+    }
+    bulk.refresh(RefreshPolicy::True).send().await?;
+
+    let source = json!({
+        "query": {"range": {"price": {"lte": "{{max_price}}"}}},
+        "sort": [{"price": "asc"}]
+    });
+    let put = fixture
+        .client
+        .put_search_template(&template_id, source)
+        .build()?
+        .send()
+        .await?;
+    assert!(put.acknowledged);
+    let stored = fixture
+        .client
+        .get_search_template(&template_id)
+        .build()?
+        .send()
+        .await?;
+    assert!(stored.found);
+
+    let rendered = fixture
+        .client
+        .render_search_template()
+        .id(&template_id)
+        .param("max_price", 100)
+        .build()?
+        .send()
+        .await?;
+    assert_eq!(
+        rendered.template_output["query"]["range"]["price"]["lte"],
+        "100"
+    );
+
+    let response = fixture
+        .client
+        .search_template::<Product>(&index_name)
+        .id(&template_id)
+        .param("max_price", 100)
+        .build()?
+        .send()
+        .await?;
+    let names: Vec<_> = response
+        .hits
+        .hits
+        .iter()
+        .map(|hit| hit.source.as_ref().unwrap().name.as_str())
+        .collect();
+    assert_eq!(names, vec!["lamp", "chair"]);
+
+    let responses = fixture
+        .client
+        .msearch_template::<Product>()
+        .build()?
+        .add_template(MSearchTemplateItem {
+            header: MSearchHeader::builder().index(&index_name).build()?,
+            body: SearchTemplate::stored(&template_id).param("max_price", 50),
+        })
+        .add_template(MSearchTemplateItem {
+            header: MSearchHeader::builder().index(&index_name).build()?,
+            body: SearchTemplate::inline(json!({"query": {"term": {"name": "{{name}}"}}}))
+                .param("name", "desk"),
+        })
+        .send()
+        .await?;
+    assert_eq!(responses.responses[0].hits.total.value, 1);
+    assert_eq!(responses.responses[1].hits.total.value, 1);
+
+    let deleted = fixture
+        .client
+        .delete_search_template(&template_id)
+        .build()?
+        .send()
+        .await?;
+    assert!(deleted.acknowledged);
+
+    Ok(())
+}
@@ -1,10 +1,18 @@
 pub mod fixture;
 
-use crate::fixture::OpenSearchFixture;
+use crate::fixture::{stub_server_targets, OpenSearchFixture};
 use anyhow::Result;
-use opensearch_api::types::common::GeoPoint;
+use opensearch_api::types::aggregations::{Aggregation, Aggregations};
+use opensearch_api::types::common::{GeoPoint, RefreshPolicy};
 use opensearch_api::types::query::*;
-use opensearch_api::types::search::{MSearchHeader, MSearchItem};
+use opensearch_api::types::search::{
+    Collapse, CompletionContext, CompletionFuzzy, CompletionSuggester, FieldAndFormat,
+    HighlightOptions, IndexBoost, InnerHits, MSearchHeader, MSearchItem, PhraseHighlight,
+    PhraseSuggester, Rescore, SearchType, SmoothingModel, Suggest, SuggestMode, Suggester,
+    SuggestionOption, TermSuggester, TotalHitsRelation, TrackTotalHits,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 
 mod common {
     use crate::fixture::OpenSearchFixture;
@@ -519,6 +527,73 @@ async fn test_match_none_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_count() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = "search_test";
+    common::index_test_documents(&fixture, index_name).await?;
+    let index = fixture.namespaced_index(index_name);
+
+    // Count all documents when no query is given
+    let response = fixture.client.count(&index).build()?.send().await?;
+    assert_eq!(response.count, 5);
+    assert_eq!(response._shards.failed, 0);
+
+    let query = TermQuery::builder()
+        .field(
+            "tags".to_string(),
+            TermQueryRule::value(serde_json::Value::String("tutorial".to_string())),
+        )
+        .build()?;
+
+    let response = fixture
+        .client
+        .count(&index)
+        .query(query)
+        .ignore_unavailable(true)
+        .build()?
+        .send()
+        .await?;
+    assert_eq!(response.count, 2);
+
+    common::cleanup_test_index(&fixture, index_name).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_explain() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = "search_test";
+    common::index_test_documents(&fixture, index_name).await?;
+    let index = fixture.namespaced_index(index_name);
+
+    let query = MatchQuery::builder()
+        .field(
+            "content".to_string(),
+            MatchQueryRule::Simple("OpenSearch".to_string()),
+        )
+        .build()?;
+
+    let response = fixture
+        .client
+        .explain::<common::TestDocument>(&index, "1")
+        .query(query)
+        .source(true)
+        .build()?
+        .send()
+        .await?;
+
+    assert!(response.matched);
+    let explanation = response.explanation.expect("explanation is returned");
+    assert!(explanation.value > 0.0);
+    assert!(!explanation.description.is_empty());
+    let document = response.get.expect("document is returned");
+    assert_eq!(document.source.expect("source is returned").id, "1");
+
+    common::cleanup_test_index(&fixture, index_name).await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_match_phrase_query() -> Result<()> {
     let fixture = OpenSearchFixture::new().await?;
@@ -992,3 +1067,425 @@ async fn test_msearch() -> Result<()> {
     common::cleanup_test_index(&fixture, index_name).await?;
     Ok(())
 }
+
+#[tokio::test]
+async fn test_search_parameter_placement() -> Result<()> {
+    let (client, requests, targets) = stub_server_targets(vec![json!({
+        "took": 1,
+        "timed_out": false,
+        "_shards": {"total": 1, "successful": 1, "failed": 0},
+        "hits": {"max_score": null, "hits": []}
+    })])
+    .await?;
+
+    let response = client
+        .search::<Value>("logs")
+        .query(MatchAllQuery::builder().build()?)
+        .track_total_hits(TrackTotalHits::from(false))
+        .post_filter(TermQuery::builder().field("published", true).build()?)
+        .collapse(Collapse::new("title"))
+        .rescore(vec![
+            Rescore::query(MatchAllQuery::builder().build()?).window_size(10)
+        ])
+        .indices_boost(vec![IndexBoost::new("logs", 1.5)])
+        .docvalue_fields(vec![FieldAndFormat::from("rating")])
+        .fields(vec![FieldAndFormat::with_format(
+            "created_at",
+            "epoch_millis",
+        )])
+        .timeout("5s")
+        .terminate_after(1000u64)
+        .seq_no_primary_term(true)
+        .stats(vec!["dashboard".to_string()])
+        .profile(true)
+        .ext(json!({"plugin": {"enabled": true}}))
+        .preference("_local")
+        .routing("user-1")
+        .search_type(SearchType::DfsQueryThenFetch)
+        .request_cache(false)
+        .allow_partial_search_results(false)
+        .build()?
+        .send()
+        .await?;
+
+    // Hits are not counted when tracking is disabled
+    assert_eq!(
+        response.hits.total.relation,
+        TotalHitsRelation::GreaterThanOrEqual
+    );
+
+    // Execution options go in the query string and the search itself in the body
+    assert_eq!(
+        targets.lock().unwrap()[0],
+        "POST /logs/_search?preference=_local&routing=user-1&search_type=dfs_query_then_fetch&request_cache=false&allow_partial_search_results=false"
+    );
+    let body: Value = serde_json::from_str(&requests.lock().unwrap()[0])?;
+    assert_eq!(body["track_total_hits"], json!(false));
+    assert_eq!(
+        body["post_filter"],
+        json!({"term": {"published": {"value": true}}})
+    );
+    assert_eq!(body["collapse"], json!({"field": "title"}));
+    assert_eq!(body["rescore"][0]["window_size"], json!(10));
+    assert_eq!(body["indices_boost"], json!([{"logs": 1.5}]));
+    assert_eq!(body["docvalue_fields"], json!([{"field": "rating"}]));
+    assert_eq!(
+        body["fields"],
+        json!([{"field": "created_at", "format": "epoch_millis"}])
+    );
+    assert_eq!(body["timeout"], json!("5s"));
+    assert_eq!(body["terminate_after"], json!(1000));
+    assert_eq!(body["seq_no_primary_term"], json!(true));
+    assert_eq!(body["stats"], json!(["dashboard"]));
+    assert_eq!(body["profile"], json!(true));
+    assert_eq!(body["ext"], json!({"plugin": {"enabled": true}}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_body_options() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = "search_test";
+    common::index_test_documents(&fixture, index_name).await?;
+    let index = fixture.namespaced_index(index_name);
+
+    let response = fixture
+        .client
+        .search::<common::TestDocument>(&index)
+        .query(MatchAllQuery::builder().build()?)
+        .post_filter(TermQuery::builder().field("published", true).build()?)
+        .collapse(
+            Collapse::new("title").inner_hits(InnerHits::builder().name("same_title").build()?),
+        )
+        .rescore(vec![Rescore::query(
+            MatchQuery::builder()
+                .field("content", MatchQueryRule::Simple("OpenSearch".to_string()))
+                .build()?,
+        )
+        .window_size(10)])
+        .indices_boost(vec![IndexBoost::new(&index, 2.0)])
+        .fields(vec![FieldAndFormat::with_format(
+            "created_at",
+            "yyyy-MM-dd",
+        )])
+        .seq_no_primary_term(true)
+        .version(true)
+        .track_total_hits(TrackTotalHits::from(2u64))
+        .timeout("10s")
+        .request_cache(false)
+        .build()?
+        .send()
+        .await?;
+
+    // The post filter drops the unpublished document and the total stops at 2
+    assert_eq!(response.hits.total.value, 2);
+    assert_eq!(
+        response.hits.total.relation,
+        TotalHitsRelation::GreaterThanOrEqual
+    );
+    assert_eq!(response.hits.hits.len(), 4);
+    for hit in &response.hits.hits {
+        assert!(hit.source_ref_required().published);
+        assert!(hit.seq_no.is_some());
+        assert!(hit.primary_term.is_some());
+        assert!(hit.version.is_some());
+        assert_eq!(
+            hit.field_value::<String>("title")?,
+            Some(hit.source_ref_required().title.clone())
+        );
+        let created_at = hit.field_value::<String>("created_at")?.unwrap();
+        assert_eq!(created_at.len(), "2023-01-15".len());
+        assert!(hit.inner_hits.as_ref().unwrap().contains_key("same_title"));
+    }
+
+    common::cleanup_test_index(&fixture, index_name).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_suggesters() -> Result<()> {
+    #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+    struct Song {
+        title: String,
+        suggest: Value,
+    }
+
+    let fixture = OpenSearchFixture::new().await?;
+    let index = fixture.namespaced_index("suggest_test");
+    fixture
+        .client
+        .indices()
+        .create(&index)
+        .mappings(json!({
+            "properties": {
+                "title": {"type": "text"},
+                "suggest": {
+                    "type": "completion",
+                    "contexts": [{"name": "genre", "type": "category"}]
+                }
+            }
+        }))
+        .build()?
+        .send()
+        .await?;
+
+    let songs = [
+        ("Smells Like Teen Spirit", "Nirvana", "grunge", 10),
+        ("Nightswimming", "Nirvana Tribute", "grunge", 2),
+        ("Nights in White Satin", "Night Birds", "rock", 5),
+    ];
+    let mut bulk = fixture.client.bulk();
+    for (position, (title, input, genre, weight)) in songs.iter().enumerate() {
+        let song = Song {
+            title: title.to_string(),
+            suggest: json!({"input": input, "weight": weight, "contexts": {"genre": [genre]}}),
+        };
+        bulk = bulk.index(&index, Some(position.to_string()), &song);
+    }
+    bulk.refresh(RefreshPolicy::True).send().await?;
+
+    let suggest = Suggest::new()
+        .suggester(
+            "autocomplete",
+            Suggester::completion(
+                "nirv",
+                CompletionSuggester::builder()
+                    .field("suggest")
+                    .skip_duplicates(true)
+                    .context("genre", CompletionContext::category("grunge"))
+                    .build()?,
+            ),
+        )
+        .suggester(
+            "typo",
+            Suggester::completion(
+                "nrvana",
+                CompletionSuggester::builder()
+                    .field("suggest")
+                    .fuzzy(
+                        CompletionFuzzy::builder()
+                            .fuzziness(Fuzziness::Auto)
+                            .build()?,
+                    )
+                    .build()?,
+            ),
+        )
+        .suggester(
+            "spelling",
+            Suggester::term(
+                "smels",
+                TermSuggester::builder()
+                    .field("title")
+                    .suggest_mode(SuggestMode::Always)
+                    .build()?,
+            ),
+        )
+        .suggester(
+            "phrase",
+            Suggester::phrase(
+                "smels like",
+                PhraseSuggester::builder()
+                    .field("title")
+                    .smoothing(SmoothingModel::StupidBackoff { discount: 0.4 })
+                    .highlight(PhraseHighlight::new("<em>", "</em>"))
+                    .build()?,
+            ),
+        );
+    let response = fixture
+        .client
+        .search::<Song>(&index)
+        .size(0)
+        .suggest(suggest)
+        .build()?
+        .send()
+        .await?;
+    let suggestions = response.suggest.unwrap();
+
+    // Completions come with their documents, best weight first
+    let options = &suggestions["autocomplete"][0].options;
+    assert_eq!(options.len(), 2);
+    match &options[0] {
+        SuggestionOption::Completion(option) => {
+            assert_eq!(option.text, "Nirvana");
+            assert_eq!(
+                option.source::<Song>()?.unwrap().title,
+                "Smells Like Teen Spirit"
+            );
+            assert_eq!(option.contexts.as_ref().unwrap()["genre"], vec!["grunge"]);
+        }
+        other => panic!("expected a completion option, got {:?}", other),
+    }
+    assert!(!suggestions["typo"][0].options.is_empty());
+
+    let spelling = &suggestions["spelling"][0].options;
+    assert!(matches!(&spelling[0], SuggestionOption::Term(option) if option.text == "smells"));
+
+    match &suggestions["phrase"][0].options[0] {
+        SuggestionOption::Phrase(option) => {
+            assert_eq!(option.text, "smells like");
+            assert_eq!(option.highlighted.as_deref(), Some("<em>smells</em> like"));
+        }
+        other => panic!("expected a phrase option, got {:?}", other),
+    }
+
+    fixture
+        .client
+        .indices()
+        .delete(&index)
+        .build()?
+        .send()
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_nested_inner_hits() -> Result<()> {
+    #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+    struct Variant {
+        sku: String,
+        color: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Clone)]
+    struct Product {
+        name: String,
+        variants: Vec<Variant>,
+    }
+
+    let fixture = OpenSearchFixture::new().await?;
+    let index = fixture.namespaced_index("inner_hits_test");
+    fixture
+        .client
+        .indices()
+        .create(&index)
+        .mappings(json!({
+            "properties": {
+                "name": {"type": "text"},
+                "variants": {
+                    "type": "nested",
+                    "properties": {
+                        "sku": {"type": "keyword"},
+                        "color": {"type": "text"}
+                    }
+                }
+            }
+        }))
+        .build()?
+        .send()
+        .await?;
+
+    let variant = |sku: &str, color: &str| Variant {
+        sku: sku.to_string(),
+        color: color.to_string(),
+    };
+    let product = Product {
+        name: "Desk lamp".to_string(),
+        variants: vec![
+            variant("LAMP-B", "black"),
+            variant("LAMP-R", "bright red"),
+            variant("LAMP-W", "white"),
+        ],
+    };
+    fixture
+        .client
+        .bulk()
+        .index(&index, Some("lamp"), &product)
+        .refresh(RefreshPolicy::True)
+        .send()
+        .await?;
+
+    let nested = NestedQuery::builder()
+        .path("variants")
+        .query(Box::new(Query::from(
+            MatchQuery::builder()
+                .field("variants.color", MatchQueryRule::Simple("red".to_string()))
+                .build()?,
+        )))
+        .inner_hits(
+            InnerHits::builder()
+                .name("matching_variants")
+                .size(5i64)
+                .highlight(serde_json::from_value::<HighlightOptions>(
+                    json!({"fields": {"variants.color": {}}}),
+                )?)
+                .build()?,
+        )
+        .build()?;
+    let response = fixture
+        .client
+        .search::<Product>(&index)
+        .query(nested)
+        .build()?
+        .send()
+        .await?;
+
+    // Only the matching variant is returned, with its position in the product
+    let hit = &response.hits.hits[0];
+    let variants = hit.inner_hits::<Variant>("matching_variants")?.unwrap();
+    assert_eq!(variants.hits.len(), 1);
+    let matching = &variants.hits[0];
+    assert_eq!(matching.source, Some(variant("LAMP-R", "bright red")));
+    assert_eq!(matching.nested.as_ref().unwrap().field, "variants");
+    assert_eq!(matching.nested.as_ref().unwrap().offset, 1);
+    assert_eq!(
+        matching.highlight("variants.color").unwrap(),
+        ["bright <em>red</em>".to_string()]
+    );
+
+    fixture
+        .client
+        .indices()
+        .delete(&index)
+        .build()?
+        .send()
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_profile() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = "search_test";
+    common::index_test_documents(&fixture, index_name).await?;
+    let index = fixture.namespaced_index(index_name);
+
+    let aggregations = Aggregations::builder()
+        .agg(
+            "titles",
+            Aggregation::terms().field("title").size(10).build()?,
+        )
+        .build()?;
+    let response = fixture
+        .client
+        .search::<common::TestDocument>(&index)
+        .query(
+            MatchQuery::builder()
+                .field("content", MatchQueryRule::Simple("OpenSearch".to_string()))
+                .build()?,
+        )
+        .aggregations(aggregations)
+        .profile(true)
+        .build()?
+        .send()
+        .await?;
+
+    let profile = response.profile.unwrap();
+    assert!(!profile.shards.is_empty());
+    let shard = &profile.shards[0];
+    assert!(!shard.searches[0].query.is_empty());
+    assert!(!shard.searches[0].collector.is_empty());
+
+    // Every profiled query and aggregation is ranked, slowest first
+    let queries = profile.slowest_queries(usize::MAX);
+    assert!(!queries.is_empty());
+    assert!(queries
+        .windows(2)
+        .all(|pair| pair[0].self_time_in_nanos >= pair[1].self_time_in_nanos));
+    let aggregations = profile.slowest_aggregations(1);
+    assert_eq!(aggregations[0].description, "titles");
+    assert!(aggregations[0].to_string().contains("[titles]"));
+
+    common::cleanup_test_index(&fixture, index_name).await?;
+    Ok(())
+}
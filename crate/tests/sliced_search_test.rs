@@ -0,0 +1,175 @@
+//! Tests for reading scroll and point-in-time searches in parallel slices
+
+pub mod fixture;
+
+use crate::fixture::{search_page, stub_server, OpenSearchFixture, PositionDocument};
+use anyhow::Result;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use opensearch_api::types::common::RefreshPolicy;
+use serde_json::{json, Value};
+
+fn bodies(requests: &[String]) -> Vec<Value> {
+    requests
+        .iter()
+        .map(|body| serde_json::from_str(body).unwrap_or(Value::Null))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_scroll_slices() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        search_page("_scroll_id", "scroll-a", &[0, 1]),
+        search_page("_scroll_id", "scroll-a", &[]),
+        json!({"succeeded": true, "num_freed": 1}),
+        search_page("_scroll_id", "scroll-b", &[2, 3]),
+        search_page("_scroll_id", "scroll-b", &[]),
+        json!({"succeeded": true, "num_freed": 1}),
+    ])
+    .await?;
+
+    // Slices read one after the other keep the requests in order
+    let slices = client
+        .search::<PositionDocument>("logs")
+        .scroll_slices("1m", 2);
+    assert_eq!(slices.len(), 2);
+    let hits: Vec<_> = stream::iter(slices).flatten().try_collect().await?;
+    assert_eq!(hits.len(), 4);
+
+    // Every slice has its own scroll context, cleared at its end
+    let bodies = bodies(&requests.lock().unwrap());
+    assert_eq!(bodies[0]["slice"], json!({"id": 0, "max": 2}));
+    assert_eq!(bodies[2], json!({"scroll_id": ["scroll-a"]}));
+    assert_eq!(bodies[3]["slice"], json!({"id": 1, "max": 2}));
+    assert_eq!(bodies[5], json!({"scroll_id": ["scroll-b"]}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pit_slices_share_point_in_time() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        json!({"pit_id": "pit-1"}),
+        search_page("pit_id", "pit-1", &[0]),
+        search_page("pit_id", "pit-1", &[1]),
+        json!({"pits": [{"pit_id": "pit-1", "successful": true}]}),
+    ])
+    .await?;
+
+    let slices = client
+        .search::<PositionDocument>("logs")
+        .size(10)
+        .pit_slices("1m", 2);
+    let hits: Vec<_> = stream::iter(slices).flatten().try_collect().await?;
+    assert_eq!(hits.len(), 2);
+
+    // One point in time is opened for all slices and deleted after the last one
+    let requests = requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 4);
+    assert!(requests[0].is_empty());
+    let bodies = bodies(&requests);
+    assert_eq!(bodies[1]["pit"]["id"], "pit-1");
+    assert_eq!(bodies[1]["slice"], json!({"id": 0, "max": 2}));
+    assert_eq!(bodies[2]["pit"]["id"], "pit-1");
+    assert_eq!(bodies[2]["slice"], json!({"id": 1, "max": 2}));
+    assert_eq!(bodies[3], json!({"pit_id": ["pit-1"]}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pit_slice_errors() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        json!({"pit_id": "pit-1"}),
+        json!({"unexpected": true}),
+        search_page("pit_id", "pit-1", &[1]),
+        json!({"pits": [{"pit_id": "pit-1", "successful": true}]}),
+    ])
+    .await?;
+
+    // A failed slice does not end the others nor delete the point in time they read
+    let mut slices = client
+        .search::<PositionDocument>("logs")
+        .size(10)
+        .pit_slices("1m", 2)
+        .into_iter();
+    let failed: Vec<_> = slices.next().unwrap().collect().await;
+    assert_eq!(failed.len(), 1);
+    assert!(failed[0].is_err());
+    assert_eq!(requests.lock().unwrap().len(), 2);
+
+    let hits: Vec<_> = slices.next().unwrap().try_collect().await?;
+    assert_eq!(hits.len(), 1);
+    let last: Value = serde_json::from_str(&requests.lock().unwrap()[3])?;
+    assert_eq!(last, json!({"pit_id": ["pit-1"]}));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_single_slice() -> Result<()> {
+    let (client, requests) = stub_server(vec![
+        search_page("_scroll_id", "scroll-a", &[]),
+        json!({"succeeded": true, "num_freed": 1}),
+    ])
+    .await?;
+
+    let slices = client
+        .search::<PositionDocument>("logs")
+        .scroll_slices("1m", 1);
+    assert_eq!(slices.len(), 1);
+    let hits: Vec<_> = stream::iter(slices).flatten().try_collect().await?;
+    assert!(hits.is_empty());
+
+    let first: Value = serde_json::from_str(&requests.lock().unwrap()[0])?;
+    assert!(first.get("slice").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sliced_export_against_cluster() -> Result<()> {
+    let fixture = OpenSearchFixture::new().await?;
+    let index_name = fixture.namespaced_index("sliced-search");
+
+    let mut bulk = fixture.client.bulk();
+    for position in 0..120 {
+        bulk = bulk.index(
+            &index_name,
+            Some(format!("doc-{}", position)),
+            &PositionDocument { position },
+        );
+    }
+    bulk.refresh(RefreshPolicy::True).send().await?;
+    let mut search = fixture.client.search::<PositionDocument>(&index_name);
+    search.size(25);
+
+    // Scroll slices merged into one stream
+    let slices = search.scroll_slices("1m", 3);
+    let mut positions: Vec<u32> = stream::select_all(slices.into_iter().map(Box::pin))
+        .map_ok(|hit| hit.source.unwrap().position)
+        .try_collect()
+        .await?;
+    positions.sort();
+    assert_eq!(positions, (0..120).collect::<Vec<_>>());
+
+    // Point-in-time slices handed to separate tasks
+    let workers: Vec<_> = search
+        .pit_slices("1m", 3)
+        .into_iter()
+        .map(|slice| {
+            tokio::spawn(
+                slice
+                    .map_ok(|hit| hit.source.unwrap().position)
+                    .try_collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+    let mut positions = Vec::new();
+    for worker in workers {
+        positions.extend(worker.await??);
+    }
+    positions.sort();
+    assert_eq!(positions, (0..120).collect::<Vec<_>>());
+
+    Ok(())
+}